@@ -0,0 +1,223 @@
+use hdk::prelude::*;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::BTreeMap;
+
+use crate::*;
+
+/// Input and output schema of one extern. Definitions they refer to live
+/// in `ApiSchema::definitions`.
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ExternSchema {
+    pub name: String,
+    #[cfg_attr(feature = "ts", ts(type = "unknown"))]
+    pub input: serde_json::Value,
+    #[cfg_attr(feature = "ts", ts(type = "unknown"))]
+    pub output: serde_json::Value,
+}
+
+/// JSON Schema (draft 7) description of every extern this zome exposes
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ApiSchema {
+    /// The DNA these externs were read from
+    #[cfg_attr(feature = "ts", ts(type = "DnaHash"))]
+    #[schemars(with = "DnaHashSchema")]
+    pub dna_hash: DnaHash,
+    pub zome: String,
+    pub externs: Vec<ExternSchema>,
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, unknown>"))]
+    pub definitions: BTreeMap<String, serde_json::Value>,
+}
+
+fn schema_value(schema: Schema) -> ExternResult<serde_json::Value> {
+    serde_json::to_value(schema).map_err(|err| SharefeedError::Unreachable(err.to_string()).into())
+}
+
+/// `name: Input => Output` for each extern. Holochain types are written as
+/// their stand-ins from `sharefeed_integrity::schema`.
+macro_rules! extern_schemas {
+    ($gen:ident; $($name:ident: $input:ty => $output:ty,)*) => {
+        vec![$(ExternSchema {
+            name: String::from(stringify!($name)),
+            input: schema_value($gen.subschema_for::<$input>())?,
+            output: schema_value($gen.subschema_for::<$output>())?,
+        },)*]
+    };
+}
+
+/// Describes every extern's input and output, so generic clients and tests
+/// can validate payloads against the DNA they're actually talking to.
+/// Lifecycle callbacks and scheduled functions aren't included.
+#[hdk_extern]
+pub fn get_api_schema(_: ()) -> ExternResult<ApiSchema> {
+    trace_call!("get_api_schema");
+    let mut gen = SchemaGenerator::default();
+    let externs = extern_schemas![gen;
+        get_blocked_agents: () => Blocklist,
+        block_agent: AgentPubKeySchema => Blocklist,
+        unblock_agent: AgentPubKeySchema => Blocklist,
+        mute_agent: AgentPubKeySchema => Blocklist,
+        unmute_agent: AgentPubKeySchema => Blocklist,
+        mute_share: ActionHashSchema => Blocklist,
+        unmute_share: ActionHashSchema => Blocklist,
+        grant_extension_capability: GrantExtensionCapabilityInput => ExtensionCapability,
+        revoke_capability: ActionHashSchema => ActionHashSchema,
+        list_my_capability_grants: () => Vec<CapabilityGrantInfo>,
+        create_comment: Comment => RecordSchema,
+        get_comments: ReadInput<ActionHashSchema> => Vec<CommentInfo>,
+        count_comments: ReadInput<ActionHashSchema> => usize,
+        delete_comment: ActionHashSchema => ActionHashSchema,
+        grant_posting_delegate: GrantPostingDelegateInput => ActionHashSchema,
+        receive_posting_delegation: PostingDelegation => ActionHashSchema,
+        post_to_feed_as: PostToFeedAsInput => ActionHashSchema,
+        post_as_feed: PostToFeedAsInput => ActionHashSchema,
+        get_latest_digest: () => Option<Digest>,
+        get_trending_tags: ReadInput<TrendingTagsInput> => Vec<TrendingTag>,
+        get_top_shared_urls: ReadInput<TopSharedUrlsInput> => Vec<TopSharedUrl>,
+        get_explore_sample: ReadInput<ExploreSampleInput> => Vec<ShareItemInfo>,
+        get_similar_shares: ReadInput<SimilarSharesInput> => Vec<ShareItemInfo>,
+        export_feed_jsonfeed: ReadInput<ActionHashSchema> => JsonFeed,
+        export_opml: () => String,
+        export_activitypub_outbox: ReadInput<ActivityPubOutboxInput> => ActivityPubOutbox,
+        export_my_data: () => PersonalDataExport,
+        get_record_for_peer: ActionHashSchema => Option<RecordSchema>,
+        create_feed: Feed => RecordSchema,
+        get_feed: ReadInput<ActionHashSchema> => Option<RecordSchema>,
+        update_feed: UpdateFeedInput => RecordSchema,
+        set_feed_keyword_filters: SetKeywordFiltersInput => RecordSchema,
+        delete_feed: ActionHashSchema => ActionHashSchema,
+        add_share_to_feed: AddShareToFeedInput => (),
+        remove_share_from_feed: RemoveShareFromFeedInput => (),
+        get_feed_share_links: ReadInput<ActionHashSchema> => Vec<FeedShareLinkInfo>,
+        get_feed_shares: ReadInput<ActionHashSchema> => Vec<ShareItemInfo>,
+        count_feed_shares: ReadInput<ActionHashSchema> => usize,
+        get_feed_shares_with_fallback: ReadInput<ActionHashSchema> => Vec<ShareItemInfo>,
+        get_feed_share_summaries: ReadInput<ActionHashSchema> => Vec<ShareSummaryInfo>,
+        get_feed_shares_chunk: ReadInput<FeedSharesChunkInput> => FeedSharesChunk,
+        get_my_feeds: ReadInput<()> => Vec<FeedInfo>,
+        add_member_to_feed: AddMemberToFeedInput => (),
+        leave_feed: ActionHashSchema => (),
+        get_feed_members: ReadInput<ActionHashSchema> => Vec<AgentPubKeySchema>,
+        get_feed_members_page: ReadInput<FeedMembersPageInput> => FeedMembersPage,
+        follow_agent: AgentPubKeySchema => (),
+        unfollow_agent: AgentPubKeySchema => (),
+        get_following: () => Vec<AgentPubKeySchema>,
+        get_following_timeline: ReadInput<TimelineInput> => Vec<ShareItemInfo>,
+        import_bookmarks: ImportBookmarksInput => ImportReport,
+        import_pocket: ImportPocketInput => ImportReport,
+        import_bookmarking_service: ImportBookmarkingServiceInput => ImportReport,
+        import_my_data: PersonalDataExport => RestoreReport,
+        request_membership: RequestMembershipInput => Vec<AgentPubKeySchema>,
+        receive_membership_request: RequestMembershipInput => ActionHashSchema,
+        get_pending_membership_requests: () => Vec<MembershipRequestInfo>,
+        get_my_mentions: ReadInput<()> => Vec<ShareItemInfo>,
+        close_chain_for_migration: DnaHashSchema => ActionHashSchema,
+        get_chain_closure: () => Option<ChainClosure>,
+        init_from_previous_chain: CellIdSchema => RestoreReport,
+        publish_network_blocklist: NetworkBlocklist => RecordSchema,
+        get_network_blocklist: ReadInput<()> => NetworkBlocklist,
+        get_notifications: GetNotificationsInput => Vec<NotificationInfo>,
+        mark_read: Vec<ActionHashSchema> => (),
+        get_unread_notification_count: () => usize,
+        get_push_payload_for_notification: ActionHashSchema => PushPayload,
+        get_notification_prefs: () => NotificationPrefs,
+        set_notification_prefs: NotificationPrefs => ActionHashSchema,
+        query_shares: ReadInput<ShareFilter> => Vec<ShareItemInfo>,
+        add_reaction: Reaction => RecordSchema,
+        get_reactions: ReadInput<ActionHashSchema> => Vec<ReactionInfo>,
+        count_reactions: ReadInput<ActionHashSchema> => usize,
+        remove_reaction: ActionHashSchema => ActionHashSchema,
+        get_agent_reputation: AgentPubKeySchema => AgentReputation,
+        create_saved_search: SavedSearch => ActionHashSchema,
+        get_saved_searches: () => Vec<SavedSearchInfo>,
+        update_saved_search: UpdateSavedSearchInput => ActionHashSchema,
+        delete_saved_search: ActionHashSchema => ActionHashSchema,
+        search_shares: ReadInput<SearchSharesInput> => SearchResultsPage,
+        search_feed: ReadInput<SearchFeedInput> => SearchResultsPage,
+        search_titles: ReadInput<SearchTitlesInput> => Vec<ShareItemInfo>,
+        create_share_item: ShareItem => RecordSchema,
+        create_share_item_idempotent: CreateShareItemIdempotentInput => RecordSchema,
+        get_share_item: ReadInput<ActionHashSchema> => Option<RecordSchema>,
+        get_share_item_resolved: GetShareItemResolvedInput => Option<ResolvedRecord>,
+        update_share_item: UpdateShareItemInput => RecordSchema,
+        delete_share_item: ActionHashSchema => ActionHashSchema,
+        get_share_summaries_for_week: ReadInput<TimeRangeInput> => Vec<ShareSummaryInfo>,
+        get_shares_for_week: ReadInput<TimeRangeInput> => Vec<ShareItemInfo>,
+        count_shares_for_week: ReadInput<TimeRangeInput> => usize,
+        get_recent_shares: ReadInput<()> => Vec<ShareItemInfo>,
+        get_other_sharers: ReadInput<String> => Vec<OtherSharer>,
+        get_agent_summary: ReadInput<AgentPubKeySchema> => AgentSummary,
+        get_asset_info: ReadInput<ActionHashSchema> => Option<AssetInfo>,
+        search_assets: ReadInput<SearchAssetsInput> => Vec<AssetRef>,
+        create_share_from_wal: CreateShareFromWalInput => RecordSchema,
+        resolve_deeplink: ReadInput<String> => ResolvedDeeplink,
+        archive_to_vault: ArchiveToVaultInput => VaultArchiveInfo,
+        get_vault_archive: ActionHashSchema => Option<VaultArchiveInfo>,
+        get_my_vault_archives: () => Vec<VaultArchiveInfo>,
+        report_share: Report => RecordSchema,
+        get_reports_for_feed: ReadInput<ActionHashSchema> => Vec<ReportInfo>,
+        get_moderation_queue: ReadInput<ActionHashSchema> => ModerationQueue,
+        resolve_report: ResolveReportInput => RecordSchema,
+        review_pending_share: ReviewPendingShareInput => RecordSchema,
+        get_moderation_log: ReadInput<ModerationLogInput> => ModerationLogPage,
+        file_appeal: Appeal => RecordSchema,
+        get_appeals_for_moderation_action: ReadInput<ActionHashSchema> => Vec<AppealInfo>,
+        accept_appeal: DecideAppealInput => RecordSchema,
+        uphold_appeal: DecideAppealInput => RecordSchema,
+        ban_member: Ban => RecordSchema,
+        unban_member: UnbanMemberInput => (),
+        get_feed_bans: ReadInput<ActionHashSchema> => Vec<BanInfo>,
+        get_agent_directory: ReadInput<()> => Vec<AgentPubKeySchema>,
+        get_instance_info: () => InstanceInfo,
+        health_check: () => HealthReport,
+        prune_orphaned_links: () => usize,
+        get_share_detail: ReadInput<ActionHashSchema> => Option<ShareDetail>,
+        get_feed_page: ReadInput<FeedPageInput> => Option<FeedPage>,
+        get_home_timeline: ReadInput<TimelineInput> => Vec<ShareItemInfo>,
+        record_open: ActionHashSchema => (),
+        get_open_count: ReadInput<ActionHashSchema> => usize,
+        mark_seen: SeenReceiptInput => (),
+        get_seen_by: ReadInput<SeenReceiptInput> => Vec<SeenReceipt>,
+        create_compilation: Compilation => RecordSchema,
+        get_compilations: ReadInput<ActionHashSchema> => Vec<CompilationInfo>,
+        get_compilations_for_share: ReadInput<ActionHashSchema> => Vec<CompilationInfo>,
+        save_for_later: ActionHashSchema => SavedItemInfo,
+        get_saved: () => Vec<SavedItemInfo>,
+        remove_saved: ActionHashSchema => (),
+        reorder_saved: Vec<ActionHashSchema> => Vec<SavedItemInfo>,
+        create_curator_note: CuratorNote => RecordSchema,
+        get_curator_notes: ReadInput<CuratorNotesInput> => Vec<CuratorNoteInfo>,
+        delete_curator_note: ActionHashSchema => (),
+        subscribe_to_tag: String => TagSubscriptionInfo,
+        unsubscribe_from_tag: String => (),
+        get_tag_subscriptions: () => Vec<TagSubscriptionInfo>,
+        subscribe_to_domain: String => DomainSubscriptionInfo,
+        unsubscribe_from_domain: String => (),
+        get_domain_subscriptions: () => Vec<DomainSubscriptionInfo>,
+        propose_steward_action: StewardProposal => RecordSchema,
+        approve_steward_proposal: ActionHashSchema => (),
+        withdraw_steward_proposal: ActionHashSchema => (),
+        get_steward_proposals: ReadInput<ActionHashSchema> => Vec<StewardProposalInfo>,
+        get_home_feed: ReadInput<()> => Option<ActionHashSchema>,
+        follow_home_feed: () => Option<ActionHashSchema>,
+        get_onboarding_state: () => OnboardingState,
+        set_onboarding_state: OnboardingState => ActionHashSchema,
+        get_api_schema: () => ApiSchema,
+    ];
+    let definitions = gen
+        .take_definitions()
+        .into_iter()
+        .map(|(name, schema)| Ok((name, schema_value(schema)?)))
+        .collect::<ExternResult<BTreeMap<String, serde_json::Value>>>()?;
+
+    Ok(ApiSchema {
+        dna_hash: dna_info()?.hash,
+        zome: zome_info()?.name.to_string(),
+        externs,
+        definitions,
+    })
+}
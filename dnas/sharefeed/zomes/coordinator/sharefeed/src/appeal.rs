@@ -0,0 +1,248 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::ban::lift_ban;
+use crate::error::SharefeedError;
+use crate::input::check_appeal;
+use crate::moderation::{
+    create_moderation_action, get_moderation_actions, restore_share_to_feed,
+    take_share_out_of_feed, ModerationActionInfo,
+};
+use crate::read::{ReadInput, ReadStrategy};
+use crate::report::check_is_steward;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct AppealInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub appeal_hash: ActionHash,
+    pub appeal: Appeal,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub appellant: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+    /// The steward's decision, once there is one
+    pub decision: Option<ModerationActionInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct DecideAppealInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub appeal_hash: ActionHash,
+    pub note: Option<String>,
+}
+
+fn get_moderation_action(moderation_action_hash: &ActionHash) -> ExternResult<ModerationAction> {
+    get(moderation_action_hash.clone(), GetOptions::network())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the ModerationAction",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            SharefeedError::InvalidInput(String::from("Expected a ModerationAction entry")).into(),
+        )
+}
+
+/// Appeals filed against one moderation action, each with the decision
+/// taken on it from `feed_actions`, the feed's moderation log
+fn get_appeals(
+    moderation_action_hash: ActionHash,
+    feed_actions: &[ModerationActionInfo],
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<AppealInfo>> {
+    let links = get_links(
+        LinkQuery::try_new(moderation_action_hash, LinkTypes::ModerationActionToAppeals)?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut appeals: Vec<AppealInfo> = Vec::new();
+    for link in links {
+        let appeal_hash =
+            ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
+        let Some(record) = get(appeal_hash.clone(), strategy.get_options())? else {
+            continue;
+        };
+        let Some(appeal) = record
+            .entry()
+            .to_app_option::<Appeal>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        let decision = feed_actions
+            .iter()
+            .find(|info| match &info.moderation_action.kind {
+                ModerationKind::DecideAppeal {
+                    appeal_hash: decided,
+                    ..
+                } => decided == &appeal_hash,
+                _ => false,
+            })
+            .cloned();
+        appeals.push(AppealInfo {
+            appeal_hash,
+            appeal,
+            appellant: record.action().author().clone(),
+            created_at: link.timestamp,
+            decision,
+        });
+    }
+
+    appeals.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(appeals)
+}
+
+/// Appeals against any of the actions in a feed's moderation log
+pub fn get_feed_appeals(
+    feed_actions: &[ModerationActionInfo],
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<AppealInfo>> {
+    let mut appeals: Vec<AppealInfo> = Vec::new();
+    for info in feed_actions {
+        if matches!(
+            info.moderation_action.kind,
+            ModerationKind::DecideAppeal { .. }
+        ) {
+            continue;
+        }
+        appeals.extend(get_appeals(
+            info.moderation_action_hash.clone(),
+            feed_actions,
+            strategy,
+        )?);
+    }
+    Ok(appeals)
+}
+
+/// Asks the feed's stewards to reconsider one of their moderation actions
+#[hdk_extern]
+pub fn file_appeal(appeal: Appeal) -> ExternResult<Record> {
+    trace_call!("file_appeal");
+    check_appeal(&appeal)?;
+    let moderation_action = get_moderation_action(&appeal.moderation_action_hash)?;
+    if matches!(moderation_action.kind, ModerationKind::DecideAppeal { .. }) {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Decisions on appeals cannot be appealed again",
+        ))
+        .into());
+    }
+
+    let appeal_hash = create_entry(&EntryTypes::Appeal(appeal.clone()))?;
+    create_link(
+        appeal.moderation_action_hash,
+        appeal_hash.clone(),
+        LinkTypes::ModerationActionToAppeals,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    let record = get(appeal_hash, GetOptions::local())?.ok_or(SharefeedError::NotFound(
+        String::from("Could not find the newly created Appeal"),
+    ))?;
+    Ok(record)
+}
+
+/// Appeals against a moderation action and what was decided on each, so
+/// anyone can follow action, appeal and decision
+#[hdk_extern]
+pub fn get_appeals_for_moderation_action(
+    moderation_action_hash: ReadInput<ActionHash>,
+) -> ExternResult<Vec<AppealInfo>> {
+    trace_call!("get_appeals_for_moderation_action");
+    let (moderation_action_hash, strategy) = moderation_action_hash.into_parts();
+    let moderation_action = get_moderation_action(&moderation_action_hash)?;
+    let feed_actions = get_moderation_actions(moderation_action.feed_hash, strategy)?;
+    get_appeals(moderation_action_hash, &feed_actions, strategy)
+}
+
+fn decide_appeal(input: DecideAppealInput, outcome: AppealOutcome) -> ExternResult<Record> {
+    let appeal: Appeal = get(input.appeal_hash.clone(), GetOptions::network())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Appeal",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Expected an Appeal entry",
+        )))?;
+    let appealed = get_moderation_action(&appeal.moderation_action_hash)?;
+    let feed_hash = appealed.feed_hash.clone();
+    check_is_steward(&feed_hash)?;
+
+    let already_decided = get_moderation_actions(feed_hash.clone(), ReadStrategy::Network)?
+        .into_iter()
+        .any(|info| match info.moderation_action.kind {
+            ModerationKind::DecideAppeal { appeal_hash, .. } => appeal_hash == input.appeal_hash,
+            _ => false,
+        });
+    if already_decided {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "This appeal has already been decided",
+        ))
+        .into());
+    }
+
+    // Accepting an appeal undoes what the appealed action did to the feed
+    if outcome == AppealOutcome::Accepted {
+        match &appealed.kind {
+            ModerationKind::ResolveReport {
+                report_hash,
+                outcome: ReportOutcome::ShareRemoved,
+            } => {
+                let report: Report = get(report_hash.clone(), GetOptions::network())?
+                    .ok_or(SharefeedError::NotFound(String::from(
+                        "Could not find the Report",
+                    )))?
+                    .entry()
+                    .to_app_option()
+                    .map_err(|e| wasm_error!(e))?
+                    .ok_or(SharefeedError::InvalidInput(String::from(
+                        "Expected a Report entry",
+                    )))?;
+                restore_share_to_feed(&feed_hash, &report.share_item_hash)?;
+            }
+            ModerationKind::RejectShare { share_item_hash }
+            | ModerationKind::RemoveShare { share_item_hash } => {
+                restore_share_to_feed(&feed_hash, share_item_hash)?;
+            }
+            ModerationKind::ApproveShare { share_item_hash } => {
+                take_share_out_of_feed(&feed_hash, share_item_hash)?;
+            }
+            ModerationKind::BanMember { ban_hash } => {
+                lift_ban(&feed_hash, ban_hash)?;
+            }
+            _ => {}
+        }
+    }
+
+    create_moderation_action(ModerationAction {
+        feed_hash,
+        kind: ModerationKind::DecideAppeal {
+            appeal_hash: input.appeal_hash,
+            outcome,
+        },
+        note: input.note,
+    })
+}
+
+/// Agrees with the appellant and reverses the appealed action where that
+/// means putting a share back in the feed or taking it out
+#[hdk_extern]
+pub fn accept_appeal(input: DecideAppealInput) -> ExternResult<Record> {
+    trace_call!("accept_appeal");
+    decide_appeal(input, AppealOutcome::Accepted)
+}
+
+/// Keeps the appealed action in place
+#[hdk_extern]
+pub fn uphold_appeal(input: DecideAppealInput) -> ExternResult<Record> {
+    trace_call!("uphold_appeal");
+    decide_appeal(input, AppealOutcome::Upheld)
+}
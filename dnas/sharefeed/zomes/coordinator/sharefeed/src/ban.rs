@@ -0,0 +1,184 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::membership::get_feed_stewards;
+use crate::moderation::create_moderation_action;
+use crate::read::{ReadInput, ReadStrategy};
+use crate::report::check_is_steward;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct BanInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub ban_hash: ActionHash,
+    pub ban: Ban,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub steward: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct UnbanMemberInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub agent: AgentPubKey,
+    pub note: Option<String>,
+}
+
+/// Bans in force in a feed, oldest first
+pub fn get_bans(feed_hash: &ActionHash, strategy: ReadStrategy) -> ExternResult<Vec<BanInfo>> {
+    let links = get_links(
+        LinkQuery::try_new(feed_hash.clone(), LinkTypes::FeedToBans)?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut bans: Vec<BanInfo> = Vec::new();
+    for link in links {
+        let ban_hash = ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        if bans.iter().any(|info| info.ban_hash == ban_hash) {
+            continue;
+        }
+        if let Some(record) = get(ban_hash.clone(), strategy.get_options())? {
+            if let Some(ban) = record
+                .entry()
+                .to_app_option::<Ban>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                bans.push(BanInfo {
+                    ban_hash,
+                    ban,
+                    steward: record.action().author().clone(),
+                    created_at: record.action().timestamp(),
+                });
+            }
+        }
+    }
+
+    bans.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(bans)
+}
+
+/// Refuses agents banned from the feed
+pub fn check_not_banned(feed_hash: &ActionHash, agent: &AgentPubKey) -> ExternResult<()> {
+    if get_bans(feed_hash, ReadStrategy::Network)?
+        .iter()
+        .any(|info| &info.ban.agent == agent)
+    {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "This agent is banned from the feed",
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Deletes the FeedToBans links of a ban, which lifts it
+pub fn lift_ban(feed_hash: &ActionHash, ban_hash: &ActionHash) -> ExternResult<()> {
+    let ban_target = AnyLinkableHash::from(ban_hash.clone());
+    let links = get_links(
+        LinkQuery::try_new(feed_hash.clone(), LinkTypes::FeedToBans)?,
+        GetStrategy::Network,
+    )?;
+    for link in links.into_iter().filter(|link| link.target == ban_target) {
+        delete_link(link.create_link_hash, GetOptions::network())?;
+    }
+    Ok(())
+}
+
+/// Bans an agent from a feed, removing them from its members. Banned
+/// agents can't be added back or add shares until the ban is lifted.
+#[hdk_extern]
+pub fn ban_member(ban: Ban) -> ExternResult<Record> {
+    trace_call!("ban_member");
+    check_is_steward(&ban.feed_hash)?;
+    if get_feed_stewards(&ban.feed_hash)?.contains(&ban.agent) {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Stewards cannot be banned from their feed",
+        ))
+        .into());
+    }
+    if ban.reason.trim().is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from("A ban must give a reason")).into());
+    }
+
+    let ban_hash = create_entry(&EntryTypes::Ban(ban.clone()))?;
+    create_link(
+        ban.feed_hash.clone(),
+        ban_hash.clone(),
+        LinkTypes::FeedToBans,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+
+    let member_target = AnyLinkableHash::from(ban.agent.clone());
+    let member_links = get_links(
+        LinkQuery::try_new(ban.feed_hash.clone(), LinkTypes::FeedToMember)?,
+        GetStrategy::Network,
+    )?;
+    for link in member_links
+        .into_iter()
+        .filter(|link| link.target == member_target)
+    {
+        delete_link(link.create_link_hash, GetOptions::network())?;
+    }
+
+    create_moderation_action(ModerationAction {
+        feed_hash: ban.feed_hash,
+        kind: ModerationKind::BanMember {
+            ban_hash: ban_hash.clone(),
+        },
+        note: None,
+    })?;
+
+    let record = get(ban_hash, GetOptions::local())?.ok_or(SharefeedError::NotFound(
+        String::from("Could not find the newly created Ban"),
+    ))?;
+    Ok(record)
+}
+
+/// Lifts an agent's bans from a feed. They are not added back as a member.
+#[hdk_extern]
+pub fn unban_member(input: UnbanMemberInput) -> ExternResult<()> {
+    trace_call!("unban_member");
+    check_is_steward(&input.feed_hash)?;
+    let bans: Vec<BanInfo> = get_bans(&input.feed_hash, ReadStrategy::Network)?
+        .into_iter()
+        .filter(|info| info.ban.agent == input.agent)
+        .collect();
+    if bans.is_empty() {
+        return Err(SharefeedError::NotFound(String::from(
+            "This agent isn't banned from the feed",
+        ))
+        .into());
+    }
+
+    for info in bans {
+        lift_ban(&input.feed_hash, &info.ban_hash)?;
+        create_moderation_action(ModerationAction {
+            feed_hash: input.feed_hash.clone(),
+            kind: ModerationKind::UnbanMember {
+                ban_hash: info.ban_hash,
+            },
+            note: input.note.clone(),
+        })?;
+    }
+    Ok(())
+}
+
+/// Bans in force in a feed, for its stewards
+#[hdk_extern]
+pub fn get_feed_bans(feed_hash: ReadInput<ActionHash>) -> ExternResult<Vec<BanInfo>> {
+    trace_call!("get_feed_bans");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    check_is_steward(&feed_hash)?;
+    get_bans(&feed_hash, strategy)
+}
@@ -0,0 +1,51 @@
+use hdk::prelude::*;
+
+use crate::error::SharefeedError;
+use crate::read::ReadStrategy;
+
+/// Fetches many records in a single host call instead of one `get` per
+/// hash. Results line up with `action_hashes`.
+pub fn get_records(
+    action_hashes: Vec<ActionHash>,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<Option<Record>>> {
+    if action_hashes.is_empty() {
+        return Ok(vec![]);
+    }
+    let inputs: Vec<GetInput> = action_hashes
+        .into_iter()
+        .map(|action_hash| GetInput::new(action_hash.into(), strategy.get_options()))
+        .collect();
+    HDK.with(|hdk| hdk.borrow().get(inputs))
+}
+
+/// The latest action on each of `agents`' chains, for validation rules that
+/// read other agents' chains up to a head the author names. I'm left out,
+/// since validation reads my chain up to the action itself.
+pub fn get_chain_heads<'a>(
+    agents: impl IntoIterator<Item = &'a AgentPubKey>,
+) -> ExternResult<Vec<ActionHash>> {
+    let me = agent_info()?.agent_initial_pubkey;
+    let mut seen: Vec<&AgentPubKey> = Vec::new();
+    let mut chain_heads: Vec<ActionHash> = Vec::new();
+    for agent in agents {
+        if agent == &me || seen.contains(&agent) {
+            continue;
+        }
+        seen.push(agent);
+        let activity = get_agent_activity(
+            agent.clone(),
+            ChainQueryFilter::new(),
+            ActivityRequest::Full,
+        )?;
+        let Some((_, chain_head)) = activity.valid_activity.last() else {
+            return Err(SharefeedError::Unreachable(format!(
+                "Could not get the chain of {}",
+                agent
+            ))
+            .into());
+        };
+        chain_heads.push(chain_head.clone());
+    }
+    Ok(chain_heads)
+}
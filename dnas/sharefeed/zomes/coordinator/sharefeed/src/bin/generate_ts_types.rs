@@ -0,0 +1,201 @@
+//! Writes TypeScript declarations for the inputs, outputs, signals and
+//! entries of the sharefeed zomes to a single module, so the UI's types
+//! can't drift from the Rust structs.
+//!
+//! cargo run -p sharefeed --features ts --bin generate_ts_types -- <out.ts>
+
+use ts_rs::TS;
+
+const DEFAULT_OUTPUT: &str = "ui/src/lib/holochain/generated.ts";
+
+const HEADER: &str = "\
+// Generated by `npm run generate:types` from the sharefeed zomes. Do not edit.
+
+import type {
+  ActionHash,
+  AgentPubKey,
+  AgentPubKeyB64,
+  CapSecret,
+  DnaHash,
+  Record as HcRecord,
+  Timestamp,
+} from '@holochain/client';
+";
+
+fn main() -> std::io::Result<()> {
+    let output = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| String::from(DEFAULT_OUTPUT));
+
+    let declarations = [
+        sharefeed::GrantExtensionCapabilityInput::decl(),
+        sharefeed::ExtensionCapability::decl(),
+        sharefeed::CapabilityGrantInfo::decl(),
+        sharefeed::CommentInfo::decl(),
+        sharefeed::GrantPostingDelegateInput::decl(),
+        sharefeed::PostingDelegation::decl(),
+        sharefeed::PostToFeedAsInput::decl(),
+        sharefeed::TrendingTagsInput::decl(),
+        sharefeed::TrendingTag::decl(),
+        sharefeed::TopSharedUrlsInput::decl(),
+        sharefeed::TopSharedUrl::decl(),
+        sharefeed::ExploreSampleInput::decl(),
+        sharefeed::SimilarSharesInput::decl(),
+        sharefeed::JsonFeed::decl(),
+        sharefeed::JsonFeedItem::decl(),
+        sharefeed::JsonFeedAuthor::decl(),
+        sharefeed::ActivityPubOutboxInput::decl(),
+        sharefeed::ActivityPubOutbox::decl(),
+        sharefeed::ActivityPubActivity::decl(),
+        sharefeed::ActivityPubNote::decl(),
+        sharefeed::ActivityPubLink::decl(),
+        sharefeed::ActivityPubHashtag::decl(),
+        sharefeed::ExportedEntry::<()>::decl(),
+        sharefeed::ExportedFeedShare::decl(),
+        sharefeed::PersonalDataExport::decl(),
+        sharefeed::UpdateFeedInput::decl(),
+        sharefeed::SetKeywordFiltersInput::decl(),
+        sharefeed::AddShareToFeedInput::decl(),
+        sharefeed::RemoveShareFromFeedInput::decl(),
+        sharefeed::FeedShareLinkInfo::decl(),
+        sharefeed::FeedSharesChunkInput::decl(),
+        sharefeed::FeedSharesChunk::decl(),
+        sharefeed::FeedInfo::decl(),
+        sharefeed::AddMemberToFeedInput::decl(),
+        sharefeed::FeedMembersCursor::decl(),
+        sharefeed::FeedMembersPageInput::decl(),
+        sharefeed::FeedMember::decl(),
+        sharefeed::FeedMembersPage::decl(),
+        sharefeed::TimelineInput::decl(),
+        sharefeed::ImportItemStatus::decl(),
+        sharefeed::ImportItemResult::decl(),
+        sharefeed::ImportReport::decl(),
+        sharefeed::ImportBookmarksInput::decl(),
+        sharefeed::ImportPocketInput::decl(),
+        sharefeed::BookmarkingService::decl(),
+        sharefeed::ImportBookmarkingServiceInput::decl(),
+        sharefeed::RestoreReport::decl(),
+        sharefeed::RequestMembershipInput::decl(),
+        sharefeed::MembershipRequestInfo::decl(),
+        sharefeed::NotificationInfo::decl(),
+        sharefeed::PushPayload::decl(),
+        sharefeed::GetNotificationsInput::decl(),
+        sharefeed::Profile::decl(),
+        sharefeed::AuthorProfile::decl(),
+        sharefeed::ShareFilter::decl(),
+        sharefeed::ReactionInfo::decl(),
+        sharefeed::ReadStrategy::decl(),
+        sharefeed::ReadInput::<()>::decl(),
+        sharefeed::SavedSearchInfo::decl(),
+        sharefeed::UpdateSavedSearchInput::decl(),
+        sharefeed::SearchSharesInput::decl(),
+        sharefeed::SearchResultsPage::decl(),
+        sharefeed::SearchFeedInput::decl(),
+        sharefeed::SearchTitlesInput::decl(),
+        sharefeed::CreateShareItemIdempotentInput::decl(),
+        sharefeed::GetShareItemResolvedInput::decl(),
+        sharefeed::RecordSource::decl(),
+        sharefeed::ResolvedRecord::decl(),
+        sharefeed::UpdateShareItemInput::decl(),
+        sharefeed::TimeRangeInput::decl(),
+        sharefeed::ShareItemInfo::decl(),
+        sharefeed::ShareSummaryInfo::decl(),
+        sharefeed::SharerFeed::decl(),
+        sharefeed::OtherSharer::decl(),
+        sharefeed::Signal::decl(),
+        sharefeed::TagCount::decl(),
+        sharefeed::AgentSummary::decl(),
+        sharefeed::AssetKind::decl(),
+        sharefeed::AssetInfo::decl(),
+        sharefeed::AssetRef::decl(),
+        sharefeed::SearchAssetsInput::decl(),
+        sharefeed::CreateShareFromWalInput::decl(),
+        sharefeed::ResolvedDeeplink::decl(),
+        sharefeed::ArchiveToVaultInput::decl(),
+        sharefeed::VaultShare::decl(),
+        sharefeed::VaultArchiveInfo::decl(),
+        sharefeed::ReportInfo::decl(),
+        sharefeed::ModerationActionInfo::decl(),
+        sharefeed::ModerationQueue::decl(),
+        sharefeed::ModerationLogInput::decl(),
+        sharefeed::ModerationLogPage::decl(),
+        sharefeed::ResolveReportInput::decl(),
+        sharefeed::ReviewPendingShareInput::decl(),
+        sharefeed::AppealInfo::decl(),
+        sharefeed::DecideAppealInput::decl(),
+        sharefeed::BanInfo::decl(),
+        sharefeed::UnbanMemberInput::decl(),
+        sharefeed::InstanceInfo::decl(),
+        sharefeed::AnchorHealth::decl(),
+        sharefeed::HealthReport::decl(),
+        sharefeed::ReactionCount::decl(),
+        sharefeed::ShareDetail::decl(),
+        sharefeed::FeedPageInput::decl(),
+        sharefeed::FeedPage::decl(),
+        sharefeed::SeenReceiptInput::decl(),
+        sharefeed::SeenReceipt::decl(),
+        sharefeed::CompilationInfo::decl(),
+        sharefeed::SavedItemInfo::decl(),
+        sharefeed::CuratorNotesInput::decl(),
+        sharefeed::CuratorNoteInfo::decl(),
+        sharefeed::TagSubscriptionInfo::decl(),
+        sharefeed::DomainSubscriptionInfo::decl(),
+        sharefeed::StewardProposalInfo::decl(),
+        sharefeed::ExternSchema::decl(),
+        sharefeed::ApiSchema::decl(),
+        sharefeed_integrity::Blocklist::decl(),
+        sharefeed_integrity::Comment::decl(),
+        sharefeed_integrity::DigestFeedSection::decl(),
+        sharefeed_integrity::Digest::decl(),
+        sharefeed_integrity::Feed::decl(),
+        sharefeed_integrity::KeywordFilterAction::decl(),
+        sharefeed_integrity::KeywordFilter::decl(),
+        sharefeed_integrity::ShareLinkSummary::decl(),
+        sharefeed_integrity::MembershipRequest::decl(),
+        sharefeed_integrity::ReportOutcome::decl(),
+        sharefeed_integrity::AppealOutcome::decl(),
+        sharefeed_integrity::Appeal::decl(),
+        sharefeed_integrity::Ban::decl(),
+        sharefeed_integrity::Compilation::decl(),
+        sharefeed_integrity::SavedItem::decl(),
+        sharefeed_integrity::CuratorNote::decl(),
+        sharefeed_integrity::TagSubscription::decl(),
+        sharefeed_integrity::DomainSubscription::decl(),
+        sharefeed_integrity::StewardProposalKind::decl(),
+        sharefeed_integrity::StewardProposal::decl(),
+        sharefeed_integrity::StewardApproval::decl(),
+        sharefeed_integrity::OnboardingState::decl(),
+        sharefeed_integrity::ModerationKind::decl(),
+        sharefeed_integrity::ModerationAction::decl(),
+        sharefeed_integrity::NetworkBlocklist::decl(),
+        sharefeed_integrity::NotificationKind::decl(),
+        sharefeed_integrity::Notification::decl(),
+        sharefeed_integrity::NotificationLevel::decl(),
+        sharefeed_integrity::FeedNotificationLevel::decl(),
+        sharefeed_integrity::QuietHours::decl(),
+        sharefeed_integrity::NotificationPrefs::decl(),
+        sharefeed_integrity::SharefeedFeatures::decl(),
+        sharefeed_integrity::SharefeedProperties::decl(),
+        sharefeed_integrity::Reaction::decl(),
+        sharefeed_integrity::AgentReputation::decl(),
+        sharefeed_integrity::ReputationCache::decl(),
+        sharefeed_integrity::ReportReason::decl(),
+        sharefeed_integrity::Report::decl(),
+        sharefeed_integrity::SavedSearch::decl(),
+        sharefeed_integrity::ShareItem::decl(),
+        sharefeed_integrity::ShareProvenance::decl(),
+        sharefeed_integrity::ShareKind::decl(),
+        sharefeed_integrity::ShareTranslation::decl(),
+        sharefeed_integrity::VaultArchive::decl(),
+    ];
+
+    let mut module = String::from(HEADER);
+    for declaration in declarations {
+        module.push_str("\nexport ");
+        module.push_str(&declaration);
+        module.push('\n');
+    }
+    std::fs::write(&output, module)?;
+    println!("Wrote {} declarations to {}", declarations.len(), output);
+    Ok(())
+}
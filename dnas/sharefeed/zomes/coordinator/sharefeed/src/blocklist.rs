@@ -0,0 +1,101 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+fn latest_blocklist_record() -> ExternResult<Option<Record>> {
+    let filter = ChainQueryFilter::new()
+        .entry_type(UnitEntryTypes::Blocklist.try_into()?)
+        .include_entries(true);
+    Ok(query(filter)?.into_iter().last())
+}
+
+#[hdk_extern]
+pub fn get_blocked_agents(_: ()) -> ExternResult<Blocklist> {
+    trace_call!("get_blocked_agents");
+    let blocklist = match latest_blocklist_record()? {
+        Some(record) => record
+            .entry()
+            .to_app_option::<Blocklist>()
+            .map_err(|e| wasm_error!(e))?
+            .unwrap_or_default(),
+        None => Blocklist::default(),
+    };
+    Ok(blocklist)
+}
+
+fn save_blocklist(blocklist: Blocklist) -> ExternResult<Blocklist> {
+    match latest_blocklist_record()? {
+        Some(record) => update_entry(record.action_address().clone(), &blocklist)?,
+        None => create_entry(&EntryTypes::Blocklist(blocklist.clone()))?,
+    };
+    Ok(blocklist)
+}
+
+#[hdk_extern]
+pub fn block_agent(agent: AgentPubKey) -> ExternResult<Blocklist> {
+    trace_call!("block_agent");
+    let mut blocklist = get_blocked_agents(())?;
+    if blocklist.blocked.contains(&agent) {
+        return Ok(blocklist);
+    }
+    blocklist.blocked.push(agent);
+    save_blocklist(blocklist)
+}
+
+#[hdk_extern]
+pub fn unblock_agent(agent: AgentPubKey) -> ExternResult<Blocklist> {
+    trace_call!("unblock_agent");
+    let mut blocklist = get_blocked_agents(())?;
+    if !blocklist.blocked.contains(&agent) {
+        return Ok(blocklist);
+    }
+    blocklist.blocked.retain(|blocked| blocked != &agent);
+    save_blocklist(blocklist)
+}
+
+#[hdk_extern]
+pub fn mute_agent(agent: AgentPubKey) -> ExternResult<Blocklist> {
+    trace_call!("mute_agent");
+    let mut blocklist = get_blocked_agents(())?;
+    if blocklist.muted.contains(&agent) {
+        return Ok(blocklist);
+    }
+    blocklist.muted.push(agent);
+    save_blocklist(blocklist)
+}
+
+#[hdk_extern]
+pub fn unmute_agent(agent: AgentPubKey) -> ExternResult<Blocklist> {
+    trace_call!("unmute_agent");
+    let mut blocklist = get_blocked_agents(())?;
+    if !blocklist.muted.contains(&agent) {
+        return Ok(blocklist);
+    }
+    blocklist.muted.retain(|muted| muted != &agent);
+    save_blocklist(blocklist)
+}
+
+/// Stops notifications about new comments and reactions on a share, e.g.
+/// for a busy thread
+#[hdk_extern]
+pub fn mute_share(share_item_hash: ActionHash) -> ExternResult<Blocklist> {
+    trace_call!("mute_share");
+    let mut blocklist = get_blocked_agents(())?;
+    if blocklist.muted_shares.contains(&share_item_hash) {
+        return Ok(blocklist);
+    }
+    blocklist.muted_shares.push(share_item_hash);
+    save_blocklist(blocklist)
+}
+
+#[hdk_extern]
+pub fn unmute_share(share_item_hash: ActionHash) -> ExternResult<Blocklist> {
+    trace_call!("unmute_share");
+    let mut blocklist = get_blocked_agents(())?;
+    if !blocklist.muted_shares.contains(&share_item_hash) {
+        return Ok(blocklist);
+    }
+    blocklist
+        .muted_shares
+        .retain(|muted| muted != &share_item_hash);
+    save_blocklist(blocklist)
+}
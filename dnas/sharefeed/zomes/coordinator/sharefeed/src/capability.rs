@@ -0,0 +1,140 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::{ActionHashSchema, AgentPubKeySchema, CapSecretSchema, TimestampSchema};
+use std::collections::{BTreeSet, HashSet};
+
+pub const EXTENSION_GRANT_TAG: &str = "browser_extension";
+
+/// Functions a browser extension may call with its grant
+const EXTENSION_FUNCTIONS: [&str; 3] = [
+    "create_share_item",
+    "create_share_item_idempotent",
+    "add_share_to_feed",
+];
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct GrantExtensionCapabilityInput {
+    /// Key the extension signs its zome calls with
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub signing_key: AgentPubKey,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ExtensionCapability {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub grant_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "CapSecret"))]
+    #[schemars(with = "CapSecretSchema")]
+    pub cap_secret: CapSecret,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct CapabilityGrantInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub grant_hash: ActionHash,
+    pub tag: String,
+    pub functions: Vec<String>,
+    #[cfg_attr(feature = "ts", ts(type = "Array<AgentPubKey>"))]
+    #[schemars(with = "Vec<AgentPubKeySchema>")]
+    pub assignees: Vec<AgentPubKey>,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+/// Creates an assigned grant that only lets `signing_key` share links,
+/// so the extension never holds an unrestricted connection to the cell
+pub fn create_extension_grant(signing_key: AgentPubKey) -> ExternResult<ExtensionCapability> {
+    let zome_name = zome_info()?.name;
+    let functions: BTreeSet<(ZomeName, FunctionName)> = EXTENSION_FUNCTIONS
+        .iter()
+        .map(|function| (zome_name.clone(), (*function).into()))
+        .collect();
+
+    let cap_secret = generate_cap_secret()?;
+    let mut assignees = BTreeSet::new();
+    assignees.insert(signing_key);
+    let grant_hash = create_cap_grant(CapGrantEntry {
+        tag: String::from(EXTENSION_GRANT_TAG),
+        access: CapAccess::Assigned {
+            secret: cap_secret,
+            assignees,
+        },
+        functions: GrantedFunctions::Listed(functions),
+    })?;
+
+    Ok(ExtensionCapability {
+        grant_hash,
+        cap_secret,
+    })
+}
+
+#[hdk_extern]
+pub fn grant_extension_capability(
+    input: GrantExtensionCapabilityInput,
+) -> ExternResult<ExtensionCapability> {
+    trace_call!("grant_extension_capability");
+    create_extension_grant(input.signing_key)
+}
+
+#[hdk_extern]
+pub fn revoke_capability(grant_hash: ActionHash) -> ExternResult<ActionHash> {
+    trace_call!("revoke_capability");
+    delete_cap_grant(grant_hash)
+}
+
+/// Live cap grants on my chain. Secrets are deliberately left out.
+#[hdk_extern]
+pub fn list_my_capability_grants(_: ()) -> ExternResult<Vec<CapabilityGrantInfo>> {
+    trace_call!("list_my_capability_grants");
+    let deleted: HashSet<ActionHash> =
+        query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::Delete(delete) => Some(delete.deletes_address.clone()),
+                _ => None,
+            })
+            .collect();
+
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(EntryType::CapGrant)
+            .include_entries(true),
+    )?;
+
+    let mut grants: Vec<CapabilityGrantInfo> = Vec::new();
+    for record in records {
+        let grant_hash = record.action_address().clone();
+        if deleted.contains(&grant_hash) {
+            continue;
+        }
+        if let Some(Entry::CapGrant(grant)) = record.entry().as_option() {
+            let functions = match &grant.functions {
+                GrantedFunctions::All => vec![String::from("*")],
+                GrantedFunctions::Listed(functions) => functions
+                    .iter()
+                    .map(|(_, function)| function.to_string())
+                    .collect(),
+            };
+            let assignees = match &grant.access {
+                CapAccess::Assigned { assignees, .. } => assignees.iter().cloned().collect(),
+                _ => vec![],
+            };
+            grants.push(CapabilityGrantInfo {
+                grant_hash,
+                tag: grant.tag.clone(),
+                functions,
+                assignees,
+                created_at: record.action().timestamp(),
+            });
+        }
+    }
+
+    Ok(grants)
+}
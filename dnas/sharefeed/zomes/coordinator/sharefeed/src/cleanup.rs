@@ -0,0 +1,122 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::export::my_deleted_actions;
+
+/// Daily, after the reputation refresh
+const LINK_CLEANUP_SCHEDULE: &str = "0 0 4 * * *";
+
+/// Links that index a share, by the share at their base or target. These
+/// are the links left behind when a share or feed is deleted.
+const SHARE_INDEX_LINK_TYPES: [LinkTypes; 10] = [
+    LinkTypes::TimeIndex,
+    LinkTypes::FeedToShare,
+    LinkTypes::ShareItemToFeed,
+    LinkTypes::FeedToPendingShare,
+    LinkTypes::AuthorToShare,
+    LinkTypes::UrlToShare,
+    LinkTypes::WordToShare,
+    LinkTypes::TrigramToShare,
+    LinkTypes::TagTimeIndex,
+    LinkTypes::UrlTimeIndex,
+];
+
+#[hdk_extern(infallible)]
+pub fn scheduled_link_cleanup(_: Option<Schedule>) -> Option<Schedule> {
+    trace_call!("scheduled_link_cleanup");
+    if let Err(err) = prune_orphaned_links(()) {
+        debug!("Failed to prune orphaned links: {:?}", err);
+    }
+    Some(Schedule::Persisted(LINK_CLEANUP_SCHEDULE.to_string()))
+}
+
+/// Whether one of a record's deletes was made by someone allowed to: its
+/// author, or a steward for a feed. Validation rejects anyone else's, but
+/// a peer may hold them before their warrant arrives.
+fn has_authorized_delete(details: &RecordDetails) -> ExternResult<bool> {
+    let author = details.record.action().author();
+    let feed_type: EntryType = UnitEntryTypes::Feed.try_into()?;
+    let stewards: Vec<AgentPubKey> = if details.record.action().entry_type() == Some(&feed_type) {
+        details
+            .record
+            .entry()
+            .to_app_option::<Feed>()
+            .map_err(|e| wasm_error!(e))?
+            .map(|feed| feed.stewards)
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    Ok(details.deletes.iter().any(|delete| {
+        let deleter = &delete.hashed.content.author;
+        deleter == author || stewards.contains(deleter)
+    }))
+}
+
+/// Whether the record at `address` is known to be deleted. Records this
+/// peer hasn't seen count as live, so nothing is pruned on a guess.
+fn is_deleted(
+    address: &AnyLinkableHash,
+    my_deleted: &HashSet<ActionHash>,
+    seen: &mut HashMap<ActionHash, bool>,
+) -> ExternResult<bool> {
+    let Ok(action_hash) = ActionHash::try_from(address.clone()) else {
+        return Ok(false);
+    };
+    if let Some(deleted) = seen.get(&action_hash) {
+        return Ok(*deleted);
+    }
+    let deleted = my_deleted.contains(&action_hash)
+        || match get_details(action_hash.clone(), GetOptions::local())? {
+            Some(Details::Record(details)) => has_authorized_delete(&details)?,
+            _ => false,
+        };
+    seen.insert(action_hash, deleted);
+    Ok(deleted)
+}
+
+/// Deletes share index links I authored whose share or feed has since
+/// been deleted. Returns how many links were removed.
+#[hdk_extern]
+pub fn prune_orphaned_links(_: ()) -> ExternResult<usize> {
+    trace_call!("prune_orphaned_links");
+    let index_types = SHARE_INDEX_LINK_TYPES
+        .into_iter()
+        .map(ScopedLinkType::try_from)
+        .collect::<ExternResult<Vec<ScopedLinkType>>>()?;
+
+    let removed_links: HashSet<ActionHash> =
+        query(ChainQueryFilter::new().action_type(ActionType::DeleteLink))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::DeleteLink(delete_link) => Some(delete_link.link_add_address.clone()),
+                _ => None,
+            })
+            .collect();
+    let my_deleted = my_deleted_actions()?;
+    let mut seen: HashMap<ActionHash, bool> = HashMap::new();
+
+    let mut pruned = 0;
+    for record in query(ChainQueryFilter::new().action_type(ActionType::CreateLink))? {
+        let Action::CreateLink(create_link) = record.action() else {
+            continue;
+        };
+        let link_hash = record.action_address();
+        if removed_links.contains(link_hash)
+            || !index_types.iter().any(|scoped| {
+                scoped.zome_index == create_link.zome_index
+                    && scoped.zome_type == create_link.link_type
+            })
+        {
+            continue;
+        }
+        if is_deleted(&create_link.base_address, &my_deleted, &mut seen)?
+            || is_deleted(&create_link.target_address, &my_deleted, &mut seen)?
+        {
+            delete_link(link_hash.clone(), GetOptions::local())?;
+            pruned += 1;
+        }
+    }
+    Ok(pruned)
+}
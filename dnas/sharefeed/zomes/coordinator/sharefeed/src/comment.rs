@@ -0,0 +1,153 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::blocklist::get_blocked_agents;
+use crate::error::SharefeedError;
+use crate::input::check_comment;
+use crate::profiles::{AuthorProfile, AuthorProfileCache};
+use crate::read::ReadInput;
+use crate::signals::Signal;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct CommentInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub comment_hash: ActionHash,
+    pub comment: Comment,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub author: AgentPubKey,
+    pub author_profile: Option<AuthorProfile>,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+/// The share's author plus everyone who has commented on it
+pub fn get_share_participants(share_item_hash: &ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    let mut participants: Vec<AgentPubKey> = Vec::new();
+    if let Some(record) = get(share_item_hash.clone(), GetOptions::local())? {
+        participants.push(record.action().author().clone());
+    }
+
+    let links = get_links(
+        LinkQuery::try_new(share_item_hash.clone(), LinkTypes::ShareItemToComments)?,
+        GetStrategy::Local,
+    )?;
+    for link in links {
+        if !participants.contains(&link.author) {
+            participants.push(link.author);
+        }
+    }
+    Ok(participants)
+}
+
+#[hdk_extern]
+pub fn create_comment(comment: Comment) -> ExternResult<Record> {
+    trace_call!("create_comment");
+    check_comment(&comment, &sharefeed_properties()?)?;
+    let comment_hash = create_entry(&EntryTypes::Comment(comment.clone()))?;
+    create_link(
+        comment.share_item_hash.clone(),
+        comment_hash.clone(),
+        LinkTypes::ShareItemToComments,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+
+    let signal = Signal::CommentAdded {
+        share_item_hash: comment.share_item_hash.clone(),
+        comment_hash: comment_hash.clone(),
+        author: agent_info()?.agent_initial_pubkey,
+    };
+    // The share's participants hear about it from post_commit, once
+    // they can fetch the comment from me
+    emit_signal(&signal)?;
+
+    let record = get(comment_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the newly created Comment")),
+    )?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_comments(share_item_hash: ReadInput<ActionHash>) -> ExternResult<Vec<CommentInfo>> {
+    trace_call!("get_comments");
+    let (share_item_hash, strategy) = share_item_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(share_item_hash, LinkTypes::ShareItemToComments)?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut profiles = AuthorProfileCache::default();
+    let blocklist = get_blocked_agents(())?;
+    let mut comments: Vec<CommentInfo> = Vec::new();
+    for link in links {
+        let comment_hash =
+            ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
+        if let Some(record) = get(comment_hash.clone(), strategy.get_options())? {
+            if blocklist.hides(record.action().author()) {
+                continue;
+            }
+            if let Some(comment) = record
+                .entry()
+                .to_app_option::<Comment>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                let author = record.action().author().clone();
+                comments.push(CommentInfo {
+                    comment_hash,
+                    comment,
+                    author_profile: profiles.get(&author),
+                    author,
+                    created_at: link.timestamp,
+                });
+            }
+        }
+    }
+
+    // Oldest first, like a conversation
+    comments.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+    Ok(comments)
+}
+
+/// Number of comments on a share, from its links alone
+#[hdk_extern]
+pub fn count_comments(share_item_hash: ReadInput<ActionHash>) -> ExternResult<usize> {
+    trace_call!("count_comments");
+    let (share_item_hash, strategy) = share_item_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(share_item_hash, LinkTypes::ShareItemToComments)?,
+        strategy.get_strategy(),
+    )?;
+    Ok(links.len())
+}
+
+#[hdk_extern]
+pub fn delete_comment(comment_hash: ActionHash) -> ExternResult<ActionHash> {
+    trace_call!("delete_comment");
+    let record = get(comment_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the Comment to delete")),
+    )?;
+    let comment: Comment = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Record is not a Comment",
+        )))?;
+
+    let links = get_links(
+        LinkQuery::try_new(comment.share_item_hash, LinkTypes::ShareItemToComments)?,
+        GetStrategy::Local,
+    )?;
+    for link in links {
+        if link.target == AnyLinkableHash::from(comment_hash.clone()) {
+            delete_link(link.create_link_hash, GetOptions::local())?;
+        }
+    }
+
+    delete_entry(comment_hash)
+}
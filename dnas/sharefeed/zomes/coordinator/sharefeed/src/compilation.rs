@@ -0,0 +1,141 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::read::{ReadInput, ReadStrategy};
+use crate::report::check_is_steward;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct CompilationInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub compilation_hash: ActionHash,
+    pub compilation: Compilation,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub steward: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+/// Compilations the links point at, newest first
+fn compilation_infos_from_links(
+    links: Vec<Link>,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<CompilationInfo>> {
+    let mut compilations: Vec<CompilationInfo> = Vec::new();
+    for link in links {
+        let compilation_hash = ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        if compilations
+            .iter()
+            .any(|info| info.compilation_hash == compilation_hash)
+        {
+            continue;
+        }
+        if let Some(record) = get(compilation_hash.clone(), strategy.get_options())? {
+            if let Some(compilation) = record
+                .entry()
+                .to_app_option::<Compilation>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                compilations.push(CompilationInfo {
+                    compilation_hash,
+                    compilation,
+                    steward: record.action().author().clone(),
+                    created_at: record.action().timestamp(),
+                });
+            }
+        }
+    }
+
+    compilations.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(compilations)
+}
+
+/// Publishes a steward's roundup of shares in their feed. Each included
+/// share links to the compilation, so readers of a share can find it.
+#[hdk_extern]
+pub fn create_compilation(compilation: Compilation) -> ExternResult<Record> {
+    trace_call!("create_compilation");
+    check_is_steward(&compilation.feed_hash)?;
+    if compilation.share_item_hashes.is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "A compilation must include at least one share",
+        ))
+        .into());
+    }
+    if compilation.share_item_hashes.len() > MAX_COMPILATION_SHARES {
+        return Err(SharefeedError::InvalidInput(format!(
+            "A compilation can include at most {} shares",
+            MAX_COMPILATION_SHARES
+        ))
+        .into());
+    }
+    let feed_shares: Vec<AnyLinkableHash> = get_links(
+        LinkQuery::try_new(compilation.feed_hash.clone(), LinkTypes::FeedToShare)?,
+        GetStrategy::Network,
+    )?
+    .into_iter()
+    .map(|link| link.target)
+    .collect();
+    if compilation
+        .share_item_hashes
+        .iter()
+        .any(|share_item_hash| !feed_shares.contains(&share_item_hash.clone().into()))
+    {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "A compilation can only include shares in its feed",
+        ))
+        .into());
+    }
+
+    let compilation_hash = create_entry(&EntryTypes::Compilation(compilation.clone()))?;
+    create_link(
+        compilation.feed_hash.clone(),
+        compilation_hash.clone(),
+        LinkTypes::FeedToCompilations,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    for share_item_hash in compilation.share_item_hashes {
+        create_link(
+            share_item_hash,
+            compilation_hash.clone(),
+            LinkTypes::ShareItemToCompilations,
+            SharefeedLinkTag::new().to_link_tag()?,
+        )?;
+    }
+
+    let record = get(compilation_hash, GetOptions::local())?.ok_or(SharefeedError::NotFound(
+        String::from("Could not find the newly created Compilation"),
+    ))?;
+    Ok(record)
+}
+
+/// A feed's compilations, newest first
+#[hdk_extern]
+pub fn get_compilations(feed_hash: ReadInput<ActionHash>) -> ExternResult<Vec<CompilationInfo>> {
+    trace_call!("get_compilations");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToCompilations)?,
+        strategy.get_strategy(),
+    )?;
+    compilation_infos_from_links(links, strategy)
+}
+
+/// Compilations that include a share, newest first
+#[hdk_extern]
+pub fn get_compilations_for_share(
+    share_item_hash: ReadInput<ActionHash>,
+) -> ExternResult<Vec<CompilationInfo>> {
+    trace_call!("get_compilations_for_share");
+    let (share_item_hash, strategy) = share_item_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(share_item_hash, LinkTypes::ShareItemToCompilations)?,
+        strategy.get_strategy(),
+    )?;
+    compilation_infos_from_links(links, strategy)
+}
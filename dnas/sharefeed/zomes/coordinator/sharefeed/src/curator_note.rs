@@ -0,0 +1,163 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::feed::get_feed_members;
+use crate::read::ReadInput;
+use crate::report::check_is_steward;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct CuratorNotesInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct CuratorNoteInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub curator_note_hash: ActionHash,
+    pub curator_note: CuratorNote,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub steward: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+/// Adds a steward's note to a share in one of their feeds
+#[hdk_extern]
+pub fn create_curator_note(curator_note: CuratorNote) -> ExternResult<Record> {
+    trace_call!("create_curator_note");
+    check_is_steward(&curator_note.feed_hash)?;
+    if curator_note.content.trim().is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Curator note content cannot be empty",
+        ))
+        .into());
+    }
+    let share_target = AnyLinkableHash::from(curator_note.share_item_hash.clone());
+    if !get_links(
+        LinkQuery::try_new(curator_note.feed_hash.clone(), LinkTypes::FeedToShare)?,
+        GetStrategy::Network,
+    )?
+    .iter()
+    .any(|link| link.target == share_target)
+    {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Curator notes can only be added to shares in the feed",
+        ))
+        .into());
+    }
+
+    let curator_note_hash = create_entry(&EntryTypes::CuratorNote(curator_note.clone()))?;
+    create_link(
+        curator_note.share_item_hash,
+        curator_note_hash.clone(),
+        LinkTypes::ShareItemToCuratorNotes,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+
+    let record = get(curator_note_hash, GetOptions::local())?.ok_or(SharefeedError::NotFound(
+        String::from("Could not find the newly created CuratorNote"),
+    ))?;
+    Ok(record)
+}
+
+/// Steward notes on a share in a feed, oldest first. Only members of the
+/// feed see them.
+#[hdk_extern]
+pub fn get_curator_notes(
+    input: ReadInput<CuratorNotesInput>,
+) -> ExternResult<Vec<CuratorNoteInfo>> {
+    trace_call!("get_curator_notes");
+    let (input, strategy) = input.into_parts();
+    let members = get_feed_members(ReadInput::WithStrategy {
+        input: input.feed_hash.clone(),
+        strategy,
+    })?;
+    if !members.contains(&agent_info()?.agent_initial_pubkey) {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only members of this feed can see its curator notes",
+        ))
+        .into());
+    }
+
+    let links = get_links(
+        LinkQuery::try_new(input.share_item_hash, LinkTypes::ShareItemToCuratorNotes)?,
+        strategy.get_strategy(),
+    )?;
+    let mut curator_notes: Vec<CuratorNoteInfo> = Vec::new();
+    for link in links {
+        let curator_note_hash =
+            ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        if curator_notes
+            .iter()
+            .any(|info| info.curator_note_hash == curator_note_hash)
+        {
+            continue;
+        }
+        let Some(record) = get(curator_note_hash.clone(), strategy.get_options())? else {
+            continue;
+        };
+        let Some(curator_note) = record
+            .entry()
+            .to_app_option::<CuratorNote>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        if curator_note.feed_hash != input.feed_hash {
+            continue;
+        }
+        curator_notes.push(CuratorNoteInfo {
+            curator_note_hash,
+            curator_note,
+            steward: record.action().author().clone(),
+            created_at: record.action().timestamp(),
+        });
+    }
+
+    curator_notes.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(curator_notes)
+}
+
+/// Removes a curator note; any steward of its feed can
+#[hdk_extern]
+pub fn delete_curator_note(curator_note_hash: ActionHash) -> ExternResult<()> {
+    trace_call!("delete_curator_note");
+    let record = get(curator_note_hash.clone(), GetOptions::network())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the CuratorNote to delete")),
+    )?;
+    let curator_note: CuratorNote = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Record is not a CuratorNote",
+        )))?;
+    check_is_steward(&curator_note.feed_hash)?;
+
+    let note_target = AnyLinkableHash::from(curator_note_hash);
+    let links = get_links(
+        LinkQuery::try_new(
+            curator_note.share_item_hash,
+            LinkTypes::ShareItemToCuratorNotes,
+        )?,
+        GetStrategy::Network,
+    )?;
+    for link in links {
+        if link.target == note_target {
+            delete_link(link.create_link_hash, GetOptions::network())?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::blocklist::get_blocked_agents;
+use crate::error::SharefeedError;
+use crate::feed::{get_feed, get_feed_members, FeedInfo};
+use crate::network_blocklist::get_network_blocklist;
+use crate::profiles::AuthorProfileCache;
+use crate::read::{ReadInput, ReadStrategy};
+use crate::share_item::{get_share_item, ShareItemInfo};
+use crate::summary::{get_agent_summary, AgentSummary};
+
+pub const DEEPLINK_SCHEME: &str = "sharefeed://";
+
+pub fn share_deeplink(share_item_hash: &ActionHash) -> String {
+    format!(
+        "{}share/{}",
+        DEEPLINK_SCHEME,
+        ActionHashB64::from(share_item_hash.clone())
+    )
+}
+
+pub fn feed_deeplink(feed_hash: &ActionHash) -> String {
+    format!(
+        "{}feed/{}",
+        DEEPLINK_SCHEME,
+        ActionHashB64::from(feed_hash.clone())
+    )
+}
+
+pub fn agent_deeplink(agent: &AgentPubKey) -> String {
+    format!(
+        "{}agent/{}",
+        DEEPLINK_SCHEME,
+        AgentPubKeyB64::from(agent.clone())
+    )
+}
+
+/// What a deep link points at, as parsed from its path
+#[derive(Debug, Clone, PartialEq)]
+pub enum Deeplink {
+    Feed(ActionHash),
+    Share(ActionHash),
+    Agent(AgentPubKey),
+}
+
+/// Parses `sharefeed://<kind>/<hash>`, or the same path without the
+/// scheme. A trailing slash, query or fragment is ignored.
+pub fn parse_deeplink(path: &str) -> Result<Deeplink, SharefeedError> {
+    let path = path.trim();
+    let path = path.strip_prefix(DEEPLINK_SCHEME).unwrap_or(path);
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let invalid = || SharefeedError::InvalidInput(format!("Not a ShareFeed link: {}", path));
+    let (kind, hash) = path.trim_matches('/').split_once('/').ok_or_else(invalid)?;
+    match kind {
+        "feed" => ActionHashB64::from_b64_str(hash)
+            .map(|hash| Deeplink::Feed(hash.into()))
+            .map_err(|_| invalid()),
+        "share" => ActionHashB64::from_b64_str(hash)
+            .map(|hash| Deeplink::Share(hash.into()))
+            .map_err(|_| invalid()),
+        "agent" => AgentPubKeyB64::from_b64_str(hash)
+            .map(|agent| Deeplink::Agent(agent.into()))
+            .map_err(|_| invalid()),
+        _ => Err(invalid()),
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum ResolvedDeeplink {
+    Feed { feed: FeedInfo },
+    Share { share: ShareItemInfo },
+    Agent { summary: AgentSummary },
+}
+
+/// Reads the original record at `hash` and the latest version of its
+/// entry through `get_latest`
+fn get_original_and_latest<T>(
+    hash: &ActionHash,
+    strategy: ReadStrategy,
+    get_latest: fn(ReadInput<ActionHash>) -> ExternResult<Option<Record>>,
+    not_found: &str,
+) -> ExternResult<(Record, T)>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    let not_found = || SharefeedError::NotFound(String::from(not_found));
+    let original = get(hash.clone(), strategy.get_options())?.ok_or_else(not_found)?;
+    let latest = get_latest(ReadInput::WithStrategy {
+        input: hash.clone(),
+        strategy,
+    })?
+    .ok_or_else(not_found)?;
+    let entry = latest
+        .entry()
+        .to_app_option::<T>()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or_else(not_found)?;
+    Ok((original, entry))
+}
+
+/// Opens a `sharefeed://` link in one call: the feed, share or agent it
+/// points at, hydrated the way listings show them. Private feeds only
+/// resolve for their members, and shares hidden from listings by my
+/// blocklist or the network blocklist don't resolve at all.
+#[hdk_extern]
+pub fn resolve_deeplink(path: ReadInput<String>) -> ExternResult<ResolvedDeeplink> {
+    trace_call!("resolve_deeplink");
+    let (path, strategy) = path.into_parts();
+    match parse_deeplink(&path)? {
+        Deeplink::Feed(feed_hash) => {
+            let (original, feed) = get_original_and_latest::<Feed>(
+                &feed_hash,
+                strategy,
+                get_feed,
+                "Could not find the Feed",
+            )?;
+            if !feed.is_public
+                && !get_feed_members(ReadInput::WithStrategy {
+                    input: feed_hash.clone(),
+                    strategy,
+                })?
+                .contains(&agent_info()?.agent_initial_pubkey)
+            {
+                return Err(SharefeedError::NotAuthorized(String::from(
+                    "Only members can open a private feed",
+                ))
+                .into());
+            }
+            Ok(ResolvedDeeplink::Feed {
+                feed: FeedInfo {
+                    action_hash: feed_hash,
+                    feed,
+                    created_at: original.action().timestamp(),
+                },
+            })
+        }
+        Deeplink::Share(share_item_hash) => {
+            let (original, share_item) = get_original_and_latest::<ShareItem>(
+                &share_item_hash,
+                strategy,
+                get_share_item,
+                "Could not find the ShareItem",
+            )?;
+            let author = original.action().author().clone();
+            let network_blocklist = get_network_blocklist(().into())?;
+            let banned_domain = url_domain(&share_item.url)
+                .is_some_and(|domain| network_blocklist.bans_domain(&domain));
+            if get_blocked_agents(())?.hides(&author)
+                || network_blocklist.bans_agent(&author)
+                || banned_domain
+            {
+                return Err(
+                    SharefeedError::NotFound(String::from("Could not find the ShareItem")).into(),
+                );
+            }
+            Ok(ResolvedDeeplink::Share {
+                share: ShareItemInfo {
+                    action_hash: share_item_hash,
+                    share_item,
+                    created_at: original.action().timestamp(),
+                    author_profile: AuthorProfileCache::default().get(&author),
+                    author,
+                },
+            })
+        }
+        Deeplink::Agent(agent) => Ok(ResolvedDeeplink::Agent {
+            summary: get_agent_summary(ReadInput::WithStrategy {
+                input: agent,
+                strategy,
+            })?,
+        }),
+    }
+}
@@ -0,0 +1,185 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::BTreeSet;
+
+use crate::error::SharefeedError;
+use crate::feed::{add_share_to_feed, get_feed, AddShareToFeedInput};
+use crate::network_blocklist::check_network_blocklist;
+use crate::share_item::create_share_item;
+
+/// Cap grant/claim tag for posting into one feed on a steward's behalf
+fn delegation_tag(feed_hash: &ActionHash) -> String {
+    format!("feed_delegate:{}", ActionHashB64::from(feed_hash.clone()))
+}
+
+fn is_feed_steward(feed_hash: &ActionHash, agent: &AgentPubKey) -> ExternResult<bool> {
+    let feed: Feed = get_feed(feed_hash.clone().into())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Feed",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    Ok(feed.stewards.contains(agent))
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct GrantPostingDelegateInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub delegate: AgentPubKey,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct PostingDelegation {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "CapSecret"))]
+    #[schemars(with = "CapSecretSchema")]
+    pub secret: CapSecret,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct PostToFeedAsInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub share_item: ShareItem,
+}
+
+/// Lets `delegate` post into the feed as me: creates an assigned grant for
+/// `post_to_feed_as` and hands the claim to the delegate by remote call.
+/// Revoke it with `revoke_capability`.
+#[hdk_extern]
+pub fn grant_posting_delegate(input: GrantPostingDelegateInput) -> ExternResult<ActionHash> {
+    trace_call!("grant_posting_delegate");
+    if !is_feed_steward(&input.feed_hash, &agent_info()?.agent_initial_pubkey)? {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only stewards can delegate posting to a feed",
+        ))
+        .into());
+    }
+
+    let secret = generate_cap_secret()?;
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_info()?.name, "post_to_feed_as".into()));
+    let mut assignees = BTreeSet::new();
+    assignees.insert(input.delegate.clone());
+    let grant_hash = create_cap_grant(CapGrantEntry {
+        tag: delegation_tag(&input.feed_hash),
+        access: CapAccess::Assigned { secret, assignees },
+        functions: GrantedFunctions::Listed(functions),
+    })?;
+
+    let response = call_remote(
+        input.delegate,
+        zome_info()?.name,
+        "receive_posting_delegation".into(),
+        None,
+        PostingDelegation {
+            feed_hash: input.feed_hash,
+            secret,
+        },
+    )?;
+    if !matches!(response, ZomeCallResponse::Ok(_)) {
+        return Err(SharefeedError::Unreachable(format!(
+            "Could not deliver the delegation: {:?}",
+            response
+        ))
+        .into());
+    }
+
+    Ok(grant_hash)
+}
+
+/// Called remotely by `grant_posting_delegate`; keeps the claim so
+/// `post_as_feed` can use it later
+#[hdk_extern]
+pub fn receive_posting_delegation(input: PostingDelegation) -> ExternResult<ActionHash> {
+    trace_call!("receive_posting_delegation");
+    let grantor = call_info()?.provenance;
+    if !is_feed_steward(&input.feed_hash, &grantor)? {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only stewards can delegate posting to a feed",
+        ))
+        .into());
+    }
+
+    create_cap_claim(CapClaimEntry::new(
+        delegation_tag(&input.feed_hash),
+        grantor,
+        input.secret,
+    ))
+}
+
+/// Called remotely by a delegate. Runs on the steward's cell, so the share
+/// and its feed link are authored by the steward rather than the delegate.
+#[hdk_extern]
+pub fn post_to_feed_as(input: PostToFeedAsInput) -> ExternResult<ActionHash> {
+    trace_call!("post_to_feed_as");
+    let call_info = call_info()?;
+    // The grant only covers the feed it was issued for
+    if let CapGrant::RemoteAgent(grant) = &call_info.cap_grant {
+        if grant.tag != delegation_tag(&input.feed_hash) {
+            return Err(SharefeedError::NotAuthorized(String::from(
+                "Delegation does not cover this feed",
+            ))
+            .into());
+        }
+    }
+    check_network_blocklist(&call_info.provenance, Some(&input.share_item.url))?;
+
+    let share_item_hash = create_share_item(input.share_item)?
+        .action_address()
+        .clone();
+    add_share_to_feed(AddShareToFeedInput {
+        feed_hash: input.feed_hash,
+        share_item_hash: share_item_hash.clone(),
+    })?;
+    Ok(share_item_hash)
+}
+
+/// Delegate side: posts through the steward holding the feed's delegation
+#[hdk_extern]
+pub fn post_as_feed(input: PostToFeedAsInput) -> ExternResult<ActionHash> {
+    trace_call!("post_as_feed");
+    let tag = delegation_tag(&input.feed_hash);
+    let claim = query(
+        ChainQueryFilter::new()
+            .entry_type(EntryType::CapClaim)
+            .include_entries(true),
+    )?
+    .into_iter()
+    .rev()
+    .find_map(|record| match record.entry().as_option() {
+        Some(Entry::CapClaim(claim)) if claim.tag == tag => Some(claim.clone()),
+        _ => None,
+    })
+    .ok_or(SharefeedError::NotAuthorized(String::from(
+        "No posting delegation for this feed",
+    )))?;
+
+    match call_remote(
+        claim.grantor,
+        zome_info()?.name,
+        "post_to_feed_as".into(),
+        Some(claim.secret),
+        input,
+    )? {
+        ZomeCallResponse::Ok(result) => result.decode().map_err(|e| wasm_error!(e)),
+        other => {
+            Err(SharefeedError::Unreachable(format!("Delegated post failed: {:?}", other)).into())
+        }
+    }
+}
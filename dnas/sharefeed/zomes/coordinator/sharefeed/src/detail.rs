@@ -0,0 +1,211 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::comment::count_comments;
+use crate::feed::{
+    get_feed, get_feed_members, get_feeds_for_share, get_my_feeds, with_feed_tags, FeedInfo,
+};
+use crate::profiles::AuthorProfileCache;
+use crate::reaction::get_reactions;
+use crate::read::{ReadInput, ReadStrategy};
+use crate::search::{search_results_page, SearchResultsPage};
+use crate::share_item::{
+    dedup_links_by_target, get_share_item, localize_share_infos, ShareItemInfo, SharerFeed,
+};
+use crate::vault::get_vault_archive;
+
+/// How many reactions a share has with one emoji
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ReactionCount {
+    pub emoji: String,
+    pub count: u32,
+    /// Whether one of them is mine
+    pub mine: bool,
+}
+
+/// Everything a share's page shows, read in one call
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ShareDetail {
+    /// The latest revision, under the share's original action hash
+    pub share: ShareItemInfo,
+    /// Feeds the share is in that I can see
+    pub feeds: Vec<SharerFeed>,
+    pub comment_count: u32,
+    /// Reaction counts per emoji, in the order each emoji was first used
+    pub reactions: Vec<ReactionCount>,
+    /// Whether I've archived the share to my vault
+    pub saved: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedPageInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    /// Only return shares added before this timestamp
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub cursor: Option<Timestamp>,
+    pub limit: Option<usize>,
+}
+
+/// Everything a feed's page shows, read in one call
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedPage {
+    /// The latest revision, under the feed's original action hash
+    pub feed: FeedInfo,
+    pub member_count: u32,
+    /// One page of the feed's shares, newest first
+    pub shares: SearchResultsPage,
+}
+
+/// Groups reactions by emoji, in the order each emoji was first used
+fn reaction_counts(
+    share_item_hash: ActionHash,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<ReactionCount>> {
+    let me = agent_info()?.agent_initial_pubkey;
+    let mut counts: Vec<ReactionCount> = Vec::new();
+    for info in get_reactions(ReadInput::WithStrategy {
+        input: share_item_hash,
+        strategy,
+    })? {
+        let mine = info.author == me;
+        match counts
+            .iter_mut()
+            .find(|count| count.emoji == info.reaction.emoji)
+        {
+            Some(count) => {
+                count.count += 1;
+                count.mine |= mine;
+            }
+            None => counts.push(ReactionCount {
+                emoji: info.reaction.emoji,
+                count: 1,
+                mine,
+            }),
+        }
+    }
+    Ok(counts)
+}
+
+/// A share with its author, feeds, comment count, reactions and whether
+/// I've saved it, so rendering a share page takes a single zome call.
+/// Private feeds are only listed for their members.
+#[hdk_extern]
+pub fn get_share_detail(
+    share_item_hash: ReadInput<ActionHash>,
+) -> ExternResult<Option<ShareDetail>> {
+    trace_call!("get_share_detail");
+    let language = share_item_hash.language();
+    let (share_item_hash, strategy) = share_item_hash.into_parts();
+    let read = |input: ActionHash| ReadInput::WithStrategy { input, strategy };
+
+    let Some(original) = get(share_item_hash.clone(), strategy.get_options())? else {
+        return Ok(None);
+    };
+    let Some(latest) = get_share_item(read(share_item_hash.clone()))? else {
+        return Ok(None);
+    };
+    let Some(share_item) = latest
+        .entry()
+        .to_app_option::<ShareItem>()
+        .map_err(|e| wasm_error!(e))?
+    else {
+        return Ok(None);
+    };
+    let share_item = match &language {
+        Some(language) => share_item.localized(language),
+        None => share_item,
+    };
+    let author = original.action().author().clone();
+
+    let my_feeds: Vec<ActionHash> = get_my_feeds(ReadInput::WithStrategy {
+        input: (),
+        strategy,
+    })?
+    .into_iter()
+    .map(|info| info.action_hash)
+    .collect();
+    let mut feeds: Vec<SharerFeed> = Vec::new();
+    for feed_hash in get_feeds_for_share(share_item_hash.clone(), strategy)? {
+        let Some(record) = get_feed(read(feed_hash.clone()))? else {
+            continue;
+        };
+        if let Some(feed) = record
+            .entry()
+            .to_app_option::<Feed>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            if feed.is_public || my_feeds.contains(&feed_hash) {
+                feeds.push(SharerFeed {
+                    feed_hash,
+                    name: feed.name,
+                });
+            }
+        }
+    }
+
+    Ok(Some(ShareDetail {
+        comment_count: count_comments(read(share_item_hash.clone()))? as u32,
+        reactions: reaction_counts(share_item_hash.clone(), strategy)?,
+        saved: get_vault_archive(share_item_hash.clone())?.is_some(),
+        feeds,
+        share: ShareItemInfo {
+            action_hash: share_item_hash,
+            share_item,
+            created_at: original.action().timestamp(),
+            author_profile: AuthorProfileCache::default().get(&author),
+            author,
+        },
+    }))
+}
+
+/// A feed with its member count and one page of its shares, newest first,
+/// so cold-loading a feed takes a single zome call. Pass the page's
+/// `next_cursor` back to fetch older shares.
+#[hdk_extern]
+pub fn get_feed_page(input: ReadInput<FeedPageInput>) -> ExternResult<Option<FeedPage>> {
+    trace_call!("get_feed_page");
+    let language = input.language();
+    let (input, strategy) = input.into_parts();
+    let read = |input: ActionHash| ReadInput::WithStrategy { input, strategy };
+
+    let Some(original) = get(input.feed_hash.clone(), strategy.get_options())? else {
+        return Ok(None);
+    };
+    let Some(latest) = get_feed(read(input.feed_hash.clone()))? else {
+        return Ok(None);
+    };
+    let Some(feed) = latest
+        .entry()
+        .to_app_option::<Feed>()
+        .map_err(|e| wasm_error!(e))?
+    else {
+        return Ok(None);
+    };
+
+    let links = get_links(
+        LinkQuery::try_new(input.feed_hash.clone(), LinkTypes::FeedToShare)?,
+        strategy.get_strategy(),
+    )?;
+    let mut links = dedup_links_by_target(links);
+    links.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    let mut shares = search_results_page(links.clone(), input.cursor, input.limit, strategy)?;
+    shares.shares = localize_share_infos(with_feed_tags(shares.shares, &links), language);
+
+    Ok(Some(FeedPage {
+        member_count: get_feed_members(read(input.feed_hash.clone()))?.len() as u32,
+        shares,
+        feed: FeedInfo {
+            action_hash: input.feed_hash,
+            feed,
+            created_at: original.action().timestamp(),
+        },
+    }))
+}
@@ -0,0 +1,72 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+use crate::feed::{get_feed_shares, get_my_feeds};
+use crate::signals::Signal;
+
+const WEEK_IN_MICROS: i64 = 7 * 24 * 60 * 60 * 1_000_000;
+
+/// Every Monday at 08:00 UTC
+const WEEKLY_DIGEST_SCHEDULE: &str = "0 0 8 * * Mon";
+
+#[hdk_extern(infallible)]
+pub fn scheduled_weekly_digest(_: Option<Schedule>) -> Option<Schedule> {
+    trace_call!("scheduled_weekly_digest");
+    if let Err(err) = create_weekly_digest() {
+        debug!("Failed to create weekly digest: {:?}", err);
+    }
+    Some(Schedule::Persisted(WEEKLY_DIGEST_SCHEDULE.to_string()))
+}
+
+/// Compiles the past week's shares across my feeds into a Digest entry.
+/// Nothing is written if none of my feeds had new shares.
+fn create_weekly_digest() -> ExternResult<Option<ActionHash>> {
+    let period_end = sys_time()?;
+    let period_start = Timestamp::from_micros(period_end.as_micros() - WEEK_IN_MICROS);
+
+    let mut sections: Vec<DigestFeedSection> = Vec::new();
+    for feed_info in get_my_feeds(().into())? {
+        let share_item_hashes: Vec<ActionHash> =
+            get_feed_shares(feed_info.action_hash.clone().into())?
+                .into_iter()
+                .filter(|share| share.created_at >= period_start)
+                .map(|share| share.action_hash)
+                .collect();
+        if !share_item_hashes.is_empty() {
+            sections.push(DigestFeedSection {
+                feed_hash: feed_info.action_hash,
+                feed_name: feed_info.feed.name,
+                share_item_hashes,
+            });
+        }
+    }
+
+    if sections.is_empty() {
+        return Ok(None);
+    }
+
+    let digest_hash = create_entry(&EntryTypes::Digest(Digest {
+        period_start,
+        period_end,
+        sections,
+    }))?;
+    emit_signal(Signal::DigestReady {
+        digest_hash: digest_hash.clone(),
+    })?;
+    Ok(Some(digest_hash))
+}
+
+#[hdk_extern]
+pub fn get_latest_digest(_: ()) -> ExternResult<Option<Digest>> {
+    trace_call!("get_latest_digest");
+    let filter = ChainQueryFilter::new()
+        .entry_type(UnitEntryTypes::Digest.try_into()?)
+        .include_entries(true);
+    match query(filter)?.into_iter().last() {
+        Some(record) => record
+            .entry()
+            .to_app_option::<Digest>()
+            .map_err(|e| wasm_error!(e)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,438 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::SharefeedError;
+use crate::read::{ReadInput, ReadStrategy};
+use crate::share_item::{
+    dedup_links_by_target, get_share_item, share_item_infos_from_links, time_bucket_for_timestamp,
+    time_path_for_timestamp, ShareItemInfo,
+};
+
+const WEEK_IN_MICROS: i64 = 7 * 24 * 60 * 60 * 1_000_000;
+
+/// Previous weeks compared against the current one by default
+const DEFAULT_TRENDING_WINDOW: u32 = 4;
+/// Longest window any discovery ranking looks back over
+const MAX_WINDOW_WEEKS: u32 = 26;
+const DEFAULT_TRENDING_LIMIT: usize = 20;
+/// Weeks, counting the current one, ranked by default
+const DEFAULT_TOP_URLS_WINDOW: u32 = 1;
+const DEFAULT_TOP_URLS_LIMIT: usize = 10;
+/// Earliest shares of a URL tried when picking one to represent it
+const REPRESENTATIVE_SHARE_CANDIDATES: usize = 5;
+/// Weeks, counting the current one, the explore sample is drawn from
+const EXPLORE_WEEKS: u32 = 4;
+const DEFAULT_EXPLORE_LIMIT: usize = 10;
+const MAX_EXPLORE_LIMIT: usize = 50;
+/// Weeks, counting the current one, searched for similar shares
+const SIMILAR_WEEKS: u32 = 8;
+const DEFAULT_SIMILAR_LIMIT: usize = 6;
+/// A shared tag counts for more than a shared domain
+const SIMILAR_TAG_WEIGHT: usize = 2;
+const SIMILAR_DOMAIN_WEIGHT: usize = 1;
+
+/// Anchor for the tags used by shares created in the same week as `timestamp`
+pub fn tag_time_path_for_timestamp(timestamp: Timestamp) -> Path {
+    let (year, week) = time_bucket_for_timestamp(timestamp);
+    Path::from(format!("tags.{}.{:02}", year, week))
+}
+
+/// Anchor for the URLs shared in the same week as `timestamp`
+pub fn url_time_path_for_timestamp(timestamp: Timestamp) -> Path {
+    let (year, week) = time_bucket_for_timestamp(timestamp);
+    Path::from(format!("shared_urls.{}.{:02}", year, week))
+}
+
+/// Records a freshly created share's URL anchor in its week's URL index
+pub fn index_share_item_url(created_at: Timestamp, share_item: &ShareItem) -> ExternResult<()> {
+    create_link(
+        url_time_path_for_timestamp(created_at).path_entry_hash()?,
+        url_path(&share_item.url)?.path_entry_hash()?,
+        LinkTypes::UrlTimeIndex,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    Ok(())
+}
+
+/// Week buckets from `weeks` weeks back up to and including the current
+/// one, newest first. Stepping back a week at a time can land twice in the
+/// short bucket at the end of a year, so buckets are deduplicated.
+fn week_paths(now: Timestamp, weeks: u32, path_for_timestamp: fn(Timestamp) -> Path) -> Vec<Path> {
+    let mut paths: Vec<Path> = Vec::new();
+    for weeks_back in 0..=weeks as i64 {
+        let path = path_for_timestamp(Timestamp::from_micros(
+            now.as_micros() - weeks_back * WEEK_IN_MICROS,
+        ));
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+fn check_window(window: u32) -> ExternResult<()> {
+    if window == 0 || window > MAX_WINDOW_WEEKS {
+        return Err(SharefeedError::InvalidInput(format!(
+            "Window must be between 1 and {} weeks",
+            MAX_WINDOW_WEEKS
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Records each tag of a freshly created share in its week's tag index
+pub fn index_share_item_tags(
+    share_item_hash: &ActionHash,
+    created_at: Timestamp,
+    share_item: &ShareItem,
+) -> ExternResult<()> {
+    let base = tag_time_path_for_timestamp(created_at).path_entry_hash()?;
+    for tag in share_item_tags(share_item) {
+        create_link(
+            base.clone(),
+            share_item_hash.clone(),
+            LinkTypes::TagTimeIndex,
+            SharefeedLinkTag::new().with_tag(tag).to_link_tag()?,
+        )?;
+    }
+    Ok(())
+}
+
+/// Distinct shares per tag in the week bucket at `path`
+fn tag_counts(path: Path, strategy: ReadStrategy) -> ExternResult<HashMap<String, usize>> {
+    let links = get_links(
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::TagTimeIndex)?,
+        strategy.get_strategy(),
+    )?;
+    let mut seen: HashSet<(String, AnyLinkableHash)> = HashSet::new();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for link in links {
+        let Some(tag) = SharefeedLinkTag::from_link_tag(&link.tag).and_then(|tag| tag.tag) else {
+            continue;
+        };
+        if seen.insert((tag.clone(), link.target)) {
+            *counts.entry(tag).or_default() += 1;
+        }
+    }
+    Ok(counts)
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct TrendingTagsInput {
+    /// Number of previous weeks the current week is compared against
+    pub window: Option<u32>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct TrendingTag {
+    pub tag: String,
+    /// Shares using the tag so far this week
+    pub current_count: usize,
+    /// Mean weekly shares using the tag over the window
+    pub previous_average: f64,
+    /// How far this week's use outpaces the window; higher is hotter
+    pub score: f64,
+}
+
+/// Tags used more this week than over the previous `window` weeks, hottest
+/// first. Tags not used at all this week are left out.
+#[hdk_extern]
+pub fn get_trending_tags(input: ReadInput<TrendingTagsInput>) -> ExternResult<Vec<TrendingTag>> {
+    trace_call!("get_trending_tags");
+    let (input, strategy) = input.into_parts();
+    let window = input.window.unwrap_or(DEFAULT_TRENDING_WINDOW);
+    check_window(window)?;
+
+    let mut paths = week_paths(sys_time()?, window, tag_time_path_for_timestamp).into_iter();
+    let Some(current_path) = paths.next() else {
+        return Ok(Vec::new());
+    };
+    let current = tag_counts(current_path, strategy)?;
+    if current.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let previous_paths: Vec<Path> = paths.collect();
+    let mut previous_totals: HashMap<String, usize> = HashMap::new();
+    let weeks = previous_paths.len().max(1) as f64;
+    for path in previous_paths {
+        for (tag, count) in tag_counts(path, strategy)? {
+            *previous_totals.entry(tag).or_default() += count;
+        }
+    }
+
+    let mut trending: Vec<TrendingTag> = current
+        .into_iter()
+        .map(|(tag, current_count)| {
+            let previous_average =
+                previous_totals.get(&tag).copied().unwrap_or_default() as f64 / weeks;
+            TrendingTag {
+                score: current_count as f64 / (previous_average + 1.0),
+                tag,
+                current_count,
+                previous_average,
+            }
+        })
+        .collect();
+    trending.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| b.current_count.cmp(&a.current_count))
+            .then_with(|| a.tag.cmp(&b.tag))
+    });
+    trending.truncate(input.limit.unwrap_or(DEFAULT_TRENDING_LIMIT));
+    Ok(trending)
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct TopSharedUrlsInput {
+    /// Number of weeks, counting the current one, to rank over
+    pub window: Option<u32>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct TopSharedUrl {
+    pub url: String,
+    /// Distinct agents who shared the URL during the window
+    pub sharer_count: usize,
+    /// The earliest visible share of the URL
+    pub share: ShareItemInfo,
+}
+
+/// URLs shared by the most distinct agents over the last `window` weeks
+#[hdk_extern]
+pub fn get_top_shared_urls(
+    input: ReadInput<TopSharedUrlsInput>,
+) -> ExternResult<Vec<TopSharedUrl>> {
+    trace_call!("get_top_shared_urls");
+    let (input, strategy) = input.into_parts();
+    let window = input.window.unwrap_or(DEFAULT_TOP_URLS_WINDOW);
+    check_window(window)?;
+
+    let mut sharers: HashMap<AnyLinkableHash, HashSet<AgentPubKey>> = HashMap::new();
+    for path in week_paths(sys_time()?, window - 1, url_time_path_for_timestamp) {
+        let links = get_links(
+            LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::UrlTimeIndex)?,
+            strategy.get_strategy(),
+        )?;
+        for link in links {
+            sharers.entry(link.target).or_default().insert(link.author);
+        }
+    }
+    let mut ranked: Vec<(AnyLinkableHash, usize)> = sharers
+        .into_iter()
+        .map(|(url_anchor, agents)| (url_anchor, agents.len()))
+        .collect();
+    ranked.sort_by(|(anchor_a, count_a), (anchor_b, count_b)| {
+        count_b.cmp(count_a).then_with(|| anchor_a.cmp(anchor_b))
+    });
+
+    let limit = input.limit.unwrap_or(DEFAULT_TOP_URLS_LIMIT);
+    let mut top: Vec<TopSharedUrl> = Vec::new();
+    for (url_anchor, sharer_count) in ranked {
+        if top.len() >= limit {
+            break;
+        }
+        let Some(url_anchor) = url_anchor.into_entry_hash() else {
+            continue;
+        };
+        let mut links = get_links(
+            LinkQuery::try_new(url_anchor, LinkTypes::UrlToShare)?,
+            strategy.get_strategy(),
+        )?;
+        links.sort_by_key(|link| link.timestamp);
+        links.truncate(REPRESENTATIVE_SHARE_CANDIDATES);
+        let mut shares = share_item_infos_from_links(links, strategy)?;
+        shares.sort_by_key(|share| share.created_at);
+        if let Some(share) = shares.into_iter().next() {
+            top.push(TopSharedUrl {
+                url: share.share_item.url.clone(),
+                sharer_count,
+                share,
+            });
+        }
+    }
+    Ok(top)
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ExploreSampleInput {
+    /// Seeds the sample: the same nonce over the same shares gives the
+    /// same sample, a new nonce gives a new one
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub nonce: u64,
+    pub limit: Option<usize>,
+}
+
+/// SplitMix64, enough to shuffle deterministically without pulling in a
+/// random number crate
+struct SampleRng(u64);
+
+impl SampleRng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Index in `0..len`; `len` must not be zero
+    fn below(&mut self, len: usize) -> usize {
+        (self.next() % len as u64) as usize
+    }
+}
+
+/// A pseudo-random sample of other agents' shares from the last few weeks,
+/// newest first. Only the sampled shares are fetched.
+#[hdk_extern]
+pub fn get_explore_sample(
+    input: ReadInput<ExploreSampleInput>,
+) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("get_explore_sample");
+    let (input, strategy) = input.into_parts();
+    let limit = input
+        .limit
+        .unwrap_or(DEFAULT_EXPLORE_LIMIT)
+        .min(MAX_EXPLORE_LIMIT);
+    let me = agent_info()?.agent_initial_pubkey;
+
+    let mut links: Vec<Link> = Vec::new();
+    for path in week_paths(sys_time()?, EXPLORE_WEEKS - 1, time_path_for_timestamp) {
+        links.extend(get_links(
+            LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::TimeIndex)?,
+            strategy.get_strategy(),
+        )?);
+    }
+    // A stable order first, so the nonce alone decides the sample
+    let mut links: Vec<Link> = dedup_links_by_target(links)
+        .into_iter()
+        .filter(|link| link.author != me)
+        .collect();
+    links.sort_by(|a, b| a.target.cmp(&b.target));
+
+    // Partial Fisher-Yates: the first `limit` positions end up sampled
+    let mut rng = SampleRng(input.nonce);
+    let sample_size = limit.min(links.len());
+    for i in 0..sample_size {
+        let j = i + rng.below(links.len() - i);
+        links.swap(i, j);
+    }
+    links.truncate(sample_size);
+
+    share_item_infos_from_links(links, strategy)
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SimilarSharesInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_hash: ActionHash,
+    pub limit: Option<usize>,
+}
+
+/// Recent shares with the most tags in common with `share_hash`, a shared
+/// domain breaking ties, best match first. Shares with nothing in common
+/// are left out.
+#[hdk_extern]
+pub fn get_similar_shares(
+    input: ReadInput<SimilarSharesInput>,
+) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("get_similar_shares");
+    let (input, strategy) = input.into_parts();
+    let record = get_share_item(ReadInput::WithStrategy {
+        input: input.share_hash.clone(),
+        strategy,
+    })?
+    .ok_or(SharefeedError::NotFound(String::from(
+        "Could not find the ShareItem",
+    )))?;
+    let share_item = record
+        .entry()
+        .to_app_option::<ShareItem>()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the ShareItem",
+        )))?;
+    let tags = share_item_tags(&share_item);
+    let domain = url_domain(&share_item.url);
+    let source = AnyLinkableHash::from(input.share_hash);
+
+    let mut scores: HashMap<AnyLinkableHash, (usize, Link)> = HashMap::new();
+    let now = sys_time()?;
+    if !tags.is_empty() {
+        for path in week_paths(now, SIMILAR_WEEKS - 1, tag_time_path_for_timestamp) {
+            let links = get_links(
+                LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::TagTimeIndex)?,
+                strategy.get_strategy(),
+            )?;
+            let mut seen: HashSet<(String, AnyLinkableHash)> = HashSet::new();
+            for link in links {
+                let Some(tag) = SharefeedLinkTag::from_link_tag(&link.tag).and_then(|tag| tag.tag)
+                else {
+                    continue;
+                };
+                if link.target == source
+                    || !tags.contains(&tag)
+                    || !seen.insert((tag, link.target.clone()))
+                {
+                    continue;
+                }
+                scores
+                    .entry(link.target.clone())
+                    .and_modify(|(score, _)| *score += SIMILAR_TAG_WEIGHT)
+                    .or_insert((SIMILAR_TAG_WEIGHT, link));
+            }
+        }
+    }
+    if domain.is_some() {
+        for path in week_paths(now, SIMILAR_WEEKS - 1, time_path_for_timestamp) {
+            let links = get_links(
+                LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::TimeIndex)?,
+                strategy.get_strategy(),
+            )?;
+            for link in dedup_links_by_target(links) {
+                let same_domain = ShareLinkSummary::from_link_tag(&link.tag)
+                    .is_some_and(|summary| summary.domain == domain);
+                if link.target == source || !same_domain {
+                    continue;
+                }
+                scores
+                    .entry(link.target.clone())
+                    .and_modify(|(score, _)| *score += SIMILAR_DOMAIN_WEIGHT)
+                    .or_insert((SIMILAR_DOMAIN_WEIGHT, link));
+            }
+        }
+    }
+
+    let mut matches: Vec<(usize, Link)> = scores.into_values().collect();
+    matches.sort_by(|(score_a, link_a), (score_b, link_b)| {
+        score_b
+            .cmp(score_a)
+            .then_with(|| link_b.timestamp.cmp(&link_a.timestamp))
+    });
+    matches.truncate(input.limit.unwrap_or(DEFAULT_SIMILAR_LIMIT));
+
+    let ranks: Vec<AnyLinkableHash> = matches
+        .iter()
+        .map(|(_, link)| link.target.clone())
+        .collect();
+    let links: Vec<Link> = matches.into_iter().map(|(_, link)| link).collect();
+    let mut shares = share_item_infos_from_links(links, strategy)?;
+    shares.sort_by_key(|share| {
+        let target = AnyLinkableHash::from(share.action_hash.clone());
+        ranks.iter().position(|rank| rank == &target)
+    });
+    Ok(shares)
+}
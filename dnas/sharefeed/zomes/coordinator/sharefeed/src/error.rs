@@ -0,0 +1,80 @@
+use hdk::prelude::*;
+use std::fmt;
+
+/// Errors returned by sharefeed externs. They reach clients as a guest
+/// error string of the form `Kind: message`, so clients can branch on the
+/// kind before the first `:` instead of matching the message.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum SharefeedError {
+    /// A record, entry or link target that should exist could not be found
+    NotFound(String),
+    /// The caller isn't allowed to do this, e.g. not a steward or banned
+    NotAuthorized(String),
+    /// The input or the record it points at is malformed
+    InvalidInput(String),
+    /// An index link the operation relies on is missing
+    IndexMissing(String),
+    /// A remote call to another agent or zome failed
+    Unreachable(String),
+    /// The caller has hit one of the network's limits on how often they
+    /// may do this
+    RateLimited(String),
+    /// An update was based on a revision that is no longer the latest. The
+    /// message ends with the latest revision's action hash to rebase onto.
+    Conflict(String),
+}
+
+impl SharefeedError {
+    /// Refuses an update of `entry_type` based on `previous` when `latest`
+    /// is the head of its update chain
+    pub fn check_latest_revision(
+        entry_type: &str,
+        previous: &ActionHash,
+        latest: &ActionHash,
+    ) -> Result<(), SharefeedError> {
+        if previous == latest {
+            return Ok(());
+        }
+        Err(SharefeedError::Conflict(format!(
+            "{} has been updated since this revision; latest revision: {}",
+            entry_type,
+            ActionHashB64::from(latest.clone())
+        )))
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SharefeedError::NotFound(_) => "NotFound",
+            SharefeedError::NotAuthorized(_) => "NotAuthorized",
+            SharefeedError::InvalidInput(_) => "InvalidInput",
+            SharefeedError::IndexMissing(_) => "IndexMissing",
+            SharefeedError::Unreachable(_) => "Unreachable",
+            SharefeedError::RateLimited(_) => "RateLimited",
+            SharefeedError::Conflict(_) => "Conflict",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            SharefeedError::NotFound(message)
+            | SharefeedError::NotAuthorized(message)
+            | SharefeedError::InvalidInput(message)
+            | SharefeedError::IndexMissing(message)
+            | SharefeedError::Unreachable(message)
+            | SharefeedError::RateLimited(message)
+            | SharefeedError::Conflict(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for SharefeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.kind(), self.message())
+    }
+}
+
+impl From<SharefeedError> for WasmError {
+    fn from(err: SharefeedError) -> Self {
+        wasm_error!(WasmErrorInner::Guest(err.to_string()))
+    }
+}
@@ -0,0 +1,640 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::blocklist::get_blocked_agents;
+use crate::deeplink::{agent_deeplink, feed_deeplink, share_deeplink};
+use crate::error::SharefeedError;
+use crate::feed::{get_feed, get_feed_members, get_feed_shares, get_my_feeds, FeedInfo};
+use crate::follow::get_following;
+use crate::notification_prefs::get_notification_prefs;
+use crate::profiles::get_agent_profile;
+use crate::read::ReadInput;
+use crate::saved_search::get_saved_searches;
+use crate::share_item::ShareItemInfo;
+
+pub const JSON_FEED_VERSION: &str = "https://jsonfeed.org/version/1.1";
+pub const ACTIVITY_STREAMS_CONTEXT: &str = "https://www.w3.org/ns/activitystreams";
+pub const ACTIVITY_STREAMS_PUBLIC: &str = "https://www.w3.org/ns/activitystreams#Public";
+const OUTBOX_PAGE_SIZE: usize = 20;
+/// Version of the `export_my_data` document format
+pub const PERSONAL_DATA_VERSION: u32 = 1;
+
+/// A feed in the JSON Feed 1.1 format (https://jsonfeed.org/version/1.1).
+/// Fields serialize under the names the spec gives them.
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct JsonFeed {
+    pub version: String,
+    pub title: String,
+    pub home_page_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub items: Vec<JsonFeedItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct JsonFeedItem {
+    pub id: String,
+    pub url: String,
+    pub external_url: String,
+    pub title: String,
+    pub content_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    pub date_published: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    pub authors: Vec<JsonFeedAuthor>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct JsonFeedAuthor {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+}
+
+/// RFC 3339 form of a timestamp in UTC, e.g. `2024-03-01T12:00:00Z`
+pub fn rfc3339(timestamp: Timestamp) -> String {
+    let seconds = timestamp.as_seconds_and_nanos().0;
+    let days = seconds.div_euclid(86400);
+    let seconds_of_day = seconds.rem_euclid(86400);
+
+    // Days since the epoch to a civil date, after Howard Hinnant's
+    // `civil_from_days`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+/// Loads a feed the caller may export: public feeds, or private feeds
+/// they are a member of
+pub fn get_exportable_feed(feed_hash: &ActionHash) -> ExternResult<Feed> {
+    let feed: Feed = get_feed(feed_hash.clone().into())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Feed",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    if !feed.is_public {
+        let me = agent_info()?.agent_initial_pubkey;
+        if !get_feed_members(feed_hash.clone().into())?.contains(&me) {
+            return Err(SharefeedError::NotAuthorized(String::from(
+                "Only members can export a private feed",
+            ))
+            .into());
+        }
+    }
+    Ok(feed)
+}
+
+/// A feed and its shares, newest first, as a JSON Feed 1.1 document
+#[hdk_extern]
+pub fn export_feed_jsonfeed(feed_hash: ReadInput<ActionHash>) -> ExternResult<JsonFeed> {
+    trace_call!("export_feed_jsonfeed");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    let feed = get_exportable_feed(&feed_hash)?;
+    let shares = get_feed_shares(ReadInput::WithStrategy {
+        input: feed_hash.clone(),
+        strategy,
+    })?;
+
+    let items = shares
+        .into_iter()
+        .map(|share| {
+            let share_item = share.share_item;
+            let (name, avatar) = match share.author_profile {
+                Some(profile) => (profile.nickname, profile.avatar),
+                None => (AgentPubKeyB64::from(share.author).to_string(), None),
+            };
+            JsonFeedItem {
+                id: ActionHashB64::from(share.action_hash.clone()).to_string(),
+                url: share_deeplink(&share.action_hash),
+                external_url: share_item.url,
+                content_text: share_item
+                    .selection
+                    .clone()
+                    .or_else(|| share_item.description.clone())
+                    .unwrap_or_else(|| share_item.title.clone()),
+                title: share_item.title,
+                summary: share_item.description,
+                image: share_item.thumbnail,
+                date_published: rfc3339(share.created_at),
+                tags: share_item.tags,
+                authors: vec![JsonFeedAuthor { name, avatar }],
+            }
+        })
+        .collect();
+
+    Ok(JsonFeed {
+        version: String::from(JSON_FEED_VERSION),
+        title: feed.name,
+        home_page_url: feed_deeplink(&feed_hash),
+        description: feed.description,
+        items,
+    })
+}
+
+/// Escapes text for use in XML content and attribute values
+pub fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn opml_feed_outline(feed_info: &FeedInfo) -> String {
+    let name = xml_escape(&feed_info.feed.name);
+    let description = match &feed_info.feed.description {
+        Some(description) => format!(" description=\"{}\"", xml_escape(description)),
+        None => String::new(),
+    };
+    format!(
+        "      <outline type=\"sharefeed\" text=\"{}\" title=\"{}\" htmlUrl=\"{}\"{}/>\n",
+        name,
+        name,
+        feed_deeplink(&feed_info.action_hash),
+        description
+    )
+}
+
+/// An OPML 2.0 outline of the feeds I steward, the other feeds I belong
+/// to and the agents I follow, each under its own heading
+#[hdk_extern]
+pub fn export_opml(_: ()) -> ExternResult<String> {
+    trace_call!("export_opml");
+    let me = agent_info()?.agent_initial_pubkey;
+    let (stewarded, joined): (Vec<FeedInfo>, Vec<FeedInfo>) = get_my_feeds(().into())?
+        .into_iter()
+        .partition(|feed_info| feed_info.feed.stewards.contains(&me));
+
+    let mut opml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    opml.push_str("<opml version=\"2.0\">\n");
+    opml.push_str("  <head>\n    <title>Sharefeed subscriptions</title>\n  </head>\n");
+    opml.push_str("  <body>\n");
+
+    opml.push_str("    <outline text=\"Feeds I steward\">\n");
+    for feed_info in &stewarded {
+        opml.push_str(&opml_feed_outline(feed_info));
+    }
+    opml.push_str("    </outline>\n");
+
+    opml.push_str("    <outline text=\"Feeds I belong to\">\n");
+    for feed_info in &joined {
+        opml.push_str(&opml_feed_outline(feed_info));
+    }
+    opml.push_str("    </outline>\n");
+
+    opml.push_str("    <outline text=\"Following\">\n");
+    for agent in get_following(())? {
+        let name = match get_agent_profile(agent.clone()) {
+            Ok(Some(profile)) => profile.nickname,
+            _ => AgentPubKeyB64::from(agent.clone()).to_string(),
+        };
+        let name = xml_escape(&name);
+        opml.push_str(&format!(
+            "      <outline type=\"sharefeed-agent\" text=\"{}\" title=\"{}\" htmlUrl=\"{}\"/>\n",
+            name,
+            name,
+            agent_deeplink(&agent)
+        ));
+    }
+    opml.push_str("    </outline>\n");
+
+    opml.push_str("  </body>\n</opml>\n");
+    Ok(opml)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ActivityPubOutboxInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    /// Page to render, from 0; `None` renders the collection itself
+    pub page: Option<usize>,
+}
+
+/// An ActivityStreams OrderedCollection, or one OrderedCollectionPage of it
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ActivityPubOutbox {
+    #[serde(rename = "@context")]
+    pub context: String,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub total_items: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_of: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ordered_items: Vec<ActivityPubActivity>,
+}
+
+/// A `Create` activity announcing one share
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ActivityPubActivity {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub actor: String,
+    pub published: String,
+    pub to: Vec<String>,
+    pub object: ActivityPubNote,
+}
+
+/// A share as a `Note` whose attachment is the shared page as a `Link`
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ActivityPubNote {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub attributed_to: String,
+    pub published: String,
+    pub to: Vec<String>,
+    pub name: String,
+    /// HTML rendering of the share
+    pub content: String,
+    pub url: String,
+    pub attachment: Vec<ActivityPubLink>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tag: Vec<ActivityPubHashtag>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ActivityPubLink {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub href: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ActivityPubHashtag {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub name: String,
+}
+
+fn activitypub_activity(share: ShareItemInfo) -> ActivityPubActivity {
+    let share_url = share_deeplink(&share.action_hash);
+    let actor = agent_deeplink(&share.author);
+    let published = rfc3339(share.created_at);
+    let public = vec![String::from(ACTIVITY_STREAMS_PUBLIC)];
+    let share_item = share.share_item;
+
+    let mut content = format!(
+        "<p><a href=\"{}\">{}</a></p>",
+        xml_escape(&share_item.url),
+        xml_escape(&share_item.title)
+    );
+    if let Some(description) = &share_item.description {
+        content.push_str(&format!("<p>{}</p>", xml_escape(description)));
+    }
+    if let Some(selection) = &share_item.selection {
+        content.push_str(&format!(
+            "<blockquote>{}</blockquote>",
+            xml_escape(selection)
+        ));
+    }
+
+    ActivityPubActivity {
+        id: format!("{}/activity", share_url),
+        kind: String::from("Create"),
+        actor: actor.clone(),
+        published: published.clone(),
+        to: public.clone(),
+        object: ActivityPubNote {
+            id: share_url.clone(),
+            kind: String::from("Note"),
+            attributed_to: actor,
+            published,
+            to: public,
+            name: share_item.title.clone(),
+            content,
+            url: share_url,
+            attachment: vec![ActivityPubLink {
+                kind: String::from("Link"),
+                href: share_item.url,
+                name: share_item.title,
+            }],
+            tag: normalize_tags(&share_item.tags)
+                .into_iter()
+                .map(|tag| ActivityPubHashtag {
+                    kind: String::from("Hashtag"),
+                    // Hashtags can't contain spaces
+                    name: format!("#{}", tag.split_whitespace().collect::<String>()),
+                })
+                .collect(),
+        },
+    }
+}
+
+/// A public feed's shares, newest first, as an ActivityStreams outbox of
+/// `Create` activities, so a bridge outside the conductor can federate
+/// the feed. Ids are sharefeed:// deep links for the bridge to map onto
+/// its own URLs.
+#[hdk_extern]
+pub fn export_activitypub_outbox(
+    input: ReadInput<ActivityPubOutboxInput>,
+) -> ExternResult<ActivityPubOutbox> {
+    trace_call!("export_activitypub_outbox");
+    let (input, strategy) = input.into_parts();
+    let feed = get_exportable_feed(&input.feed_hash)?;
+    if !feed.is_public {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only public feeds can be federated",
+        ))
+        .into());
+    }
+    let shares = get_feed_shares(ReadInput::WithStrategy {
+        input: input.feed_hash.clone(),
+        strategy,
+    })?;
+
+    let outbox_id = format!("{}/outbox", feed_deeplink(&input.feed_hash));
+    let page_id = |page: usize| format!("{}?page={}", outbox_id, page);
+    let total_items = shares.len();
+    let total_pages = total_items.div_ceil(OUTBOX_PAGE_SIZE).max(1);
+
+    let Some(page) = input.page else {
+        return Ok(ActivityPubOutbox {
+            context: String::from(ACTIVITY_STREAMS_CONTEXT),
+            id: outbox_id.clone(),
+            kind: String::from("OrderedCollection"),
+            total_items,
+            first: Some(page_id(0)),
+            last: Some(page_id(total_pages - 1)),
+            part_of: None,
+            next: None,
+            prev: None,
+            ordered_items: Vec::new(),
+        });
+    };
+
+    let ordered_items = shares
+        .into_iter()
+        .skip(page.saturating_mul(OUTBOX_PAGE_SIZE))
+        .take(OUTBOX_PAGE_SIZE)
+        .map(activitypub_activity)
+        .collect();
+    Ok(ActivityPubOutbox {
+        context: String::from(ACTIVITY_STREAMS_CONTEXT),
+        id: page_id(page),
+        kind: String::from("OrderedCollectionPage"),
+        total_items,
+        first: None,
+        last: None,
+        part_of: Some(outbox_id.clone()),
+        next: (page + 1 < total_pages).then(|| page_id(page + 1)),
+        prev: (page > 0).then(|| page_id(page - 1)),
+        ordered_items,
+    })
+}
+
+/// One of my entries in a personal data export: its latest version, under
+/// the hash of the action that first created it
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ExportedEntry<T> {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub action_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+    pub entry: T,
+}
+
+/// A share I added to a feed
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ExportedFeedShare {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub added_at: Timestamp,
+}
+
+/// Everything I have authored, read from my own source chain
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct PersonalDataExport {
+    pub version: u32,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub agent: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub exported_at: Timestamp,
+    pub share_items: Vec<ExportedEntry<ShareItem>>,
+    pub feeds: Vec<ExportedEntry<Feed>>,
+    pub feed_shares: Vec<ExportedFeedShare>,
+    pub comments: Vec<ExportedEntry<Comment>>,
+    pub reactions: Vec<ExportedEntry<Reaction>>,
+    #[cfg_attr(feature = "ts", ts(type = "Array<AgentPubKey>"))]
+    #[schemars(with = "Vec<AgentPubKeySchema>")]
+    pub following: Vec<AgentPubKey>,
+    pub notification_prefs: NotificationPrefs,
+    pub blocklist: Blocklist,
+    pub saved_searches: Vec<SavedSearch>,
+}
+
+/// Hashes of every action I have deleted
+pub fn my_deleted_actions() -> ExternResult<HashSet<ActionHash>> {
+    Ok(
+        query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::Delete(delete) => Some(delete.deletes_address.clone()),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// The latest version of each entry of `entry_type` on my chain, oldest
+/// first. An entry is left out once any of its versions was deleted.
+pub fn my_live_entries<T>(
+    entry_type: UnitEntryTypes,
+    deleted: &HashSet<ActionHash>,
+) -> ExternResult<Vec<ExportedEntry<T>>>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(entry_type.try_into()?)
+            .include_entries(true),
+    )?;
+
+    // Updates may point at an earlier update, so each version is traced
+    // back to the create it descends from
+    let mut roots: HashMap<ActionHash, ActionHash> = HashMap::new();
+    let mut deleted_roots: HashSet<ActionHash> = HashSet::new();
+    let mut order: Vec<ActionHash> = Vec::new();
+    let mut latest: HashMap<ActionHash, ExportedEntry<T>> = HashMap::new();
+    for record in records {
+        let action_hash = record.action_address().clone();
+        let root = match record.action() {
+            Action::Update(update) => roots
+                .get(&update.original_action_address)
+                .cloned()
+                .unwrap_or_else(|| update.original_action_address.clone()),
+            _ => action_hash.clone(),
+        };
+        roots.insert(action_hash.clone(), root.clone());
+        if deleted.contains(&action_hash) || deleted.contains(&root) {
+            deleted_roots.insert(root.clone());
+        }
+
+        let Some(entry) = record
+            .entry()
+            .to_app_option::<T>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        match latest.get_mut(&root) {
+            Some(exported) => exported.entry = entry,
+            None => {
+                order.push(root.clone());
+                latest.insert(
+                    root.clone(),
+                    ExportedEntry {
+                        action_hash: root,
+                        created_at: record.action().timestamp(),
+                        entry,
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(order
+        .into_iter()
+        .filter(|root| !deleted_roots.contains(root))
+        .filter_map(|root| latest.remove(&root))
+        .collect())
+}
+
+/// Links of `link_type` I created and haven't deleted, oldest first
+fn my_live_links(link_type: LinkTypes) -> ExternResult<Vec<(ActionHash, CreateLink)>> {
+    let scoped = ScopedLinkType::try_from(link_type)?;
+    let deleted: HashSet<ActionHash> =
+        query(ChainQueryFilter::new().action_type(ActionType::DeleteLink))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::DeleteLink(delete_link) => Some(delete_link.link_add_address.clone()),
+                _ => None,
+            })
+            .collect();
+    Ok(
+        query(ChainQueryFilter::new().action_type(ActionType::CreateLink))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::CreateLink(create_link)
+                    if create_link.zome_index == scoped.zome_index
+                        && create_link.link_type == scoped.zome_type
+                        && !deleted.contains(record.action_address()) =>
+                {
+                    Some((record.action_address().clone(), create_link.clone()))
+                }
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Every share, feed, comment and reaction I authored, the shares I added
+/// to feeds, who I follow and my preferences, as one document that
+/// `import_my_data` can restore
+#[hdk_extern]
+pub fn export_my_data(_: ()) -> ExternResult<PersonalDataExport> {
+    trace_call!("export_my_data");
+    let deleted = my_deleted_actions()?;
+
+    let feed_shares = my_live_links(LinkTypes::FeedToShare)?
+        .into_iter()
+        .filter_map(|(_, create_link)| {
+            Some(ExportedFeedShare {
+                feed_hash: create_link.base_address.into_action_hash()?,
+                share_item_hash: create_link.target_address.into_action_hash()?,
+                added_at: create_link.timestamp,
+            })
+        })
+        .collect();
+
+    Ok(PersonalDataExport {
+        version: PERSONAL_DATA_VERSION,
+        agent: agent_info()?.agent_initial_pubkey,
+        exported_at: sys_time()?,
+        share_items: my_live_entries(UnitEntryTypes::ShareItem, &deleted)?,
+        feeds: my_live_entries(UnitEntryTypes::Feed, &deleted)?,
+        feed_shares,
+        comments: my_live_entries(UnitEntryTypes::Comment, &deleted)?,
+        reactions: my_live_entries(UnitEntryTypes::Reaction, &deleted)?,
+        following: get_following(())?,
+        notification_prefs: get_notification_prefs(())?,
+        blocklist: get_blocked_agents(())?,
+        saved_searches: get_saved_searches(())?
+            .into_iter()
+            .map(|info| info.saved_search)
+            .collect(),
+    })
+}
@@ -0,0 +1,93 @@
+use hdk::prelude::*;
+use std::collections::HashSet;
+
+/// Called remotely by peers whose DHT view hasn't synced a record yet.
+/// Anyone may call it, so records with private entries, like my
+/// notifications or capability grants, are never handed out.
+#[hdk_extern]
+pub fn get_record_for_peer(action_hash: ActionHash) -> ExternResult<Option<Record>> {
+    trace_call!("get_record_for_peer");
+    let Some(record) = get(action_hash, GetOptions::local())? else {
+        return Ok(None);
+    };
+    if record
+        .action()
+        .entry_type()
+        .is_some_and(|entry_type| !entry_type.visibility().is_public())
+    {
+        return Ok(None);
+    }
+    Ok(Some(record))
+}
+
+/// Whether a record a peer sent is the one asked for: its action hashes
+/// to `action_hash` and its entry, if it has one, to the action's entry hash
+fn is_intact(record: &Record, action_hash: &ActionHash) -> ExternResult<bool> {
+    if &hash_action(record.action().clone())? != action_hash {
+        return Ok(false);
+    }
+    let Some(entry_hash) = record.action().entry_hash() else {
+        return Ok(true);
+    };
+    match record.entry().as_option() {
+        Some(entry) => Ok(&hash_entry(entry.clone())? == entry_hash),
+        None => Ok(false),
+    }
+}
+
+/// Fetches records that aren't available locally from agents likely to
+/// hold them: the author of the link pointing at the record first, then
+/// any extra holders such as a feed's stewards. Agents that fail to answer
+/// are skipped for the rest of the zome call.
+#[derive(Default)]
+pub struct RemoteFetcher {
+    extra_holders: Vec<AgentPubKey>,
+    unreachable: HashSet<AgentPubKey>,
+}
+
+impl RemoteFetcher {
+    pub fn new(extra_holders: Vec<AgentPubKey>) -> Self {
+        RemoteFetcher {
+            extra_holders,
+            unreachable: HashSet::new(),
+        }
+    }
+
+    /// Asks the holders for a record the caller already failed to get locally
+    pub fn fetch_remote(
+        &mut self,
+        action_hash: &ActionHash,
+        link_author: &AgentPubKey,
+    ) -> ExternResult<Option<Record>> {
+        let me = agent_info()?.agent_initial_pubkey;
+        let mut holders = vec![link_author.clone()];
+        holders.extend(self.extra_holders.iter().cloned());
+        for holder in holders {
+            if holder == me || self.unreachable.contains(&holder) {
+                continue;
+            }
+            let response = call_remote(
+                holder.clone(),
+                zome_info()?.name,
+                "get_record_for_peer".into(),
+                None,
+                action_hash.clone(),
+            )?;
+            match response {
+                ZomeCallResponse::Ok(result) => {
+                    let record: Option<Record> = result.decode().map_err(|e| wasm_error!(e))?;
+                    // Only trust a record that actually hashes to what we asked for
+                    if let Some(record) = record {
+                        if is_intact(&record, action_hash)? {
+                            return Ok(Some(record));
+                        }
+                    }
+                }
+                _ => {
+                    self.unreachable.insert(holder);
+                }
+            }
+        }
+        Ok(None)
+    }
+}
@@ -1,10 +1,27 @@
 use hdk::prelude::*;
+use schemars::JsonSchema;
 use sharefeed_integrity::*;
 
-use crate::share_item::ShareItemInfo;
+use crate::ban::check_not_banned;
+use crate::batch::{get_chain_heads, get_records};
+use crate::error::SharefeedError;
+use crate::fallback::RemoteFetcher;
+use crate::input::check_feed;
+use crate::membership::get_feed_stewards;
+use crate::moderation::create_moderation_action;
+use crate::network_blocklist::{check_network_blocklist, get_moderator_chain_heads};
+use crate::read::{ReadInput, ReadStrategy};
+use crate::share_item::{
+    dedup_links_by_target, localize_share_infos, share_item_infos_from_links,
+    share_item_infos_with_fallback, share_summaries_from_links, ShareItemInfo, ShareSummaryInfo,
+};
+use crate::signals::{send_remote_signal_to_agents, Signal};
+use crate::steward_proposal::needs_steward_approval;
 
 #[hdk_extern]
 pub fn create_feed(feed: Feed) -> ExternResult<Record> {
+    trace_call!("create_feed");
+    check_feed(&feed)?;
     let feed_hash = create_entry(&EntryTypes::Feed(feed.clone()))?;
 
     // Link from agent to feed (my feeds)
@@ -13,30 +30,37 @@ pub fn create_feed(feed: Feed) -> ExternResult<Record> {
         agent_info.agent_initial_pubkey.clone(),
         feed_hash.clone(),
         LinkTypes::AgentToFeed,
-        (),
+        SharefeedLinkTag::new().to_link_tag()?,
     )?;
 
     // Link from feed to all stewards as members
+    let mut linked: Vec<&AgentPubKey> = Vec::new();
     for steward in &feed.stewards {
+        if linked.contains(&steward) {
+            continue;
+        }
+        linked.push(steward);
         create_link(
             feed_hash.clone(),
             steward.clone(),
             LinkTypes::FeedToMember,
-            (),
+            SharefeedLinkTag::new().to_link_tag()?,
         )?;
     }
 
-    let record = get(feed_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
-        WasmErrorInner::Guest(String::from("Could not find the newly created Feed"))
+    let record = get(feed_hash.clone(), GetOptions::local())?.ok_or(SharefeedError::NotFound(
+        String::from("Could not find the newly created Feed"),
     ))?;
     Ok(record)
 }
 
 #[hdk_extern]
-pub fn get_feed(original_feed_hash: ActionHash) -> ExternResult<Option<Record>> {
+pub fn get_feed(original_feed_hash: ReadInput<ActionHash>) -> ExternResult<Option<Record>> {
+    trace_call!("get_feed");
+    let (original_feed_hash, strategy) = original_feed_hash.into_parts();
     let links = get_links(
         LinkQuery::try_new(original_feed_hash.clone(), LinkTypes::FeedUpdates)?,
-        GetStrategy::Local,
+        strategy.get_strategy(),
     )?;
     let latest_link = links
         .into_iter()
@@ -45,119 +69,590 @@ pub fn get_feed(original_feed_hash: ActionHash) -> ExternResult<Option<Record>>
         Some(link) => ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?,
         None => original_feed_hash.clone(),
     };
-    get(latest_feed_hash, GetOptions::local())
+    get(latest_feed_hash, strategy.get_options())
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct UpdateFeedInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub original_feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub previous_feed_hash: ActionHash,
     pub updated_feed: Feed,
 }
 
 #[hdk_extern]
 pub fn update_feed(input: UpdateFeedInput) -> ExternResult<Record> {
+    trace_call!("update_feed");
+    save_feed_update(input, false)
+}
+
+/// Writes a new revision of a feed. Making a members-only feed public
+/// needs every steward's approval through a `StewardProposal`, so unless
+/// `approved` that's refused when the feed has other stewards.
+pub fn save_feed_update(input: UpdateFeedInput, approved: bool) -> ExternResult<Record> {
+    check_feed(&input.updated_feed)?;
+    let latest = get_feed(ReadInput::WithStrategy {
+        input: input.original_feed_hash.clone(),
+        strategy: ReadStrategy::Network,
+    })?
+    .ok_or(SharefeedError::NotFound(String::from(
+        "Could not find the Feed to update",
+    )))?;
+    SharefeedError::check_latest_revision(
+        "Feed",
+        &input.previous_feed_hash,
+        latest.action_address(),
+    )?;
+    let latest_feed: Feed = latest
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    let me = agent_info()?.agent_initial_pubkey;
+    if !latest_feed.stewards.contains(&me) {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only stewards of a feed can update it",
+        ))
+        .into());
+    }
+    if latest_feed
+        .stewards
+        .iter()
+        .any(|steward| steward != &me && !input.updated_feed.stewards.contains(steward))
+    {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Stewards can step down, but can't remove other stewards",
+        ))
+        .into());
+    }
+    if input.updated_feed.steward_quorum != latest_feed.steward_quorum {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "A feed's steward quorum can't be changed",
+        ))
+        .into());
+    }
+    if !approved
+        && !latest_feed.is_public
+        && input.updated_feed.is_public
+        && latest_feed.stewards.iter().any(|steward| steward != &me)
+    {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Making a members-only feed public needs every steward's approval",
+        ))
+        .into());
+    }
     let updated_feed_hash = update_entry(input.previous_feed_hash.clone(), &input.updated_feed)?;
     create_link(
         input.original_feed_hash.clone(),
         updated_feed_hash.clone(),
         LinkTypes::FeedUpdates,
-        (),
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    let record = get(updated_feed_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the newly updated Feed")),
     )?;
-    let record = get(updated_feed_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
-        WasmErrorInner::Guest(String::from("Could not find the newly updated Feed"))
-    ))?;
     Ok(record)
 }
 
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SetKeywordFiltersInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub keyword_filters: Vec<KeywordFilter>,
+}
+
+/// Replaces a feed's keyword filters. Only its stewards may change them.
+#[hdk_extern]
+pub fn set_feed_keyword_filters(input: SetKeywordFiltersInput) -> ExternResult<Record> {
+    trace_call!("set_feed_keyword_filters");
+    let record = get_feed(input.feed_hash.clone().into())?.ok_or(SharefeedError::NotFound(
+        String::from("Could not find the Feed"),
+    ))?;
+    let feed: Feed = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    if !feed.stewards.contains(&agent_info()?.agent_initial_pubkey) {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only stewards can change a feed's keyword filters",
+        ))
+        .into());
+    }
+    update_feed(UpdateFeedInput {
+        original_feed_hash: input.feed_hash,
+        previous_feed_hash: record.action_address().clone(),
+        updated_feed: Feed {
+            keyword_filters: input.keyword_filters,
+            ..feed
+        },
+    })
+}
+
+/// Deletes a feed. Feeds whose steward quorum is more than one are
+/// instead deleted through a `StewardProposal` the other stewards approve.
 #[hdk_extern]
 pub fn delete_feed(original_feed_hash: ActionHash) -> ExternResult<ActionHash> {
+    trace_call!("delete_feed");
+    if needs_steward_approval(&original_feed_hash)? {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Deleting a feed with several stewards needs a second steward's approval",
+        ))
+        .into());
+    }
     delete_entry(original_feed_hash)
 }
 
 // Feed membership operations
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct AddShareToFeedInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub share_item_hash: ActionHash,
 }
 
 #[hdk_extern]
 pub fn add_share_to_feed(input: AddShareToFeedInput) -> ExternResult<()> {
+    trace_call!("add_share_to_feed");
+    check_network_blocklist(&agent_info()?.agent_initial_pubkey, None)?;
+    check_not_banned(&input.feed_hash, &agent_info()?.agent_initial_pubkey)?;
+
+    // Adding a share that's already in the feed is a no-op
+    let existing = get_links(
+        LinkQuery::try_new(input.feed_hash.clone(), LinkTypes::FeedToShare)?,
+        GetStrategy::Local,
+    )?;
+    let share_target = AnyLinkableHash::from(input.share_item_hash.clone());
+    if existing.iter().any(|link| link.target == share_target) {
+        return Ok(());
+    }
+
+    // Carry a summary of the share in the tag so listings can skip the get
+    let share_record = get(input.share_item_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the ShareItem")),
+    )?;
+    let share_item: ShareItem = share_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a ShareItem entry",
+        )))?;
+
+    // Feeds that require approval, or whose keyword filters the share trips,
+    // hold shares from non-stewards for review
+    let feed: Feed = get_feed(input.feed_hash.clone().into())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Feed",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    let tag = feed_share_link_tag(&feed, &share_item, share_record.action().author().clone())?;
+    let is_steward = feed.stewards.contains(&agent_info()?.agent_initial_pubkey);
+    let filter_action = feed.keyword_filter_action(&share_item);
+    if !is_steward && filter_action == Some(KeywordFilterAction::Reject) {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "This share matches a keyword the feed doesn't allow",
+        ))
+        .into());
+    }
+    if !is_steward && (feed.requires_approval || filter_action.is_some()) {
+        let pending = get_links(
+            LinkQuery::try_new(input.feed_hash.clone(), LinkTypes::FeedToPendingShare)?,
+            GetStrategy::Local,
+        )?;
+        if !pending.iter().any(|link| link.target == share_target) {
+            create_link(
+                input.feed_hash,
+                input.share_item_hash,
+                LinkTypes::FeedToPendingShare,
+                tag,
+            )?;
+        }
+        return Ok(());
+    }
+
+    link_share_into_feed(input.feed_hash, input.share_item_hash, tag)
+}
+
+/// FeedToShare link tag for a share: its summary, plus its tags merged
+/// with the feed's default tags when the feed has any
+pub fn feed_share_link_tag(
+    feed: &Feed,
+    share_item: &ShareItem,
+    author: AgentPubKey,
+) -> ExternResult<LinkTag> {
+    let mut link_tag =
+        SharefeedLinkTag::new().with_summary(ShareLinkSummary::new(share_item, author));
+    if !feed.default_tags.is_empty() {
+        link_tag = link_tag.with_feed_tags(feed.tags_for_share(share_item));
+    }
+    link_tag.to_link_tag()
+}
+
+/// Shows shares in a feed with the tags the feed gave them, as recorded on
+/// their FeedToShare `links`
+pub fn with_feed_tags(share_items: Vec<ShareItemInfo>, links: &[Link]) -> Vec<ShareItemInfo> {
+    share_items
+        .into_iter()
+        .map(|mut info| {
+            let share_target = AnyLinkableHash::from(info.action_hash.clone());
+            let feed_tags = links
+                .iter()
+                .filter(|link| link.target == share_target)
+                .filter_map(|link| SharefeedLinkTag::from_link_tag(&link.tag))
+                .map(|link_tag| link_tag.feed_tags)
+                .find(|feed_tags| !feed_tags.is_empty());
+            if let Some(feed_tags) = feed_tags {
+                info.share_item.tags = feed_tags;
+            }
+            info
+        })
+        .collect()
+}
+
+/// Adds a share to a feed with a FeedToShare link carrying `tag` and its
+/// ShareItemToFeed mirror, and lets the feed's members know
+pub fn link_share_into_feed(
+    feed_hash: ActionHash,
+    share_item_hash: ActionHash,
+    tag: LinkTag,
+) -> ExternResult<()> {
+    // Tags held for approval were made before the moderators' latest
+    // actions, so the heads are taken now
+    let tag = SharefeedLinkTag::from_link_tag(&tag)
+        .unwrap_or_default()
+        .with_moderator_chain_heads(get_moderator_chain_heads()?)
+        .to_link_tag()?;
     create_link(
-        input.feed_hash,
-        input.share_item_hash,
+        feed_hash.clone(),
+        share_item_hash.clone(),
         LinkTypes::FeedToShare,
-        (),
+        tag,
+    )?;
+    create_link(
+        share_item_hash.clone(),
+        feed_hash.clone(),
+        LinkTypes::ShareItemToFeed,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+
+    // Let the other members know about the new item
+    let members = get_feed_members(feed_hash.clone().into())?;
+    send_remote_signal_to_agents(
+        &Signal::NewShareInFeed {
+            feed_hash,
+            share_item_hash,
+            author: agent_info()?.agent_initial_pubkey,
+        },
+        members,
     )?;
     Ok(())
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct RemoveShareFromFeedInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub link_hash: ActionHash,
 }
 
+/// Deletes a FeedToShare link along with the ShareItemToFeed link mirroring
+/// it. Returns the deleted link when its details could be found. Stewards
+/// remove links other members wrote, so both are looked up on the network.
+pub fn unlink_share_from_feed(link_hash: ActionHash) -> ExternResult<Option<CreateLink>> {
+    let mut removed: Option<CreateLink> = None;
+    if let Some(Details::Record(details)) = get_details(link_hash.clone(), GetOptions::network())? {
+        if let Action::CreateLink(create_link) = details.record.action() {
+            let links = get_links(
+                LinkQuery::try_new(
+                    create_link.target_address.clone(),
+                    LinkTypes::ShareItemToFeed,
+                )?,
+                GetStrategy::Network,
+            )?;
+            for link in links {
+                if link.target == create_link.base_address && link.author == create_link.author {
+                    delete_link(link.create_link_hash, GetOptions::network())?;
+                }
+            }
+            removed = Some(create_link.clone());
+        }
+    }
+
+    delete_link(link_hash, GetOptions::network())?;
+    Ok(removed)
+}
+
 #[hdk_extern]
 pub fn remove_share_from_feed(input: RemoveShareFromFeedInput) -> ExternResult<()> {
-    delete_link(input.link_hash, GetOptions::local())?;
+    trace_call!("remove_share_from_feed");
+    let Some(create_link) = unlink_share_from_feed(input.link_hash)? else {
+        return Ok(());
+    };
+
+    // A steward taking out a share someone else added goes in the
+    // moderation log
+    let me = agent_info()?.agent_initial_pubkey;
+    if create_link.author != me {
+        let feed_hash =
+            ActionHash::try_from(create_link.base_address).map_err(|err| wasm_error!(err))?;
+        if get_feed_stewards(&feed_hash)?.contains(&me) {
+            let share_item_hash =
+                ActionHash::try_from(create_link.target_address).map_err(|err| wasm_error!(err))?;
+            create_moderation_action(ModerationAction {
+                feed_hash,
+                kind: ModerationKind::RemoveShare { share_item_hash },
+                note: None,
+            })?;
+        }
+    }
     Ok(())
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedShareLinkInfo {
+    /// What `remove_share_from_feed` takes
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub link_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub added_by: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub added_at: Timestamp,
+}
+
+/// The FeedToShare links of a feed, newest first, for removing shares
 #[hdk_extern]
-pub fn get_feed_shares(feed_hash: ActionHash) -> ExternResult<Vec<ShareItemInfo>> {
-    let links = get_links(
+pub fn get_feed_share_links(
+    feed_hash: ReadInput<ActionHash>,
+) -> ExternResult<Vec<FeedShareLinkInfo>> {
+    trace_call!("get_feed_share_links");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    let mut links = get_links(
         LinkQuery::try_new(feed_hash, LinkTypes::FeedToShare)?,
-        GetStrategy::Local,
+        strategy.get_strategy(),
+    )?;
+    links.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    Ok(links
+        .into_iter()
+        .filter_map(|link| {
+            Some(FeedShareLinkInfo {
+                share_item_hash: link.target.into_action_hash()?,
+                link_hash: link.create_link_hash,
+                added_by: link.author,
+                added_at: link.timestamp,
+            })
+        })
+        .collect())
+}
+
+/// Feeds a share has been added to, via the ShareItemToFeed reverse links
+pub fn get_feeds_for_share(
+    share_item_hash: ActionHash,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<ActionHash>> {
+    let links = get_links(
+        LinkQuery::try_new(share_item_hash, LinkTypes::ShareItemToFeed)?,
+        strategy.get_strategy(),
     )?;
 
-    let mut share_items: Vec<ShareItemInfo> = Vec::new();
+    let mut feed_hashes: Vec<ActionHash> = Vec::new();
     for link in links {
-        let action_hash =
-            ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
-        if let Some(record) = get(action_hash.clone(), GetOptions::local())? {
-            if let Some(share_item) = record
-                .entry()
-                .to_app_option::<ShareItem>()
-                .map_err(|e| wasm_error!(e))?
-            {
-                share_items.push(ShareItemInfo {
-                    action_hash,
-                    share_item,
-                    created_at: link.timestamp,
-                    author: record.action().author().clone(),
-                });
+        if let Ok(feed_hash) = ActionHash::try_from(link.target) {
+            if !feed_hashes.contains(&feed_hash) {
+                feed_hashes.push(feed_hash);
             }
         }
     }
+    Ok(feed_hashes)
+}
+
+#[hdk_extern]
+pub fn get_feed_shares(feed_hash: ReadInput<ActionHash>) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("get_feed_shares");
+    let language = feed_hash.language();
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToShare)?,
+        strategy.get_strategy(),
+    )?;
+
+    let share_items = share_item_infos_from_links(links.clone(), strategy)?;
+    Ok(localize_share_infos(
+        with_feed_tags(share_items, &links),
+        language,
+    ))
+}
+
+/// Number of shares in a feed, from its links alone
+#[hdk_extern]
+pub fn count_feed_shares(feed_hash: ReadInput<ActionHash>) -> ExternResult<usize> {
+    trace_call!("count_feed_shares");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToShare)?,
+        strategy.get_strategy(),
+    )?;
+    Ok(dedup_links_by_target(links).len())
+}
+
+/// `get_feed_shares` for peers whose DHT view is still syncing: shares
+/// missing locally are requested from whoever added them to the feed, then
+/// from the feed's stewards
+#[hdk_extern]
+pub fn get_feed_shares_with_fallback(
+    feed_hash: ReadInput<ActionHash>,
+) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("get_feed_shares_with_fallback");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    let stewards = match get_feed(ReadInput::WithStrategy {
+        input: feed_hash.clone(),
+        strategy,
+    })? {
+        Some(record) => record
+            .entry()
+            .to_app_option::<Feed>()
+            .map_err(|e| wasm_error!(e))?
+            .map(|feed| feed.stewards)
+            .unwrap_or_default(),
+        None => vec![],
+    };
+    let links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToShare)?,
+        strategy.get_strategy(),
+    )?;
+
+    share_item_infos_with_fallback(links, strategy, Some(&mut RemoteFetcher::new(stewards)))
+}
 
-    // Sort by created_at descending (newest first)
-    share_items.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+/// Lightweight `get_feed_shares` that renders from link tags alone
+#[hdk_extern]
+pub fn get_feed_share_summaries(
+    feed_hash: ReadInput<ActionHash>,
+) -> ExternResult<Vec<ShareSummaryInfo>> {
+    trace_call!("get_feed_share_summaries");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToShare)?,
+        strategy.get_strategy(),
+    )?;
 
-    Ok(share_items)
+    share_summaries_from_links(links, strategy)
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+pub const FEED_SHARES_CHUNK_SIZE: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedSharesChunkInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub chunk_index: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedSharesChunk {
+    pub shares: Vec<ShareItemInfo>,
+    pub chunk_index: usize,
+    pub total_chunks: usize,
+}
+
+/// One fixed-size chunk of a feed's shares, for rendering large feeds
+/// progressively. Chunks are counted from the oldest share, so adding shares
+/// only ever grows the last chunk and earlier indexes keep their contents;
+/// start from `total_chunks - 1` to show the newest shares first.
+#[hdk_extern]
+pub fn get_feed_shares_chunk(
+    input: ReadInput<FeedSharesChunkInput>,
+) -> ExternResult<FeedSharesChunk> {
+    trace_call!("get_feed_shares_chunk");
+    let (input, strategy) = input.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(input.feed_hash, LinkTypes::FeedToShare)?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut links = dedup_links_by_target(links);
+    links.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then_with(|| a.target.cmp(&b.target))
+    });
+    let total_chunks = links.len().div_ceil(FEED_SHARES_CHUNK_SIZE);
+
+    let chunk: Vec<Link> = links
+        .into_iter()
+        .skip(input.chunk_index.saturating_mul(FEED_SHARES_CHUNK_SIZE))
+        .take(FEED_SHARES_CHUNK_SIZE)
+        .collect();
+
+    Ok(FeedSharesChunk {
+        shares: share_item_infos_from_links(chunk, strategy)?,
+        chunk_index: input.chunk_index,
+        total_chunks,
+    })
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct FeedInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub action_hash: ActionHash,
     pub feed: Feed,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
     pub created_at: Timestamp,
 }
 
 #[hdk_extern]
-pub fn get_my_feeds(_: ()) -> ExternResult<Vec<FeedInfo>> {
+pub fn get_my_feeds(input: ReadInput<()>) -> ExternResult<Vec<FeedInfo>> {
+    trace_call!("get_my_feeds");
+    let (_, strategy) = input.into_parts();
     let agent_info = agent_info()?;
     let links = get_links(
         LinkQuery::try_new(agent_info.agent_initial_pubkey, LinkTypes::AgentToFeed)?,
-        GetStrategy::Local,
+        strategy.get_strategy(),
     )?;
 
+    let action_hashes = links
+        .iter()
+        .map(|link| ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err)))
+        .collect::<ExternResult<Vec<ActionHash>>>()?;
+    let records = get_records(action_hashes.clone(), strategy)?;
+
     let mut feeds: Vec<FeedInfo> = Vec::new();
-    for link in links {
-        let action_hash =
-            ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
-        if let Some(record) = get(action_hash.clone(), GetOptions::local())? {
+    for ((link, action_hash), record) in links.into_iter().zip(action_hashes).zip(records) {
+        if let Some(record) = record {
             if let Some(feed) = record
                 .entry()
                 .to_app_option::<Feed>()
@@ -175,34 +670,272 @@ pub fn get_my_feeds(_: ()) -> ExternResult<Vec<FeedInfo>> {
     Ok(feeds)
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct AddMemberToFeedInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
     pub member_pubkey: AgentPubKey,
 }
 
 #[hdk_extern]
 pub fn add_member_to_feed(input: AddMemberToFeedInput) -> ExternResult<()> {
+    trace_call!("add_member_to_feed");
+    check_not_banned(&input.feed_hash, &input.member_pubkey)?;
+    // Adding a member I already added is a no-op rather than a second link.
+    // Validation only compares links on my own chain, so only mine are read.
+    let added_by = agent_info()?.agent_initial_pubkey;
+    let member = AnyLinkableHash::from(input.member_pubkey.clone());
+    let my_links = get_links(
+        LinkQuery::try_new(input.feed_hash.clone(), LinkTypes::FeedToMember)?
+            .author(added_by.clone()),
+        GetStrategy::Local,
+    )?;
+    if my_links.iter().any(|link| link.target == member) {
+        return Ok(());
+    }
+
+    // Validation checks the feed as created, so the same feed is checked here
+    let original_feed: Feed = get(input.feed_hash.clone(), GetOptions::network())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Feed",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    if !original_feed.stewards.contains(&added_by)
+        && !(input.member_pubkey == added_by && original_feed.is_public)
+    {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only stewards can add members to a feed, unless it's public",
+        ))
+        .into());
+    }
+
     create_link(
-        input.feed_hash,
-        input.member_pubkey,
+        input.feed_hash.clone(),
+        input.member_pubkey.clone(),
         LinkTypes::FeedToMember,
-        (),
+        SharefeedLinkTag::new()
+            .with_steward_chain_heads(get_chain_heads(&original_feed.stewards)?)
+            .to_link_tag()?,
+    )?;
+
+    // Tell the new member so their client can show the feed and create
+    // their own AgentToFeed link
+    send_remote_signal_to_agents(
+        &Signal::AddedToFeed {
+            feed_hash: input.feed_hash.clone(),
+            added_by: added_by.clone(),
+        },
+        vec![input.member_pubkey.clone()],
     )?;
     Ok(())
 }
 
+/// Tells the members of a feed that announces new members about one added
+/// by a FeedToMember link I just committed, so they can fetch the link from
+/// me to check who was added. Stewards are listed in the feed itself, so
+/// the links made for them aren't announced. Called from `post_commit`.
+pub fn announce_new_member(create_link: &CreateLink, link_hash: &ActionHash) -> ExternResult<()> {
+    let feed_to_member = ScopedLinkType::try_from(LinkTypes::FeedToMember)?;
+    if create_link.zome_index != feed_to_member.zome_index
+        || create_link.link_type != feed_to_member.zome_type
+    {
+        return Ok(());
+    }
+    let feed_hash =
+        ActionHash::try_from(create_link.base_address.clone()).map_err(|err| wasm_error!(err))?;
+    let member = AgentPubKey::try_from(create_link.target_address.clone())
+        .map_err(|err| wasm_error!(err))?;
+    let feed: Feed = get_feed(feed_hash.clone().into())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Feed",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    if !feed.announce_new_members || feed.stewards.contains(&member) {
+        return Ok(());
+    }
+
+    let signal = Signal::MemberJoined {
+        feed_hash: feed_hash.clone(),
+        member: member.clone(),
+        added_by: create_link.author.clone(),
+        member_link_hash: link_hash.clone(),
+    };
+    // Members are read a page at a time so large feeds stay bounded
+    let mut cursor = None;
+    loop {
+        let page = get_feed_members_page(
+            FeedMembersPageInput {
+                feed_hash: feed_hash.clone(),
+                cursor,
+                limit: Some(MAX_MEMBERS_PAGE_SIZE),
+            }
+            .into(),
+        )?;
+        let members: Vec<AgentPubKey> = page
+            .members
+            .into_iter()
+            .map(|feed_member| feed_member.agent)
+            .filter(|agent| *agent != member)
+            .collect();
+        send_remote_signal_to_agents(&signal, members)?;
+        match page.next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+    Ok(())
+}
+
+/// Removes me from a feed's members
 #[hdk_extern]
-pub fn get_feed_members(feed_hash: ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+pub fn leave_feed(feed_hash: ActionHash) -> ExternResult<()> {
+    trace_call!("leave_feed");
+    let me = AnyLinkableHash::from(agent_info()?.agent_initial_pubkey);
     let links = get_links(
         LinkQuery::try_new(feed_hash, LinkTypes::FeedToMember)?,
-        GetStrategy::Local,
+        GetStrategy::Network,
     )?;
+    for link in links.into_iter().filter(|link| link.target == me) {
+        delete_link(link.create_link_hash, GetOptions::network())?;
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_feed_members(feed_hash: ReadInput<ActionHash>) -> ExternResult<Vec<AgentPubKey>> {
+    trace_call!("get_feed_members");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToMember)?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut members: Vec<AgentPubKey> = Vec::new();
+    for link in links {
+        if let Ok(member) = AgentPubKey::try_from(link.target) {
+            if !members.contains(&member) {
+                members.push(member);
+            }
+        }
+    }
+
+    Ok(members)
+}
 
-    let members: Vec<AgentPubKey> = links
+const DEFAULT_MEMBERS_PAGE_SIZE: usize = 100;
+const MAX_MEMBERS_PAGE_SIZE: usize = 500;
+
+/// Where a page of members ends. Links added in the same microsecond are
+/// told apart by their hash, so none are skipped between pages.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedMembersCursor {
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub added_at: Timestamp,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub create_link_hash: ActionHash,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedMembersPageInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    /// Only return members added after this cursor
+    pub cursor: Option<FeedMembersCursor>,
+    /// At most 500; 0 is treated as 1
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedMember {
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub agent: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub added_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedMembersPage {
+    pub members: Vec<FeedMember>,
+    /// Pass as `cursor` to fetch the next page; `None` on the last page
+    pub next_cursor: Option<FeedMembersCursor>,
+}
+
+/// Members of a feed in the order they were added, one page at a time.
+/// Only links added since the cursor are fetched, never their targets. A
+/// member added more than once is listed once per page, at their first
+/// link in it, so one re-added by another steward may show up again on a
+/// later page.
+#[hdk_extern]
+pub fn get_feed_members_page(
+    input: ReadInput<FeedMembersPageInput>,
+) -> ExternResult<FeedMembersPage> {
+    trace_call!("get_feed_members_page");
+    let (input, strategy) = input.into_parts();
+    let limit = input
+        .limit
+        .unwrap_or(DEFAULT_MEMBERS_PAGE_SIZE)
+        .clamp(1, MAX_MEMBERS_PAGE_SIZE);
+
+    let mut query = LinkQuery::try_new(input.feed_hash, LinkTypes::FeedToMember)?;
+    // Starts a microsecond early so links sharing the cursor's timestamp are
+    // still fetched, then cut by hash below
+    if let Some(cursor) = &input.cursor {
+        query = query.after(Timestamp::from_micros(
+            cursor.added_at.as_micros().saturating_sub(1),
+        ));
+    }
+    let links = get_links(query, strategy.get_strategy())?;
+    let mut links: Vec<(FeedMembersCursor, AgentPubKey)> = dedup_links_by_target(links)
         .into_iter()
-        .filter_map(|link| AgentPubKey::try_from(link.target).ok())
+        .filter_map(|link| {
+            let agent = AgentPubKey::try_from(link.target).ok()?;
+            let cursor = FeedMembersCursor {
+                added_at: link.timestamp,
+                create_link_hash: link.create_link_hash,
+            };
+            Some((cursor, agent))
+        })
+        .filter(|(cursor, _)| input.cursor.as_ref().is_none_or(|after| cursor > after))
         .collect();
+    links.sort_by(|a, b| a.0.cmp(&b.0));
 
-    Ok(members)
+    let next_cursor = if links.len() > limit {
+        links.truncate(limit);
+        links.last().map(|(cursor, _)| cursor.clone())
+    } else {
+        None
+    };
+    Ok(FeedMembersPage {
+        members: links
+            .into_iter()
+            .map(|(cursor, agent)| FeedMember {
+                agent,
+                added_at: cursor.added_at,
+            })
+            .collect(),
+        next_cursor,
+    })
 }
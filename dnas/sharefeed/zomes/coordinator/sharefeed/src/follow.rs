@@ -0,0 +1,155 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::feed::get_my_feeds;
+use crate::read::{ReadInput, ReadStrategy};
+use crate::share_item::{
+    dedup_links_by_target, localize_share_infos, share_item_infos_from_links, ShareItemInfo,
+};
+
+const DEFAULT_TIMELINE_PAGE_SIZE: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct TimelineInput {
+    /// Only return shares created before this timestamp
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub cursor: Option<Timestamp>,
+    pub limit: Option<usize>,
+}
+
+#[hdk_extern]
+pub fn follow_agent(agent: AgentPubKey) -> ExternResult<()> {
+    trace_call!("follow_agent");
+    if get_following(())?.contains(&agent) {
+        return Ok(());
+    }
+    let agent_info = agent_info()?;
+    create_link(
+        agent_info.agent_initial_pubkey,
+        agent,
+        LinkTypes::AgentToFollowing,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn unfollow_agent(agent: AgentPubKey) -> ExternResult<()> {
+    trace_call!("unfollow_agent");
+    let agent_info = agent_info()?;
+    let links = get_links(
+        LinkQuery::try_new(agent_info.agent_initial_pubkey, LinkTypes::AgentToFollowing)?,
+        GetStrategy::Local,
+    )?;
+    for link in links {
+        if link.target == AnyLinkableHash::from(agent.clone()) {
+            delete_link(link.create_link_hash, GetOptions::local())?;
+        }
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_following(_: ()) -> ExternResult<Vec<AgentPubKey>> {
+    trace_call!("get_following");
+    let agent_info = agent_info()?;
+    let links = get_links(
+        LinkQuery::try_new(agent_info.agent_initial_pubkey, LinkTypes::AgentToFollowing)?,
+        GetStrategy::Local,
+    )?;
+
+    let mut following: Vec<AgentPubKey> = Vec::new();
+    for link in links {
+        if let Ok(agent) = AgentPubKey::try_from(link.target) {
+            if !following.contains(&agent) {
+                following.push(agent);
+            }
+        }
+    }
+    Ok(following)
+}
+
+/// All AuthorToShare links for an agent, newest first
+pub fn get_author_share_links(
+    author: AgentPubKey,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<Link>> {
+    let mut links = get_links(
+        LinkQuery::try_new(author, LinkTypes::AuthorToShare)?,
+        strategy.get_strategy(),
+    )?;
+    links.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    Ok(links)
+}
+
+/// Merges recent shares by the agents I follow, newest first. Pass the
+/// `created_at` of the last item as `cursor` to fetch the next page.
+#[hdk_extern]
+pub fn get_following_timeline(input: ReadInput<TimelineInput>) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("get_following_timeline");
+    let language = input.language();
+    let (input, strategy) = input.into_parts();
+    let limit = input.limit.unwrap_or(DEFAULT_TIMELINE_PAGE_SIZE);
+
+    let mut links: Vec<Link> = Vec::new();
+    for agent in get_following(())? {
+        // Each author contributes at most one page worth of candidates
+        links.extend(
+            get_author_share_links(agent, strategy)?
+                .into_iter()
+                .filter(|link| match input.cursor {
+                    Some(cursor) => link.timestamp < cursor,
+                    None => true,
+                })
+                .take(limit),
+        );
+    }
+    links.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    links.truncate(limit);
+
+    Ok(localize_share_infos(
+        share_item_infos_from_links(links, strategy)?,
+        language,
+    ))
+}
+
+/// Shares in the feeds I belong to and by the agents I follow, merged
+/// newest first. A share reached several ways appears once, at the time it
+/// first reached me. Pass the `created_at` of the last item as `cursor` to
+/// fetch the next page.
+#[hdk_extern]
+pub fn get_home_timeline(input: ReadInput<TimelineInput>) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("get_home_timeline");
+    let language = input.language();
+    let (input, strategy) = input.into_parts();
+    let limit = input.limit.unwrap_or(DEFAULT_TIMELINE_PAGE_SIZE);
+
+    let mut links: Vec<Link> = Vec::new();
+    for feed in get_my_feeds(ReadInput::WithStrategy {
+        input: (),
+        strategy,
+    })? {
+        links.extend(get_links(
+            LinkQuery::try_new(feed.action_hash, LinkTypes::FeedToShare)?,
+            strategy.get_strategy(),
+        )?);
+    }
+    for agent in get_following(())? {
+        links.extend(get_author_share_links(agent, strategy)?);
+    }
+
+    let mut links: Vec<Link> = dedup_links_by_target(links)
+        .into_iter()
+        .filter(|link| input.cursor.is_none_or(|cursor| link.timestamp < cursor))
+        .collect();
+    links.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    links.truncate(limit);
+
+    Ok(localize_share_infos(
+        share_item_infos_from_links(links, strategy)?,
+        language,
+    ))
+}
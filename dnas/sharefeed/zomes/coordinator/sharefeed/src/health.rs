@@ -0,0 +1,83 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::export::{my_deleted_actions, my_live_entries};
+use crate::network_blocklist::network_blocklist_path;
+use crate::share_item::{time_bucket_for_timestamp, time_path_for_timestamp};
+use crate::SCHEDULED_FUNCTIONS;
+
+/// Whether an anchor the zome reads from is in place
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct AnchorHealth {
+    pub anchor: String,
+    pub exists: bool,
+}
+
+/// Quick local metrics for a sync/health indicator. Nothing here goes to
+/// the network, so it answers as fast as the local store does.
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct HealthReport {
+    /// ShareItems on my chain that haven't been deleted
+    pub my_shares: usize,
+    /// Feeds I have an AgentToFeed link to
+    pub my_feeds: usize,
+    /// Functions `init` schedules. Holochain doesn't expose when they
+    /// next run, only that they were registered.
+    pub scheduled_tasks: Vec<String>,
+    pub anchors: Vec<AnchorHealth>,
+}
+
+fn anchor_health(anchor: String, path: Path, link_type: LinkTypes) -> ExternResult<AnchorHealth> {
+    let links = get_links(
+        LinkQuery::try_new(path.path_entry_hash()?, link_type)?,
+        GetStrategy::Local,
+    )?;
+    Ok(AnchorHealth {
+        anchor,
+        exists: !links.is_empty(),
+    })
+}
+
+#[hdk_extern]
+pub fn health_check(_: ()) -> ExternResult<HealthReport> {
+    trace_call!("health_check");
+    let deleted = my_deleted_actions()?;
+    let my_shares = my_live_entries::<ShareItem>(UnitEntryTypes::ShareItem, &deleted)?.len();
+
+    let my_feeds = get_links(
+        LinkQuery::try_new(agent_info()?.agent_initial_pubkey, LinkTypes::AgentToFeed)?,
+        GetStrategy::Local,
+    )?
+    .len();
+
+    // init ensures this week's time path; the blocklist anchor only exists
+    // once a moderator has published to it
+    let now = sys_time()?;
+    let (year, week) = time_bucket_for_timestamp(now);
+    let anchors = vec![
+        AnchorHealth {
+            anchor: format!("shares.{}.{:02}", year, week),
+            exists: time_path_for_timestamp(now)
+                .typed(LinkTypes::TimePath)?
+                .exists()?,
+        },
+        anchor_health(
+            String::from("network_blocklist"),
+            network_blocklist_path(),
+            LinkTypes::NetworkBlocklists,
+        )?,
+    ];
+
+    Ok(HealthReport {
+        my_shares,
+        my_feeds,
+        scheduled_tasks: SCHEDULED_FUNCTIONS
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+        anchors,
+    })
+}
@@ -0,0 +1,100 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+use crate::feed::create_feed;
+use crate::read::{ReadInput, ReadStrategy};
+
+/// The network's home feed, i.e. the target of the earliest HomeFeed link
+fn get_home_feed_hash(strategy: ReadStrategy) -> ExternResult<Option<ActionHash>> {
+    let links = get_links(
+        LinkQuery::try_new(home_feed_path().path_entry_hash()?, LinkTypes::HomeFeed)?,
+        strategy.get_strategy(),
+    )?;
+    let Some(link) = links
+        .into_iter()
+        .min_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp))
+    else {
+        return Ok(None);
+    };
+    Ok(link.target.into_action_hash())
+}
+
+/// Lists the home feed in my feeds, unless it's already there
+fn follow_feed(feed_hash: &ActionHash) -> ExternResult<()> {
+    let me = agent_info()?.agent_initial_pubkey;
+    let target = AnyLinkableHash::from(feed_hash.clone());
+    let links = get_links(
+        LinkQuery::try_new(me.clone(), LinkTypes::AgentToFeed)?,
+        GetStrategy::Local,
+    )?;
+    if links.iter().any(|link| link.target == target) {
+        return Ok(());
+    }
+    create_link(
+        me,
+        feed_hash.clone(),
+        LinkTypes::AgentToFeed,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    Ok(())
+}
+
+/// Run from `init` on networks whose properties name a home feed. The
+/// first progenitor to get here creates the feed; everyone else follows
+/// it. Agents whose `init` ran before the feed reached them can catch up
+/// with `follow_home_feed`.
+pub fn bootstrap_home_feed() -> ExternResult<()> {
+    let properties = sharefeed_properties()?;
+    let Some(name) = properties.home_feed.clone() else {
+        return Ok(());
+    };
+    if let Some(feed_hash) = get_home_feed_hash(ReadStrategy::Network)? {
+        return follow_feed(&feed_hash);
+    }
+    let me = agent_info()?.agent_initial_pubkey;
+    if !properties.is_progenitor(&me) {
+        return Ok(());
+    }
+
+    // create_feed lists it in my feeds and makes me its steward
+    let record = create_feed(Feed {
+        name,
+        description: None,
+        stewards: vec![me],
+        is_public: true,
+        requires_approval: false,
+        keyword_filters: Vec::new(),
+        seen_receipts: false,
+        default_tags: Vec::new(),
+        announce_new_members: false,
+        steward_quorum: None,
+    })?;
+    create_link(
+        home_feed_path().path_entry_hash()?,
+        record.action_address().clone(),
+        LinkTypes::HomeFeed,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    Ok(())
+}
+
+/// The network's home feed, if its properties name one and a progenitor
+/// has created it
+#[hdk_extern]
+pub fn get_home_feed(input: ReadInput<()>) -> ExternResult<Option<ActionHash>> {
+    trace_call!("get_home_feed");
+    let (_, strategy) = input.into_parts();
+    get_home_feed_hash(strategy)
+}
+
+/// Follows the network's home feed, for agents whose `init` ran before it
+/// reached them. Returns the feed followed, if there is one yet.
+#[hdk_extern]
+pub fn follow_home_feed(_: ()) -> ExternResult<Option<ActionHash>> {
+    trace_call!("follow_home_feed");
+    let Some(feed_hash) = get_home_feed_hash(ReadStrategy::Network)? else {
+        return Ok(None);
+    };
+    follow_feed(&feed_hash)?;
+    Ok(Some(feed_hash))
+}
@@ -0,0 +1,895 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::blocklist::{block_agent, get_blocked_agents, mute_agent};
+use crate::comment::create_comment;
+use crate::error::SharefeedError;
+use crate::export::{
+    my_deleted_actions, my_live_entries, ExportedEntry, PersonalDataExport, PERSONAL_DATA_VERSION,
+};
+use crate::feed::{add_share_to_feed, create_feed, AddShareToFeedInput};
+use crate::follow::follow_agent;
+use crate::notification_prefs::set_notification_prefs;
+use crate::reaction::add_reaction;
+use crate::saved_search::{create_saved_search, get_saved_searches};
+use crate::share_item::create_share_item;
+
+/// Most bookmarks accepted by a single import call; larger exports must be
+/// split so each call stays within the conductor's time limits
+pub const MAX_IMPORT_ITEMS: usize = 500;
+
+/// Longest character reference decoded, e.g. `&#x1F600;`
+const MAX_ENTITY_CHARS: usize = 10;
+
+/// A bookmark parsed from an export, before it becomes a ShareItem
+#[derive(Debug, Clone, Default)]
+pub struct ImportedBookmark {
+    pub url: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    /// When it was saved in the service it came from
+    pub added_at: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum ImportItemStatus {
+    Imported {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        share_item_hash: ActionHash,
+    },
+    /// Already shared by me, or repeated earlier in the same import
+    Skipped {
+        reason: String,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ImportItemResult {
+    pub url: String,
+    pub title: String,
+    pub status: ImportItemStatus,
+}
+
+/// Outcome of an import, one result per bookmark in the order they
+/// appeared in the export
+#[derive(Serialize, Deserialize, Debug, Clone, Default, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ImportReport {
+    pub imported: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub items: Vec<ImportItemResult>,
+}
+
+impl ImportReport {
+    fn record(&mut self, url: String, title: String, status: ImportItemStatus) {
+        match status {
+            ImportItemStatus::Imported { .. } => self.imported += 1,
+            ImportItemStatus::Skipped { .. } => self.skipped += 1,
+            ImportItemStatus::Failed { .. } => self.failed += 1,
+        }
+        self.items.push(ImportItemResult { url, title, status });
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ImportBookmarksInput {
+    /// A bookmarks file in the Netscape format browsers export
+    pub html: String,
+    /// Feed to add every imported share to
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash | null"))]
+    #[schemars(with = "Option<ActionHashSchema>")]
+    pub target_feed: Option<ActionHash>,
+}
+
+/// Normalized URLs I have already shared
+fn my_shared_urls() -> ExternResult<HashSet<String>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::ShareItem.try_into()?)
+            .include_entries(true),
+    )?;
+    let mut urls: HashSet<String> = HashSet::new();
+    for record in records {
+        if let Some(share_item) = record
+            .entry()
+            .to_app_option::<ShareItem>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            urls.insert(normalize_url(&share_item.url));
+        }
+    }
+    Ok(urls)
+}
+
+fn import_bookmark(
+    bookmark: ImportedBookmark,
+    source: &str,
+    now: Timestamp,
+    target_feed: &Option<ActionHash>,
+) -> ExternResult<ActionHash> {
+    let share_item = ShareItem {
+        url: bookmark.url,
+        title: bookmark.title,
+        description: bookmark.description,
+        selection: None,
+        favicon: None,
+        thumbnail: None,
+        tags: bookmark.tags,
+        provenance: Some(ShareProvenance {
+            source: source.to_string(),
+            // Validation rejects save times after the share itself
+            original_added_at: bookmark.added_at.filter(|added_at| *added_at <= now),
+            original_action_hash: None,
+        }),
+        kind: ShareKind::Link,
+        body: None,
+        translations: BTreeMap::new(),
+        moderator_chain_heads: Vec::new(),
+    };
+    let share_item_hash = create_share_item(share_item)?.action_address().clone();
+    if let Some(feed_hash) = target_feed {
+        add_share_to_feed(AddShareToFeedInput {
+            feed_hash: feed_hash.clone(),
+            share_item_hash: share_item_hash.clone(),
+        })?;
+    }
+    Ok(share_item_hash)
+}
+
+/// Creates a ShareItem for each bookmark, recording `source` and the
+/// original save time as its provenance, and optionally adds it to
+/// `target_feed`. A bookmark that can't be imported is reported and the
+/// rest carry on. Shared by every importer.
+pub fn import_bookmarks_into(
+    bookmarks: Vec<ImportedBookmark>,
+    source: &str,
+    target_feed: Option<ActionHash>,
+) -> ExternResult<ImportReport> {
+    if bookmarks.len() > MAX_IMPORT_ITEMS {
+        return Err(SharefeedError::InvalidInput(format!(
+            "Imports are limited to {} bookmarks per call, this one has {}",
+            MAX_IMPORT_ITEMS,
+            bookmarks.len()
+        ))
+        .into());
+    }
+
+    let now = sys_time()?;
+    let mut seen = my_shared_urls()?;
+    let mut report = ImportReport::default();
+    for bookmark in bookmarks {
+        let url = bookmark.url.clone();
+        let title = bookmark.title.clone();
+        let status = if !url.starts_with("http://") && !url.starts_with("https://") {
+            ImportItemStatus::Failed {
+                error: String::from("Only http and https links can be imported"),
+            }
+        } else if !seen.insert(normalize_url(&url)) {
+            ImportItemStatus::Skipped {
+                reason: String::from("Already shared"),
+            }
+        } else {
+            match import_bookmark(bookmark, source, now, &target_feed) {
+                Ok(share_item_hash) => ImportItemStatus::Imported { share_item_hash },
+                Err(err) => ImportItemStatus::Failed {
+                    error: format!("{:?}", err),
+                },
+            }
+        };
+        report.record(url, title, status);
+    }
+    Ok(report)
+}
+
+/// Decodes the character references that appear in bookmark exports
+pub fn html_unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|end| *end <= MAX_ENTITY_CHARS) else {
+            unescaped.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                unescaped.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// Value of `name` in a tag's attribute text, e.g. `HREF="..."`
+fn html_attribute(attributes: &str, name: &str) -> Option<String> {
+    let lowercase = attributes.to_ascii_lowercase();
+    let needle = format!("{}=\"", name.to_ascii_lowercase());
+    let mut from = 0;
+    while let Some(found) = lowercase[from..].find(&needle) {
+        let start = from + found;
+        let preceded_by_space = start == 0
+            || lowercase[..start]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_whitespace);
+        let value_start = start + needle.len();
+        if preceded_by_space {
+            let value_end = value_start + attributes[value_start..].find('"')?;
+            return Some(html_unescape(&attributes[value_start..value_end]));
+        }
+        from = value_start;
+    }
+    None
+}
+
+/// Parses a count of seconds since the epoch, as exports write save times
+pub fn timestamp_from_unix_seconds(seconds: &str) -> Option<Timestamp> {
+    let seconds: i64 = seconds.trim().parse().ok()?;
+    if seconds <= 0 {
+        return None;
+    }
+    Some(Timestamp::from_micros(seconds.checked_mul(1_000_000)?))
+}
+
+/// Parses an RFC 3339 date-time such as `2024-03-01T12:00:00.000Z` or
+/// `2024-03-01T13:00:00+01:00`; fractional seconds are dropped
+pub fn timestamp_from_rfc3339(text: &str) -> Option<Timestamp> {
+    let text = text.trim();
+    let number = |range: std::ops::Range<usize>| -> Option<i64> { text.get(range)?.parse().ok() };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let mut rest = text.get(19..)?;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        rest = fraction.trim_start_matches(|c: char| c.is_ascii_digit());
+    }
+    let offset_seconds = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.chars().next()? {
+                '+' => 1,
+                '-' => -1,
+                _ => return None,
+            };
+            let hours: i64 = rest.get(1..3)?.parse().ok()?;
+            let minutes: i64 = rest.get(4..6)?.parse().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    // Civil date to days since the epoch, after Howard Hinnant's
+    // `days_from_civil`
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    let seconds = days * 86400 + hour * 3600 + minute * 60 + second - offset_seconds;
+    Some(Timestamp::from_micros(seconds.checked_mul(1_000_000)?))
+}
+
+/// Text up to the next tag, decoded and trimmed
+fn html_text(html: &str) -> String {
+    let end = html.find('<').unwrap_or(html.len());
+    html_unescape(html[..end].trim())
+}
+
+/// Parses the Netscape bookmarks format every major browser exports.
+/// Each bookmark is tagged with the names of the folders it sits in, as
+/// well as any `TAGS` it carries. Bookmarks without a URL are dropped.
+pub fn parse_netscape_bookmarks(html: &str) -> Vec<ImportedBookmark> {
+    let mut bookmarks: Vec<ImportedBookmark> = Vec::new();
+    let mut folders: Vec<String> = Vec::new();
+    let mut pending_folder: Option<String> = None;
+    // Folder names are pushed when their `<DL>` opens, so every `<DL>`
+    // records whether it opened a folder
+    let mut lists: Vec<bool> = Vec::new();
+    // A `<DD>` describes the bookmark right before it, if any
+    let mut describable: Option<usize> = None;
+
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..end];
+        let after = &rest[end + 1..];
+        let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+
+        match name.to_ascii_uppercase().as_str() {
+            "H3" => {
+                pending_folder = Some(html_text(after));
+                describable = None;
+            }
+            "DL" => {
+                describable = None;
+                let folder = pending_folder.take();
+                lists.push(folder.is_some());
+                folders.extend(folder);
+            }
+            "/DL" => {
+                describable = None;
+                if lists.pop() == Some(true) {
+                    folders.pop();
+                }
+            }
+            "A" => {
+                let Some(url) = html_attribute(attributes, "HREF") else {
+                    rest = after;
+                    continue;
+                };
+                let mut tags: Vec<String> = folders
+                    .iter()
+                    .filter(|folder| !folder.is_empty())
+                    .cloned()
+                    .collect();
+                if let Some(extra) = html_attribute(attributes, "TAGS") {
+                    for tag in split_tags(&extra, ',') {
+                        if !tags.contains(&tag) {
+                            tags.push(tag);
+                        }
+                    }
+                }
+                let title = html_text(after);
+                bookmarks.push(ImportedBookmark {
+                    title: if title.is_empty() { url.clone() } else { title },
+                    url,
+                    description: None,
+                    tags,
+                    added_at: html_attribute(attributes, "ADD_DATE")
+                        .as_deref()
+                        .and_then(timestamp_from_unix_seconds),
+                });
+                describable = Some(bookmarks.len() - 1);
+            }
+            "DD" => {
+                let description = html_text(after);
+                if let Some(index) = describable.take() {
+                    if !description.is_empty() {
+                        bookmarks[index].description = Some(description);
+                    }
+                }
+            }
+            _ => {}
+        }
+        rest = after;
+    }
+    bookmarks
+}
+
+/// Imports a browser bookmarks export, mapping folder names to tags
+#[hdk_extern]
+pub fn import_bookmarks(input: ImportBookmarksInput) -> ExternResult<ImportReport> {
+    trace_call!("import_bookmarks");
+    import_bookmarks_into(
+        parse_netscape_bookmarks(&input.html),
+        "bookmarks",
+        input.target_feed,
+    )
+}
+
+/// Rows of a CSV document, handling quoted fields with embedded commas,
+/// doubled quotes and line breaks
+pub fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            ('\r', false) => {}
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows.retain(|row| row.iter().any(|field| !field.trim().is_empty()));
+    rows
+}
+
+/// Pocket's CSV export: a `title,url,time_added,tags,status` header, with
+/// tags separated by `|`
+fn parse_pocket_csv(text: &str) -> ExternResult<Vec<ImportedBookmark>> {
+    let mut rows = parse_csv(text).into_iter();
+    let Some(header) = rows.next() else {
+        return Ok(Vec::new());
+    };
+    let column = |name: &str| header.iter().position(|field| field.trim() == name);
+    let Some(url_column) = column("url") else {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Pocket CSV export must have a url column",
+        ))
+        .into());
+    };
+    let title_column = column("title");
+    let time_column = column("time_added");
+    let tags_column = column("tags");
+
+    let field = |row: &[String], column: Option<usize>| -> String {
+        column
+            .and_then(|column| row.get(column))
+            .map(|field| field.trim().to_string())
+            .unwrap_or_default()
+    };
+    Ok(rows
+        .map(|row| {
+            let url = field(&row, Some(url_column));
+            let title = field(&row, title_column);
+            ImportedBookmark {
+                title: if title.is_empty() { url.clone() } else { title },
+                url,
+                description: None,
+                tags: split_tags(&field(&row, tags_column), '|'),
+                added_at: timestamp_from_unix_seconds(&field(&row, time_column)),
+            }
+        })
+        .collect())
+}
+
+fn split_tags(tags: &str, separator: char) -> Vec<String> {
+    let mut split: Vec<String> = Vec::new();
+    for tag in tags.split(separator).map(str::trim) {
+        if !tag.is_empty() && !split.iter().any(|existing| existing == tag) {
+            split.push(tag.to_string());
+        }
+    }
+    split
+}
+
+/// First non-empty string among `keys` of a JSON object
+fn json_str(item: &serde_json::Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .filter_map(|key| item.get(key)?.as_str())
+        .map(str::trim)
+        .find(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Seconds since the epoch, written as a number or a string
+fn json_unix_seconds(value: Option<&serde_json::Value>) -> Option<Timestamp> {
+    match value? {
+        serde_json::Value::Number(number) => {
+            timestamp_from_unix_seconds(&number.as_i64()?.to_string())
+        }
+        serde_json::Value::String(seconds) => timestamp_from_unix_seconds(seconds),
+        _ => None,
+    }
+}
+
+/// Pocket's JSON export, as returned by its retrieve API: a `list` object
+/// keyed by item id, whose `tags` is an object keyed by tag name
+fn parse_pocket_json(text: &str) -> ExternResult<Vec<ImportedBookmark>> {
+    let json: serde_json::Value = serde_json::from_str(text).map_err(|err| {
+        SharefeedError::InvalidInput(format!("Pocket export is not valid JSON: {}", err))
+    })?;
+    let items: Vec<&serde_json::Value> = match &json {
+        serde_json::Value::Object(object) => match object.get("list") {
+            Some(serde_json::Value::Object(list)) => list.values().collect(),
+            _ => object.values().collect(),
+        },
+        serde_json::Value::Array(items) => items.iter().collect(),
+        _ => Vec::new(),
+    };
+
+    let mut bookmarks: Vec<ImportedBookmark> = Vec::new();
+    for item in items {
+        let Some(url) = json_str(item, &["given_url", "resolved_url", "url"]) else {
+            continue;
+        };
+        let tags = match item.get("tags") {
+            Some(serde_json::Value::Object(tags)) => tags.keys().cloned().collect(),
+            Some(serde_json::Value::String(tags)) => split_tags(tags, '|'),
+            _ => Vec::new(),
+        };
+        bookmarks.push(ImportedBookmark {
+            title: json_str(item, &["resolved_title", "given_title", "title"])
+                .unwrap_or_else(|| url.clone()),
+            description: json_str(item, &["excerpt"]),
+            tags,
+            added_at: json_unix_seconds(item.get("time_added")),
+            url,
+        });
+    }
+    // Pocket keys items by id, so restore the order they were saved in
+    bookmarks.sort_by_key(|bookmark| bookmark.added_at);
+    Ok(bookmarks)
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ImportPocketInput {
+    /// The contents of a Pocket export, either CSV or JSON
+    pub csv_or_json: String,
+    /// Feed to add every imported share to
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash | null"))]
+    #[schemars(with = "Option<ActionHashSchema>")]
+    pub target_feed: Option<ActionHash>,
+}
+
+/// Imports a Pocket export, keeping each item's original save time in the
+/// share's provenance
+#[hdk_extern]
+pub fn import_pocket(input: ImportPocketInput) -> ExternResult<ImportReport> {
+    trace_call!("import_pocket");
+    let text = input.csv_or_json.trim_start();
+    let bookmarks = if text.starts_with('{') || text.starts_with('[') {
+        parse_pocket_json(text)?
+    } else {
+        parse_pocket_csv(text)?
+    };
+    import_bookmarks_into(bookmarks, "pocket", input.target_feed)
+}
+
+/// Bookmarking services whose JSON exports can be imported
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum BookmarkingService {
+    /// An array of posts with `href`, `description`, `extended`, a
+    /// space-separated `tags` string and an RFC 3339 `time`
+    Pinboard,
+    /// An array of raindrops, or an object with an `items` array, with
+    /// `link`, `title`, `excerpt`, a `tags` array and an RFC 3339 `created`
+    Raindrop,
+}
+
+impl BookmarkingService {
+    fn source(self) -> &'static str {
+        match self {
+            BookmarkingService::Pinboard => "pinboard",
+            BookmarkingService::Raindrop => "raindrop",
+        }
+    }
+
+    fn parse_item(self, item: &serde_json::Value) -> Option<ImportedBookmark> {
+        match self {
+            BookmarkingService::Pinboard => {
+                let url = json_str(item, &["href"])?;
+                Some(ImportedBookmark {
+                    title: json_str(item, &["description"]).unwrap_or_else(|| url.clone()),
+                    description: json_str(item, &["extended"]),
+                    tags: split_tags(
+                        item.get("tags")
+                            .and_then(|tags| tags.as_str())
+                            .unwrap_or_default(),
+                        ' ',
+                    ),
+                    added_at: json_str(item, &["time"])
+                        .as_deref()
+                        .and_then(timestamp_from_rfc3339),
+                    url,
+                })
+            }
+            BookmarkingService::Raindrop => {
+                let url = json_str(item, &["link"])?;
+                let tags = match item.get("tags") {
+                    Some(serde_json::Value::Array(tags)) => {
+                        let tags: Vec<&str> = tags.iter().filter_map(|tag| tag.as_str()).collect();
+                        split_tags(&tags.join(","), ',')
+                    }
+                    Some(serde_json::Value::String(tags)) => split_tags(tags, ','),
+                    _ => Vec::new(),
+                };
+                Some(ImportedBookmark {
+                    title: json_str(item, &["title"]).unwrap_or_else(|| url.clone()),
+                    description: json_str(item, &["excerpt", "note"]),
+                    tags,
+                    added_at: json_str(item, &["created"])
+                        .as_deref()
+                        .and_then(timestamp_from_rfc3339),
+                    url,
+                })
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ImportBookmarkingServiceInput {
+    /// The contents of the service's JSON export
+    pub json: String,
+    pub format: BookmarkingService,
+    /// Feed to add every imported share to
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash | null"))]
+    #[schemars(with = "Option<ActionHashSchema>")]
+    pub target_feed: Option<ActionHash>,
+}
+
+/// Imports a Pinboard or Raindrop JSON export. Entries without a URL are
+/// dropped before import.
+#[hdk_extern]
+pub fn import_bookmarking_service(
+    input: ImportBookmarkingServiceInput,
+) -> ExternResult<ImportReport> {
+    trace_call!("import_bookmarking_service");
+    let json: serde_json::Value = serde_json::from_str(&input.json).map_err(|err| {
+        SharefeedError::InvalidInput(format!("Export is not valid JSON: {}", err))
+    })?;
+    let Some(items) = json
+        .as_array()
+        .or_else(|| json.get("items").and_then(|items| items.as_array()))
+    else {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Export must be an array of bookmarks or have an items array",
+        ))
+        .into());
+    };
+    let bookmarks = items
+        .iter()
+        .filter_map(|item| input.format.parse_item(item))
+        .collect();
+    import_bookmarks_into(bookmarks, input.format.source(), input.target_feed)
+}
+
+/// Source recorded on shares restored from a personal data export that
+/// weren't imported from elsewhere in the first place
+pub const RESTORE_SOURCE: &str = "sharefeed";
+
+/// Outcome of `import_my_data`
+#[derive(Serialize, Deserialize, Debug, Clone, Default, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct RestoreReport {
+    /// One result per exported share, as for the other importers
+    pub shares: ImportReport,
+    pub feeds: usize,
+    pub feed_shares: usize,
+    pub comments: usize,
+    pub reactions: usize,
+    /// Everything else that couldn't be restored, e.g. a comment on a share
+    /// that doesn't exist on this network
+    pub failures: Vec<String>,
+}
+
+/// The hash `hash` was restored under, or `hash` itself when it wasn't one
+/// of mine and may still exist on this network
+fn restored_hash(restored: &HashMap<ActionHash, ActionHash>, hash: ActionHash) -> ActionHash {
+    restored.get(&hash).cloned().unwrap_or(hash)
+}
+
+/// An exported share with provenance pointing back at the original. Shares
+/// that were imported keep the service they came from.
+fn restored_share_item(exported: ExportedEntry<ShareItem>) -> ShareItem {
+    let mut share_item = exported.entry;
+    let provenance = share_item.provenance.take();
+    share_item.provenance = Some(ShareProvenance {
+        source: provenance
+            .as_ref()
+            .map_or_else(|| RESTORE_SOURCE.to_string(), |p| p.source.clone()),
+        original_added_at: Some(
+            provenance
+                .and_then(|p| p.original_added_at)
+                .unwrap_or(exported.created_at),
+        ),
+        original_action_hash: Some(exported.action_hash),
+    });
+    share_item
+}
+
+/// Re-creates the contents of an `export_my_data` document on this chain.
+/// Shares, feeds, comments and reactions that were already restored are
+/// matched rather than duplicated, so a restore that was cut short can
+/// simply be run again. Hashes inside the document are rewritten to the
+/// restored copies, and the exporting agent is replaced by me as a steward.
+#[hdk_extern]
+pub fn import_my_data(document: PersonalDataExport) -> ExternResult<RestoreReport> {
+    trace_call!("import_my_data");
+    if document.version > PERSONAL_DATA_VERSION {
+        return Err(SharefeedError::InvalidInput(format!(
+            "Export format version {} is newer than the supported version {}",
+            document.version, PERSONAL_DATA_VERSION
+        ))
+        .into());
+    }
+
+    let me = agent_info()?.agent_initial_pubkey;
+    let deleted = my_deleted_actions()?;
+    let mut report = RestoreReport::default();
+
+    // Exported share hash -> the share on this chain
+    let mut share_hashes: HashMap<ActionHash, ActionHash> = HashMap::new();
+    let mut shares_by_url: HashMap<String, ActionHash> = HashMap::new();
+    for mine in my_live_entries::<ShareItem>(UnitEntryTypes::ShareItem, &deleted)? {
+        if let Some(original) = mine
+            .entry
+            .provenance
+            .as_ref()
+            .and_then(|provenance| provenance.original_action_hash.clone())
+        {
+            share_hashes.insert(original, mine.action_hash.clone());
+        }
+        shares_by_url.insert(normalize_url(&mine.entry.url), mine.action_hash);
+    }
+    for exported in document.share_items {
+        let url = exported.entry.url.clone();
+        let title = exported.entry.title.clone();
+        let original = exported.action_hash.clone();
+        let existing = share_hashes
+            .get(&original)
+            .or_else(|| shares_by_url.get(&normalize_url(&url)))
+            .cloned();
+        let status = match existing {
+            Some(share_item_hash) => {
+                share_hashes.insert(original, share_item_hash);
+                ImportItemStatus::Skipped {
+                    reason: String::from("Already shared"),
+                }
+            }
+            None => match create_share_item(restored_share_item(exported)) {
+                Ok(record) => {
+                    let share_item_hash = record.action_address().clone();
+                    share_hashes.insert(original, share_item_hash.clone());
+                    shares_by_url.insert(normalize_url(&url), share_item_hash.clone());
+                    ImportItemStatus::Imported { share_item_hash }
+                }
+                Err(err) => ImportItemStatus::Failed {
+                    error: format!("{:?}", err),
+                },
+            },
+        };
+        report.shares.record(url, title, status);
+    }
+
+    let mut feed_hashes: HashMap<ActionHash, ActionHash> = HashMap::new();
+    let mut my_feeds = my_live_entries::<Feed>(UnitEntryTypes::Feed, &deleted)?;
+    for exported in document.feeds {
+        let mut feed = exported.entry;
+        for steward in feed.stewards.iter_mut() {
+            if *steward == document.agent {
+                *steward = me.clone();
+            }
+        }
+        if let Some(mine) = my_feeds.iter().find(|mine| mine.entry == feed) {
+            feed_hashes.insert(exported.action_hash, mine.action_hash.clone());
+            continue;
+        }
+        match create_feed(feed.clone()) {
+            Ok(record) => {
+                feed_hashes.insert(exported.action_hash, record.action_address().clone());
+                my_feeds.push(ExportedEntry {
+                    action_hash: record.action_address().clone(),
+                    created_at: record.action().timestamp(),
+                    entry: feed,
+                });
+                report.feeds += 1;
+            }
+            Err(err) => report
+                .failures
+                .push(format!("Feed \"{}\": {:?}", feed.name, err)),
+        }
+    }
+
+    for feed_share in document.feed_shares {
+        let input = AddShareToFeedInput {
+            feed_hash: restored_hash(&feed_hashes, feed_share.feed_hash),
+            share_item_hash: restored_hash(&share_hashes, feed_share.share_item_hash),
+        };
+        let description = format!(
+            "Share {} in feed {}",
+            input.share_item_hash, input.feed_hash
+        );
+        match add_share_to_feed(input) {
+            Ok(()) => report.feed_shares += 1,
+            Err(err) => report.failures.push(format!("{}: {:?}", description, err)),
+        }
+    }
+
+    let my_comments = my_live_entries::<Comment>(UnitEntryTypes::Comment, &deleted)?;
+    for exported in document.comments {
+        let mut comment = exported.entry;
+        comment.share_item_hash = restored_hash(&share_hashes, comment.share_item_hash);
+        if my_comments.iter().any(|mine| mine.entry == comment) {
+            continue;
+        }
+        let description = format!("Comment on {}", comment.share_item_hash);
+        match create_comment(comment) {
+            Ok(_) => report.comments += 1,
+            Err(err) => report.failures.push(format!("{}: {:?}", description, err)),
+        }
+    }
+
+    let my_reactions = my_live_entries::<Reaction>(UnitEntryTypes::Reaction, &deleted)?;
+    for exported in document.reactions {
+        let mut reaction = exported.entry;
+        reaction.share_item_hash = restored_hash(&share_hashes, reaction.share_item_hash);
+        if my_reactions.iter().any(|mine| mine.entry == reaction) {
+            continue;
+        }
+        let description = format!("Reaction on {}", reaction.share_item_hash);
+        match add_reaction(reaction) {
+            Ok(_) => report.reactions += 1,
+            Err(err) => report.failures.push(format!("{}: {:?}", description, err)),
+        }
+    }
+
+    for agent in document.following {
+        follow_agent(agent)?;
+    }
+
+    let blocklist = get_blocked_agents(())?;
+    for agent in document.blocklist.blocked {
+        if !blocklist.blocked.contains(&agent) {
+            block_agent(agent)?;
+        }
+    }
+    for agent in document.blocklist.muted {
+        if !blocklist.muted.contains(&agent) {
+            mute_agent(agent)?;
+        }
+    }
+
+    let mut prefs = document.notification_prefs;
+    for feed_level in prefs.feed_levels.iter_mut() {
+        feed_level.feed_hash = restored_hash(&feed_hashes, feed_level.feed_hash.clone());
+    }
+    set_notification_prefs(prefs)?;
+
+    let my_saved_searches: Vec<SavedSearch> = get_saved_searches(())?
+        .into_iter()
+        .map(|info| info.saved_search)
+        .collect();
+    for mut saved_search in document.saved_searches {
+        saved_search.feed_hash = saved_search
+            .feed_hash
+            .map(|feed_hash| restored_hash(&feed_hashes, feed_hash));
+        if !my_saved_searches.contains(&saved_search) {
+            create_saved_search(saved_search)?;
+        }
+    }
+
+    Ok(report)
+}
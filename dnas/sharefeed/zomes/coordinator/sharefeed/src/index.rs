@@ -0,0 +1,85 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+use crate::discovery::{index_share_item_tags, index_share_item_url};
+use crate::mention::create_mentions;
+use crate::search::{index_share_item_trigrams, index_share_item_words};
+use crate::share_item::time_path_for_timestamp;
+
+/// Decodes the app entry committed by `action`, if it is one of ours
+pub fn get_entry_for_action(action_hash: &ActionHash) -> ExternResult<Option<EntryTypes>> {
+    let record = match get(action_hash.clone(), GetOptions::local())? {
+        Some(record) => record,
+        None => return Ok(None),
+    };
+    let entry = match record.entry().as_option() {
+        Some(entry) => entry,
+        None => return Ok(None),
+    };
+    let (zome_index, entry_index) = match record.action().entry_type() {
+        Some(EntryType::App(AppEntryDef {
+            zome_index,
+            entry_index,
+            ..
+        })) => (zome_index, entry_index),
+        _ => return Ok(None),
+    };
+    EntryTypes::deserialize_from_type(*zome_index, *entry_index, entry)
+}
+
+/// Creates the index links for a freshly committed action. Called from
+/// `post_commit` so the write externs return as soon as the entry exists.
+pub fn index_committed_action(action: &SignedActionHashed) -> ExternResult<()> {
+    if !matches!(action.hashed.content, Action::Create(_)) {
+        return Ok(());
+    }
+    let action_hash = &action.hashed.hash;
+
+    if let Some(EntryTypes::ShareItem(share_item)) = get_entry_for_action(action_hash)? {
+        index_share_item(action_hash, action.hashed.content.timestamp(), &share_item)?;
+    }
+    Ok(())
+}
+
+fn index_share_item(
+    share_item_hash: &ActionHash,
+    created_at: Timestamp,
+    share_item: &ShareItem,
+) -> ExternResult<()> {
+    let summary = ShareLinkSummary::new(share_item, agent_info()?.agent_initial_pubkey);
+    let path = time_path_for_timestamp(created_at);
+    create_link(
+        path.path_entry_hash()?,
+        share_item_hash.clone(),
+        LinkTypes::TimeIndex,
+        summary.to_link_tag()?,
+    )?;
+
+    create_link(
+        agent_info()?.agent_initial_pubkey,
+        share_item_hash.clone(),
+        LinkTypes::AuthorToShare,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+
+    if share_item.has_url() {
+        create_link(
+            url_path(&share_item.url)?.path_entry_hash()?,
+            share_item_hash.clone(),
+            LinkTypes::UrlToShare,
+            SharefeedLinkTag::new().to_link_tag()?,
+        )?;
+    }
+
+    index_share_item_words(share_item_hash, share_item)?;
+    index_share_item_trigrams(share_item_hash, share_item)?;
+    index_share_item_tags(share_item_hash, created_at, share_item)?;
+    if share_item.has_url() {
+        index_share_item_url(created_at, share_item)?;
+    }
+
+    if let Some(description) = &share_item.description {
+        create_mentions(share_item_hash, description)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+
+// Early checks mirroring integrity validation, so bad input fails with a
+// typed error before anything is committed
+
+pub fn check_share_item(
+    share_item: &ShareItem,
+    properties: &SharefeedProperties,
+) -> Result<(), SharefeedError> {
+    share_item
+        .check_kind()
+        .map_err(SharefeedError::InvalidInput)?;
+    share_item
+        .check_translations()
+        .map_err(SharefeedError::InvalidInput)?;
+    if share_item.title.is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "ShareItem title cannot be empty",
+        )));
+    }
+    properties
+        .check_share_item_limits(share_item)
+        .map_err(SharefeedError::InvalidInput)?;
+    properties
+        .check_share_item_domain(share_item)
+        .map_err(SharefeedError::InvalidInput)?;
+    let size = serialized_size(share_item.clone())
+        .map_err(|err| SharefeedError::InvalidInput(err.to_string()))?;
+    properties
+        .check_entry_size(size)
+        .map_err(SharefeedError::InvalidInput)?;
+    Ok(())
+}
+
+pub fn check_feed(feed: &Feed) -> Result<(), SharefeedError> {
+    if feed.name.is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Feed name cannot be empty",
+        )));
+    }
+    if feed.stewards.is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Feed must have at least one steward",
+        )));
+    }
+    if feed
+        .keyword_filters
+        .iter()
+        .any(|filter| filter.keyword.trim().is_empty())
+    {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Feed keyword filters cannot be empty",
+        )));
+    }
+    feed.check_default_tags()
+        .map_err(SharefeedError::InvalidInput)?;
+    feed.check_steward_quorum()
+        .map_err(SharefeedError::InvalidInput)?;
+    Ok(())
+}
+
+pub fn check_comment(
+    comment: &Comment,
+    properties: &SharefeedProperties,
+) -> Result<(), SharefeedError> {
+    if !properties.features.comments {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Comments are turned off on this network",
+        )));
+    }
+    if comment.content.trim().is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Comment content cannot be empty",
+        )));
+    }
+    Ok(())
+}
+
+pub fn check_reaction(
+    reaction: &Reaction,
+    properties: &SharefeedProperties,
+) -> Result<(), SharefeedError> {
+    if !properties.features.reactions {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Reactions are turned off on this network",
+        )));
+    }
+    if reaction.emoji.is_empty() || reaction.emoji.chars().count() > MAX_REACTION_CHARS {
+        return Err(SharefeedError::InvalidInput(format!(
+            "Reaction must be between 1 and {} characters",
+            MAX_REACTION_CHARS
+        )));
+    }
+    Ok(())
+}
+
+pub fn check_report(report: &Report) -> Result<(), SharefeedError> {
+    if let Some(note) = &report.note {
+        if note.chars().count() > MAX_REPORT_NOTE_CHARS {
+            return Err(SharefeedError::InvalidInput(format!(
+                "Report note cannot exceed {} characters",
+                MAX_REPORT_NOTE_CHARS
+            )));
+        }
+    }
+    if report.reason == ReportReason::Other
+        && report
+            .note
+            .as_deref()
+            .map_or(true, |note| note.trim().is_empty())
+    {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Reports for another reason must explain it in a note",
+        )));
+    }
+    Ok(())
+}
+
+pub fn check_appeal(appeal: &Appeal) -> Result<(), SharefeedError> {
+    if appeal.reason.trim().is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "An appeal must give a reason",
+        )));
+    }
+    Ok(())
+}
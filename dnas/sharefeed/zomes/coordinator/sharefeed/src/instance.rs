@@ -0,0 +1,35 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+/// Version of this coordinator zome, from its crate
+pub const COORDINATOR_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// What a client is talking to: who, which network, and which code
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct InstanceInfo {
+    /// The calling agent
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub agent: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "DnaHash"))]
+    #[schemars(with = "DnaHashSchema")]
+    pub dna_hash: DnaHash,
+    pub integrity_version: String,
+    pub coordinator_version: String,
+    /// The DNA's `properties` block, with defaults filled in
+    pub properties: SharefeedProperties,
+}
+
+#[hdk_extern]
+pub fn get_instance_info(_: ()) -> ExternResult<InstanceInfo> {
+    trace_call!("get_instance_info");
+    Ok(InstanceInfo {
+        agent: agent_info()?.agent_initial_pubkey,
+        dna_hash: dna_info()?.hash,
+        integrity_version: String::from(INTEGRITY_VERSION),
+        coordinator_version: String::from(COORDINATOR_VERSION),
+        properties: sharefeed_properties()?,
+    })
+}
@@ -1,11 +1,198 @@
+#[macro_use]
+mod trace;
 pub mod share_item;
 pub use share_item::*;
 pub mod feed;
 pub use feed::*;
+pub mod signals;
+pub use signals::*;
+pub mod notification;
+pub use notification::*;
+pub mod notification_prefs;
+pub use notification_prefs::*;
+pub mod profiles;
+pub use profiles::*;
+pub mod mention;
+pub use mention::*;
+pub mod digest;
+pub use digest::*;
+pub mod index;
+pub use index::*;
+pub mod comment;
+pub use comment::*;
+pub mod reaction;
+pub use reaction::*;
+pub mod deeplink;
+pub use deeplink::*;
+pub mod follow;
+pub use follow::*;
+pub mod blocklist;
+pub use blocklist::*;
+pub mod network_blocklist;
+pub use network_blocklist::*;
+pub mod reputation;
+pub use reputation::*;
+pub mod summary;
+pub use summary::*;
+pub mod capability;
+pub use capability::*;
+pub mod membership;
+pub use membership::*;
+pub mod delegation;
+pub use delegation::*;
+pub mod fallback;
+pub use fallback::*;
+pub mod batch;
+pub use batch::*;
+pub mod read;
+pub use read::*;
+pub mod error;
+pub use error::*;
+pub mod input;
+pub use input::*;
+pub mod search;
+pub use search::*;
+pub mod query;
+pub use query::*;
+pub mod saved_search;
+pub use saved_search::*;
+pub mod discovery;
+pub use discovery::*;
+pub mod export;
+pub use export::*;
+pub mod import;
+pub use import::*;
+pub mod migration;
+pub use migration::*;
+pub mod weave;
+pub use weave::*;
+pub mod vault;
+pub use vault::*;
+pub mod report;
+pub use report::*;
+pub mod moderation;
+pub use moderation::*;
+pub mod appeal;
+pub use appeal::*;
+pub mod ban;
+pub use ban::*;
+pub mod instance;
+pub use instance::*;
+pub mod health;
+pub use health::*;
+pub mod cleanup;
+pub use cleanup::*;
+pub mod detail;
+pub use detail::*;
+pub mod opens;
+pub use opens::*;
+pub mod seen;
+pub use seen::*;
+pub mod compilation;
+pub use compilation::*;
+pub mod saved_item;
+pub use saved_item::*;
+pub mod curator_note;
+pub use curator_note::*;
+pub mod subscription;
+pub use subscription::*;
+pub mod steward_proposal;
+pub use steward_proposal::*;
+pub mod home_feed;
+pub use home_feed::*;
+pub mod onboarding;
+pub use onboarding::*;
+pub mod api_schema;
+pub use api_schema::*;
 
 use hdk::prelude::*;
+use sharefeed_integrity::*;
+use std::collections::BTreeSet;
+
+/// Functions `init` schedules to run periodically
+pub const SCHEDULED_FUNCTIONS: [&str; 5] = [
+    "scheduled_weekly_digest",
+    "scheduled_reputation_refresh",
+    "scheduled_saved_search_check",
+    "scheduled_link_cleanup",
+    "scheduled_subscription_check",
+];
 
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
+    trace_call!("init");
+    // Allow other agents to deliver remote signals to this cell
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_info()?.name, "recv_remote_signal".into()));
+    create_cap_grant(CapGrantEntry {
+        tag: String::from("remote_signals"),
+        access: CapAccess::Unrestricted,
+        functions: GrantedFunctions::Listed(functions),
+    })?;
+
+    // Let non-members ask this agent's stewarded feeds for membership
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_info()?.name, "receive_membership_request".into()));
+    create_cap_grant(CapGrantEntry {
+        tag: String::from("membership_requests"),
+        access: CapAccess::Unrestricted,
+        functions: GrantedFunctions::Listed(functions),
+    })?;
+
+    // Let stewards hand this agent posting delegations
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_info()?.name, "receive_posting_delegation".into()));
+    create_cap_grant(CapGrantEntry {
+        tag: String::from("posting_delegations"),
+        access: CapAccess::Unrestricted,
+        functions: GrantedFunctions::Listed(functions),
+    })?;
+
+    // Let peers that haven't synced a record yet fetch it from us
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_info()?.name, "get_record_for_peer".into()));
+    create_cap_grant(CapGrantEntry {
+        tag: String::from("record_fallback"),
+        access: CapAccess::Unrestricted,
+        functions: GrantedFunctions::Listed(functions),
+    })?;
+
+    // List this agent in the directory other agents browse
+    create_link(
+        agent_directory_path().path_entry_hash()?,
+        agent_info()?.agent_initial_pubkey,
+        LinkTypes::AgentDirectory,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+
+    // Readers walk the time index from its root, so this week's branch has
+    // to exist before anything is shared into it
+    time_path_for_timestamp(sys_time()?)
+        .typed(LinkTypes::TimePath)?
+        .ensure()?;
+
+    // Start new agents off in the network's home feed, if it has one. Not
+    // finding it yet is no reason to fail init.
+    if let Err(err) = bootstrap_home_feed() {
+        debug!("Failed to set up the home feed: {:?}", err);
+    }
+
+    for scheduled_function in SCHEDULED_FUNCTIONS {
+        schedule(scheduled_function)?;
+    }
+
     Ok(InitCallbackResult::Pass)
 }
+
+#[hdk_extern(infallible)]
+pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
+    trace_call!("post_commit");
+    for action in committed_actions {
+        if let Err(err) = index_committed_action(&action) {
+            debug!("Failed to index committed action: {:?}", err);
+        }
+        if let Err(err) = signal_committed_action(&action) {
+            debug!("Failed to signal committed action: {:?}", err);
+        }
+    }
+}
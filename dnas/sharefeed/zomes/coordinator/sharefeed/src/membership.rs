@@ -0,0 +1,130 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::feed::{get_feed, get_feed_members};
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct RequestMembershipInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct MembershipRequestInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub request_hash: ActionHash,
+    pub membership_request: MembershipRequest,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub received_at: Timestamp,
+}
+
+/// Stewards of a feed, i.e. the FeedToMember targets listed as stewards
+/// on the latest version of the feed
+pub fn get_feed_stewards(feed_hash: &ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    let feed: Feed = get_feed(feed_hash.clone().into())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Feed",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+
+    Ok(get_feed_members(feed_hash.clone().into())?
+        .into_iter()
+        .filter(|member| feed.stewards.contains(member))
+        .collect())
+}
+
+/// Delivers a membership request to every steward of the feed by remote
+/// call. Returns the stewards that stored the request.
+#[hdk_extern]
+pub fn request_membership(input: RequestMembershipInput) -> ExternResult<Vec<AgentPubKey>> {
+    trace_call!("request_membership");
+    let zome_name = zome_info()?.name;
+    let mut delivered_to: Vec<AgentPubKey> = Vec::new();
+    for steward in get_feed_stewards(&input.feed_hash)? {
+        let response = call_remote(
+            steward.clone(),
+            zome_name.clone(),
+            "receive_membership_request".into(),
+            None,
+            &input,
+        )?;
+        match response {
+            ZomeCallResponse::Ok(_) => delivered_to.push(steward),
+            other => debug!("Membership request to {} failed: {:?}", steward, other),
+        }
+    }
+
+    if delivered_to.is_empty() {
+        return Err(SharefeedError::Unreachable(String::from(
+            "No steward of this feed could be reached",
+        ))
+        .into());
+    }
+    Ok(delivered_to)
+}
+
+/// Called remotely by `request_membership`; stores the request as a private
+/// pending entry on the steward's chain
+#[hdk_extern]
+pub fn receive_membership_request(input: RequestMembershipInput) -> ExternResult<ActionHash> {
+    trace_call!("receive_membership_request");
+    let requester = call_info()?.provenance;
+    let me = agent_info()?.agent_initial_pubkey;
+    if !get_feed_stewards(&input.feed_hash)?.contains(&me) {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only stewards accept membership requests",
+        ))
+        .into());
+    }
+
+    create_entry(&EntryTypes::MembershipRequest(MembershipRequest {
+        feed_hash: input.feed_hash,
+        requester,
+        message: input.message,
+    }))
+}
+
+/// Requests received for feeds I steward whose requester isn't a member yet
+#[hdk_extern]
+pub fn get_pending_membership_requests(_: ()) -> ExternResult<Vec<MembershipRequestInfo>> {
+    trace_call!("get_pending_membership_requests");
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::MembershipRequest.try_into()?)
+            .include_entries(true),
+    )?;
+
+    let mut requests: Vec<MembershipRequestInfo> = Vec::new();
+    for record in records {
+        if let Some(membership_request) = record
+            .entry()
+            .to_app_option::<MembershipRequest>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            let members = get_feed_members(membership_request.feed_hash.clone().into())?;
+            if members.contains(&membership_request.requester) {
+                continue;
+            }
+            requests.push(MembershipRequestInfo {
+                request_hash: record.action_address().clone(),
+                membership_request,
+                received_at: record.action().timestamp(),
+            });
+        }
+    }
+
+    Ok(requests)
+}
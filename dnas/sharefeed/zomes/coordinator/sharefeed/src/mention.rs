@@ -0,0 +1,78 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+use crate::profiles::get_agents_for_nickname;
+use crate::read::ReadInput;
+use crate::share_item::{localize_share_infos, share_item_infos_from_links, ShareItemInfo};
+use crate::signals::{send_remote_signal_to_agents, Signal};
+
+/// Extracts the distinct `@name` tokens from a piece of text
+pub fn parse_mentions(text: &str) -> Vec<String> {
+    let mut mentions: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        let Some(name) = word.strip_prefix('@') else {
+            continue;
+        };
+        // Drop trailing punctuation, keeping the base64 alphabet used by pubkeys
+        let name = name.trim_end_matches(|c: char| !c.is_alphanumeric() && c != '_' && c != '-');
+        if !name.is_empty() && !mentions.iter().any(|m| m == name) {
+            mentions.push(name.to_string());
+        }
+    }
+    mentions
+}
+
+/// A mention is either a base64 agent pubkey or a profile nickname
+fn resolve_mention(mention: &str) -> ExternResult<Vec<AgentPubKey>> {
+    if let Ok(agent) = AgentPubKeyB64::from_b64_str(mention) {
+        return Ok(vec![agent.into()]);
+    }
+    // Unknown nicknames or an unavailable profiles zome shouldn't fail the share
+    Ok(get_agents_for_nickname(mention).unwrap_or_default())
+}
+
+/// Links every agent mentioned in `text` to the share and notifies them
+pub fn create_mentions(share_item_hash: &ActionHash, text: &str) -> ExternResult<()> {
+    let mut mentioned: Vec<AgentPubKey> = Vec::new();
+    for mention in parse_mentions(text) {
+        for agent in resolve_mention(&mention)? {
+            if !mentioned.contains(&agent) {
+                mentioned.push(agent);
+            }
+        }
+    }
+
+    for agent in &mentioned {
+        create_link(
+            agent.clone(),
+            share_item_hash.clone(),
+            LinkTypes::AgentToMention,
+            SharefeedLinkTag::new().to_link_tag()?,
+        )?;
+    }
+
+    send_remote_signal_to_agents(
+        &Signal::Mentioned {
+            share_item_hash: share_item_hash.clone(),
+            mentioned_by: agent_info()?.agent_initial_pubkey,
+        },
+        mentioned,
+    )
+}
+
+#[hdk_extern]
+pub fn get_my_mentions(input: ReadInput<()>) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("get_my_mentions");
+    let language = input.language();
+    let (_, strategy) = input.into_parts();
+    let agent_info = agent_info()?;
+    let links = get_links(
+        LinkQuery::try_new(agent_info.agent_initial_pubkey, LinkTypes::AgentToMention)?,
+        strategy.get_strategy(),
+    )?;
+
+    Ok(localize_share_infos(
+        share_item_infos_from_links(links, strategy)?,
+        language,
+    ))
+}
@@ -0,0 +1,102 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::{ActionHashSchema, MigrationTargetSchema};
+
+use crate::error::SharefeedError;
+use crate::export::PersonalDataExport;
+use crate::import::{import_my_data, RestoreReport};
+
+/// How my chain was closed for migration, as read by the cell replacing it
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct ChainClosure {
+    #[schemars(with = "ActionHashSchema")]
+    pub close_hash: ActionHash,
+    #[schemars(with = "Option<MigrationTargetSchema>")]
+    pub new_target: Option<MigrationTarget>,
+}
+
+fn latest_action_of_type(action_type: ActionType) -> ExternResult<Option<Record>> {
+    Ok(query(ChainQueryFilter::new().action_type(action_type))?
+        .into_iter()
+        .last())
+}
+
+fn call_previous_cell<I, O>(old_cell: &CellId, fn_name: &str, payload: I) -> ExternResult<O>
+where
+    I: Serialize + std::fmt::Debug,
+    O: serde::de::DeserializeOwned + std::fmt::Debug,
+{
+    match call(
+        CallTargetCell::OtherCell(old_cell.clone()),
+        zome_info()?.name,
+        fn_name.into(),
+        None,
+        payload,
+    )? {
+        ZomeCallResponse::Ok(result) => result.decode().map_err(|e| wasm_error!(e)),
+        other => Err(SharefeedError::Unreachable(format!(
+            "Call to the previous cell failed: {:?}",
+            other
+        ))
+        .into()),
+    }
+}
+
+/// Closes my chain in favour of `new_dna_hash`. Nothing can be written to
+/// this cell afterwards, but it still answers reads so the new cell can
+/// copy my data across with `init_from_previous_chain`.
+#[hdk_extern]
+pub fn close_chain_for_migration(new_dna_hash: DnaHash) -> ExternResult<ActionHash> {
+    trace_call!("close_chain_for_migration");
+    if latest_action_of_type(ActionType::CloseChain)?.is_some() {
+        return Err(
+            SharefeedError::InvalidInput(String::from("This chain is already closed")).into(),
+        );
+    }
+    close_chain(Some(MigrationTarget::Dna(new_dna_hash)))
+}
+
+#[hdk_extern]
+pub fn get_chain_closure(_: ()) -> ExternResult<Option<ChainClosure>> {
+    trace_call!("get_chain_closure");
+    Ok(
+        latest_action_of_type(ActionType::CloseChain)?.and_then(|record| match record.action() {
+            Action::CloseChain(close_chain) => Some(ChainClosure {
+                close_hash: record.action_address().clone(),
+                new_target: close_chain.new_target.clone(),
+            }),
+            _ => None,
+        }),
+    )
+}
+
+/// Opens this chain as the continuation of `old_cell`, which must have been
+/// closed for migration to this DNA, and restores everything exported from
+/// it. Running it again after an interrupted restore picks up where it left
+/// off.
+#[hdk_extern]
+pub fn init_from_previous_chain(old_cell: CellId) -> ExternResult<RestoreReport> {
+    trace_call!("init_from_previous_chain");
+    let closure: Option<ChainClosure> = call_previous_cell(&old_cell, "get_chain_closure", ())?;
+    let Some(closure) = closure else {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "The previous chain must be closed with close_chain_for_migration first",
+        ))
+        .into());
+    };
+    if closure.new_target != Some(MigrationTarget::Dna(dna_info()?.hash)) {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "The previous chain was closed for migration to a different DNA",
+        ))
+        .into());
+    }
+
+    let document: PersonalDataExport = call_previous_cell(&old_cell, "export_my_data", ())?;
+    if latest_action_of_type(ActionType::OpenChain)?.is_none() {
+        open_chain(
+            MigrationTarget::Dna(old_cell.dna_hash().clone()),
+            closure.close_hash,
+        )?;
+    }
+    import_my_data(document)
+}
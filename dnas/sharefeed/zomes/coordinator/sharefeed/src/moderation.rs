@@ -0,0 +1,353 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::HashSet;
+
+use crate::appeal::{get_feed_appeals, AppealInfo};
+use crate::error::SharefeedError;
+use crate::feed::{feed_share_link_tag, get_feed, link_share_into_feed, unlink_share_from_feed};
+use crate::read::{ReadInput, ReadStrategy};
+use crate::report::{check_is_steward, get_reports_for_feed, ReportInfo};
+use crate::share_item::{share_item_infos_from_links, ShareItemInfo};
+
+const DEFAULT_MODERATION_LOG_PAGE_SIZE: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ModerationActionInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub moderation_action_hash: ActionHash,
+    pub moderation_action: ModerationAction,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub steward: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ModerationLogInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    /// Only return actions logged after this timestamp
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub cursor: Option<Timestamp>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ModerationLogPage {
+    pub actions: Vec<ModerationActionInfo>,
+    /// Pass as `cursor` to fetch the next page; `None` on the last page
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub next_cursor: Option<Timestamp>,
+}
+
+/// Everything in a feed waiting for a steward
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ModerationQueue {
+    /// Reports nobody has resolved yet, oldest first
+    pub open_reports: Vec<ReportInfo>,
+    /// Shares from members waiting for approval
+    pub pending_shares: Vec<ShareItemInfo>,
+    /// Appeals against this feed's moderation nobody has decided yet
+    pub open_appeals: Vec<AppealInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ResolveReportInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub report_hash: ActionHash,
+    pub outcome: ReportOutcome,
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ReviewPendingShareInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    pub approve: bool,
+    pub note: Option<String>,
+}
+
+/// Removes every FeedToShare link from the feed to the share
+pub fn take_share_out_of_feed(
+    feed_hash: &ActionHash,
+    share_item_hash: &ActionHash,
+) -> ExternResult<()> {
+    let links = get_links(
+        LinkQuery::try_new(feed_hash.clone(), LinkTypes::FeedToShare)?,
+        GetStrategy::Network,
+    )?;
+    let share_target = AnyLinkableHash::from(share_item_hash.clone());
+    for link in links.into_iter().filter(|link| link.target == share_target) {
+        unlink_share_from_feed(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
+/// Puts a share a steward took out of the feed back in
+pub fn restore_share_to_feed(
+    feed_hash: &ActionHash,
+    share_item_hash: &ActionHash,
+) -> ExternResult<()> {
+    let share_target = AnyLinkableHash::from(share_item_hash.clone());
+    let in_feed = get_links(
+        LinkQuery::try_new(feed_hash.clone(), LinkTypes::FeedToShare)?,
+        GetStrategy::Network,
+    )?
+    .into_iter()
+    .any(|link| link.target == share_target);
+    if in_feed {
+        return Ok(());
+    }
+    let record = get(share_item_hash.clone(), GetOptions::network())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the ShareItem")),
+    )?;
+    let share_item: ShareItem = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a ShareItem entry",
+        )))?;
+    let feed: Feed = get_feed(ReadInput::WithStrategy {
+        input: feed_hash.clone(),
+        strategy: ReadStrategy::Network,
+    })?
+    .ok_or(SharefeedError::NotFound(String::from(
+        "Could not find the Feed",
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(SharefeedError::InvalidInput(String::from(
+        "Linked action must reference a Feed entry",
+    )))?;
+    let tag = feed_share_link_tag(&feed, &share_item, record.action().author().clone())?;
+    link_share_into_feed(feed_hash.clone(), share_item_hash.clone(), tag)
+}
+
+/// Records a steward's decision in the feed's moderation log
+pub fn create_moderation_action(moderation_action: ModerationAction) -> ExternResult<Record> {
+    let moderation_action_hash =
+        create_entry(&EntryTypes::ModerationAction(moderation_action.clone()))?;
+    create_link(
+        moderation_action.feed_hash,
+        moderation_action_hash.clone(),
+        LinkTypes::FeedToModerationActions,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    let record =
+        get(moderation_action_hash, GetOptions::local())?.ok_or(SharefeedError::NotFound(
+            String::from("Could not find the newly created ModerationAction"),
+        ))?;
+    Ok(record)
+}
+
+fn get_moderation_action_info(
+    link: &Link,
+    strategy: ReadStrategy,
+) -> ExternResult<Option<ModerationActionInfo>> {
+    let moderation_action_hash =
+        ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
+    let Some(record) = get(moderation_action_hash.clone(), strategy.get_options())? else {
+        return Ok(None);
+    };
+    let moderation_action = record
+        .entry()
+        .to_app_option::<ModerationAction>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(
+        moderation_action.map(|moderation_action| ModerationActionInfo {
+            moderation_action_hash,
+            moderation_action,
+            steward: record.action().author().clone(),
+            created_at: record.action().timestamp(),
+        }),
+    )
+}
+
+/// Every moderation action taken in a feed, oldest first
+pub fn get_moderation_actions(
+    feed_hash: ActionHash,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<ModerationActionInfo>> {
+    let links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToModerationActions)?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut actions: Vec<ModerationActionInfo> = Vec::new();
+    for link in links {
+        if let Some(info) = get_moderation_action_info(&link, strategy)? {
+            actions.push(info);
+        }
+    }
+
+    actions.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(actions)
+}
+
+/// A feed's moderation log in the order it was written, one page at a time.
+/// Anyone can read it, so members can see how their feed is moderated.
+#[hdk_extern]
+pub fn get_moderation_log(input: ReadInput<ModerationLogInput>) -> ExternResult<ModerationLogPage> {
+    trace_call!("get_moderation_log");
+    let (input, strategy) = input.into_parts();
+    let limit = input.limit.unwrap_or(DEFAULT_MODERATION_LOG_PAGE_SIZE);
+
+    let mut query = LinkQuery::try_new(input.feed_hash, LinkTypes::FeedToModerationActions)?;
+    if let Some(cursor) = input.cursor {
+        query = query.after(cursor);
+    }
+    let mut links = get_links(query, strategy.get_strategy())?;
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    let mut actions: Vec<ModerationActionInfo> = Vec::new();
+    // The cursor follows the links, which are written just after their entry
+    let mut last_link_at: Option<Timestamp> = None;
+    let mut has_more = false;
+    for link in links {
+        if actions.len() == limit {
+            has_more = true;
+            break;
+        }
+        if let Some(info) = get_moderation_action_info(&link, strategy)? {
+            actions.push(info);
+        }
+        last_link_at = Some(link.timestamp);
+    }
+
+    Ok(ModerationLogPage {
+        actions,
+        next_cursor: if has_more { last_link_at } else { None },
+    })
+}
+
+/// Open reports and shares awaiting approval in a feed, for its stewards
+#[hdk_extern]
+pub fn get_moderation_queue(feed_hash: ReadInput<ActionHash>) -> ExternResult<ModerationQueue> {
+    trace_call!("get_moderation_queue");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    check_is_steward(&feed_hash)?;
+
+    let actions = get_moderation_actions(feed_hash.clone(), strategy)?;
+    let resolved: HashSet<&ActionHash> = actions
+        .iter()
+        .filter_map(|info| match &info.moderation_action.kind {
+            ModerationKind::ResolveReport { report_hash, .. } => Some(report_hash),
+            _ => None,
+        })
+        .collect();
+    let open_reports = get_reports_for_feed(ReadInput::WithStrategy {
+        input: feed_hash.clone(),
+        strategy,
+    })?
+    .into_iter()
+    .filter(|info| !resolved.contains(&info.report_hash))
+    .collect();
+
+    let pending_links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToPendingShare)?,
+        strategy.get_strategy(),
+    )?;
+    let pending_shares = share_item_infos_from_links(pending_links, strategy)?;
+
+    let open_appeals = get_feed_appeals(&actions, strategy)?
+        .into_iter()
+        .filter(|info| info.decision.is_none())
+        .collect();
+
+    Ok(ModerationQueue {
+        open_reports,
+        pending_shares,
+        open_appeals,
+    })
+}
+
+/// Closes a report, taking the share out of the feed if it's upheld
+#[hdk_extern]
+pub fn resolve_report(input: ResolveReportInput) -> ExternResult<Record> {
+    trace_call!("resolve_report");
+    let report: Report = get(input.report_hash.clone(), GetOptions::network())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Report",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Expected a Report entry",
+        )))?;
+    check_is_steward(&report.feed_hash)?;
+
+    if input.outcome == ReportOutcome::ShareRemoved {
+        take_share_out_of_feed(&report.feed_hash, &report.share_item_hash)?;
+    }
+
+    create_moderation_action(ModerationAction {
+        feed_hash: report.feed_hash,
+        kind: ModerationKind::ResolveReport {
+            report_hash: input.report_hash,
+            outcome: input.outcome,
+        },
+        note: input.note,
+    })
+}
+
+/// Approves a share waiting in the feed's queue, adding it to the feed,
+/// or rejects it
+#[hdk_extern]
+pub fn review_pending_share(input: ReviewPendingShareInput) -> ExternResult<Record> {
+    trace_call!("review_pending_share");
+    check_is_steward(&input.feed_hash)?;
+    let share_target = AnyLinkableHash::from(input.share_item_hash.clone());
+    let pending: Vec<Link> = get_links(
+        LinkQuery::try_new(input.feed_hash.clone(), LinkTypes::FeedToPendingShare)?,
+        GetStrategy::Network,
+    )?
+    .into_iter()
+    .filter(|link| link.target == share_target)
+    .collect();
+    let Some(tag) = pending.first().map(|link| link.tag.clone()) else {
+        return Err(SharefeedError::NotFound(String::from(
+            "This share isn't waiting for approval in this feed",
+        ))
+        .into());
+    };
+    for link in pending {
+        delete_link(link.create_link_hash, GetOptions::network())?;
+    }
+
+    let kind = if input.approve {
+        link_share_into_feed(input.feed_hash.clone(), input.share_item_hash.clone(), tag)?;
+        ModerationKind::ApproveShare {
+            share_item_hash: input.share_item_hash,
+        }
+    } else {
+        ModerationKind::RejectShare {
+            share_item_hash: input.share_item_hash,
+        }
+    };
+    create_moderation_action(ModerationAction {
+        feed_hash: input.feed_hash,
+        kind,
+        note: input.note,
+    })
+}
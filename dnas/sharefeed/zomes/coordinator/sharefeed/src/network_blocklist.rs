@@ -0,0 +1,95 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+use crate::batch::get_chain_heads;
+use crate::error::SharefeedError;
+use crate::read::ReadInput;
+
+/// Anchor every published network blocklist is linked from
+pub fn network_blocklist_path() -> Path {
+    Path::from("network_blocklist")
+}
+
+#[hdk_extern]
+pub fn publish_network_blocklist(network_blocklist: NetworkBlocklist) -> ExternResult<Record> {
+    trace_call!("publish_network_blocklist");
+    let network_blocklist_hash = create_entry(&EntryTypes::NetworkBlocklist(network_blocklist))?;
+    create_link(
+        network_blocklist_path().path_entry_hash()?,
+        network_blocklist_hash.clone(),
+        LinkTypes::NetworkBlocklists,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+
+    let record = get(network_blocklist_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from(
+            "Could not find the newly created NetworkBlocklist",
+        )),
+    )?;
+    Ok(record)
+}
+
+/// The latest list published by a moderator, or an empty one
+#[hdk_extern]
+pub fn get_network_blocklist(input: ReadInput<()>) -> ExternResult<NetworkBlocklist> {
+    trace_call!("get_network_blocklist");
+    let (_, strategy) = input.into_parts();
+    let properties = sharefeed_properties()?;
+    let links = get_links(
+        LinkQuery::try_new(
+            network_blocklist_path().path_entry_hash()?,
+            LinkTypes::NetworkBlocklists,
+        )?,
+        strategy.get_strategy(),
+    )?;
+    let latest_link = links
+        .into_iter()
+        .filter(|link| properties.is_moderator(&link.author))
+        .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+
+    let Some(link) = latest_link else {
+        return Ok(NetworkBlocklist::default());
+    };
+    let action_hash = ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+    let network_blocklist = match get(action_hash, strategy.get_options())? {
+        Some(record) => record
+            .entry()
+            .to_app_option::<NetworkBlocklist>()
+            .map_err(|e| wasm_error!(e))?
+            .unwrap_or_default(),
+        None => NetworkBlocklist::default(),
+    };
+    Ok(network_blocklist)
+}
+
+/// Heads of the moderators' chains, which validation reads the network
+/// blocklist up to
+pub fn get_moderator_chain_heads() -> ExternResult<Vec<ActionHash>> {
+    let moderators: Vec<AgentPubKey> = sharefeed_properties()?
+        .moderators
+        .into_iter()
+        .map(AgentPubKey::from)
+        .collect();
+    get_chain_heads(&moderators)
+}
+
+/// Rejects writes from banned agents or pointing at banned domains
+pub fn check_network_blocklist(author: &AgentPubKey, url: Option<&str>) -> ExternResult<()> {
+    let network_blocklist = get_network_blocklist(().into())?;
+    if network_blocklist.bans_agent(author) {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "This agent has been banned from the network",
+        ))
+        .into());
+    }
+    if let Some(domain) = url.and_then(url_domain) {
+        if network_blocklist.bans_domain(&domain) {
+            return Err(SharefeedError::NotAuthorized(format!(
+                "Links to {} are not allowed on this network",
+                domain
+            ))
+            .into());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,296 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::deeplink::{feed_deeplink, share_deeplink, DEEPLINK_SCHEME};
+use crate::error::SharefeedError;
+use crate::feed::get_feed;
+use crate::profiles::get_agent_profile;
+use crate::share_item::get_share_item;
+use crate::signals::Signal;
+
+const DEFAULT_NOTIFICATION_PAGE_SIZE: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct NotificationInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub notification_hash: ActionHash,
+    pub notification: Notification,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+/// Compact, renderable form of a notification for OS-level push relays
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct PushPayload {
+    pub title: String,
+    pub body: String,
+    pub deeplink: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct GetNotificationsInput {
+    /// Only return notifications created before this timestamp
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub cursor: Option<Timestamp>,
+    pub limit: Option<usize>,
+}
+
+/// Builds the notification to persist for a signal received from `sender`
+pub fn notification_for_signal(signal: &Signal, sender: AgentPubKey) -> Option<Notification> {
+    match signal {
+        Signal::NewShareInFeed {
+            feed_hash,
+            share_item_hash,
+            ..
+        } => Some(Notification {
+            kind: NotificationKind::NewShareInFeed,
+            from: sender,
+            feed_hash: Some(feed_hash.clone()),
+            share_item_hash: Some(share_item_hash.clone()),
+            read: false,
+        }),
+        Signal::AddedToFeed { feed_hash, .. } => Some(Notification {
+            kind: NotificationKind::AddedToFeed,
+            from: sender,
+            feed_hash: Some(feed_hash.clone()),
+            share_item_hash: None,
+            read: false,
+        }),
+        Signal::MemberJoined {
+            feed_hash, member, ..
+        } => Some(Notification {
+            kind: NotificationKind::MemberJoined,
+            from: member.clone(),
+            feed_hash: Some(feed_hash.clone()),
+            share_item_hash: None,
+            read: false,
+        }),
+        Signal::Mentioned {
+            share_item_hash, ..
+        } => Some(Notification {
+            kind: NotificationKind::Mention,
+            from: sender,
+            feed_hash: None,
+            share_item_hash: Some(share_item_hash.clone()),
+            read: false,
+        }),
+        Signal::DigestReady { .. } => None,
+        Signal::CommentAdded {
+            share_item_hash, ..
+        } => Some(Notification {
+            kind: NotificationKind::Comment,
+            from: sender,
+            feed_hash: None,
+            share_item_hash: Some(share_item_hash.clone()),
+            read: false,
+        }),
+        Signal::ReactionAdded {
+            share_item_hash, ..
+        } => Some(Notification {
+            kind: NotificationKind::Reaction,
+            from: sender,
+            feed_hash: None,
+            share_item_hash: Some(share_item_hash.clone()),
+            read: false,
+        }),
+    }
+}
+
+pub fn create_notification(notification: Notification) -> ExternResult<ActionHash> {
+    create_entry(&EntryTypes::Notification(notification))
+}
+
+/// Reads all notifications from our own source chain, newest first.
+/// A notification is only ever updated to mark it read, so any update
+/// pointing at it means it has been read.
+pub fn query_notifications() -> ExternResult<Vec<NotificationInfo>> {
+    let filter = ChainQueryFilter::new()
+        .entry_type(UnitEntryTypes::Notification.try_into()?)
+        .include_entries(true);
+    let records = query(filter)?;
+
+    let read_hashes: Vec<ActionHash> = records
+        .iter()
+        .filter_map(|record| match record.action() {
+            Action::Update(update) => Some(update.original_action_address.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let mut notifications: Vec<NotificationInfo> = Vec::new();
+    for record in records {
+        if !matches!(record.action(), Action::Create(_)) {
+            continue;
+        }
+        if let Some(mut notification) = record
+            .entry()
+            .to_app_option::<Notification>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            let notification_hash = record.action_address().clone();
+            if read_hashes.contains(&notification_hash) {
+                notification.read = true;
+            }
+            notifications.push(NotificationInfo {
+                notification_hash,
+                notification,
+                created_at: record.action().timestamp(),
+            });
+        }
+    }
+
+    notifications.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(notifications)
+}
+
+#[hdk_extern]
+pub fn get_notifications(input: GetNotificationsInput) -> ExternResult<Vec<NotificationInfo>> {
+    trace_call!("get_notifications");
+    let limit = input.limit.unwrap_or(DEFAULT_NOTIFICATION_PAGE_SIZE);
+    let notifications = query_notifications()?
+        .into_iter()
+        .filter(|info| match input.cursor {
+            Some(cursor) => info.created_at < cursor,
+            None => true,
+        })
+        .take(limit)
+        .collect();
+    Ok(notifications)
+}
+
+#[hdk_extern]
+pub fn mark_read(notification_hashes: Vec<ActionHash>) -> ExternResult<()> {
+    trace_call!("mark_read");
+    for info in query_notifications()? {
+        if info.notification.read || !notification_hashes.contains(&info.notification_hash) {
+            continue;
+        }
+        let mut notification = info.notification;
+        notification.read = true;
+        update_entry(info.notification_hash, &notification)?;
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_unread_notification_count(_: ()) -> ExternResult<usize> {
+    trace_call!("get_unread_notification_count");
+    let unread = query_notifications()?
+        .into_iter()
+        .filter(|info| !info.notification.read)
+        .count();
+    Ok(unread)
+}
+
+/// Nickname from the profiles zome, falling back to a shortened pubkey
+fn agent_display_name(agent: &AgentPubKey) -> String {
+    match get_agent_profile(agent.clone()) {
+        Ok(Some(profile)) => profile.nickname,
+        _ => AgentPubKeyB64::from(agent.clone())
+            .to_string()
+            .chars()
+            .take(12)
+            .collect(),
+    }
+}
+
+fn share_title(share_item_hash: &ActionHash) -> ExternResult<Option<String>> {
+    let Some(record) = get_share_item(share_item_hash.clone().into())? else {
+        return Ok(None);
+    };
+    let share_item = record
+        .entry()
+        .to_app_option::<ShareItem>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(share_item.map(|share_item| share_item.title))
+}
+
+fn feed_name(feed_hash: &ActionHash) -> ExternResult<Option<String>> {
+    let Some(record) = get_feed(feed_hash.clone().into())? else {
+        return Ok(None);
+    };
+    let feed = record
+        .entry()
+        .to_app_option::<Feed>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(feed.map(|feed| feed.name))
+}
+
+#[hdk_extern]
+pub fn get_push_payload_for_notification(
+    notification_hash: ActionHash,
+) -> ExternResult<PushPayload> {
+    trace_call!("get_push_payload_for_notification");
+    let notification = query_notifications()?
+        .into_iter()
+        .find(|info| info.notification_hash == notification_hash)
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Notification",
+        )))?
+        .notification;
+
+    let sender = agent_display_name(&notification.from);
+    let share_title = match &notification.share_item_hash {
+        Some(share_item_hash) => share_title(share_item_hash)?,
+        None => None,
+    }
+    .unwrap_or_else(|| String::from("a share"));
+    let feed_name = match &notification.feed_hash {
+        Some(feed_hash) => feed_name(feed_hash)?,
+        None => None,
+    }
+    .unwrap_or_else(|| String::from("a feed"));
+
+    let (title, body) = match notification.kind {
+        NotificationKind::NewShareInFeed => (
+            format!("New in {}", feed_name),
+            format!("{} shared {}", sender, share_title),
+        ),
+        NotificationKind::AddedToFeed => (
+            String::from("You were added to a feed"),
+            format!("{} added you to {}", sender, feed_name),
+        ),
+        NotificationKind::Mention => (format!("{} mentioned you", sender), share_title),
+        NotificationKind::Comment => (
+            format!("{} commented", sender),
+            format!("On {}", share_title),
+        ),
+        NotificationKind::Reaction => {
+            (format!("{} reacted", sender), format!("To {}", share_title))
+        }
+        NotificationKind::SavedSearchMatch => {
+            (String::from("New match for a saved search"), share_title)
+        }
+        NotificationKind::TagSubscriptionMatch => (
+            format!("New in {}", feed_name),
+            format!("{} shared {} under a tag you follow", sender, share_title),
+        ),
+        NotificationKind::DomainSubscriptionMatch => (
+            format!("New in {}", feed_name),
+            format!("{} shared {} from a site you follow", sender, share_title),
+        ),
+        NotificationKind::MemberJoined => (
+            format!("New member in {}", feed_name),
+            format!("{} joined {}", sender, feed_name),
+        ),
+    };
+
+    let deeplink = match (&notification.share_item_hash, &notification.feed_hash) {
+        (Some(share_item_hash), _) => share_deeplink(share_item_hash),
+        (None, Some(feed_hash)) => feed_deeplink(feed_hash),
+        (None, None) => String::from(DEEPLINK_SCHEME),
+    };
+
+    Ok(PushPayload {
+        title,
+        body,
+        deeplink,
+    })
+}
@@ -0,0 +1,62 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+use crate::signals::Signal;
+
+/// Returns the latest NotificationPrefs record on our source chain, if any
+fn latest_notification_prefs_record() -> ExternResult<Option<Record>> {
+    let filter = ChainQueryFilter::new()
+        .entry_type(UnitEntryTypes::NotificationPrefs.try_into()?)
+        .include_entries(true);
+    Ok(query(filter)?.into_iter().last())
+}
+
+#[hdk_extern]
+pub fn get_notification_prefs(_: ()) -> ExternResult<NotificationPrefs> {
+    trace_call!("get_notification_prefs");
+    let prefs = match latest_notification_prefs_record()? {
+        Some(record) => record
+            .entry()
+            .to_app_option::<NotificationPrefs>()
+            .map_err(|e| wasm_error!(e))?
+            .unwrap_or_default(),
+        None => NotificationPrefs::default(),
+    };
+    Ok(prefs)
+}
+
+#[hdk_extern]
+pub fn set_notification_prefs(prefs: NotificationPrefs) -> ExternResult<ActionHash> {
+    trace_call!("set_notification_prefs");
+    match latest_notification_prefs_record()? {
+        Some(record) => update_entry(record.action_address().clone(), &prefs),
+        None => create_entry(&EntryTypes::NotificationPrefs(prefs)),
+    }
+}
+
+/// Whether an incoming signal should be turned into a notification at all
+pub fn should_notify(prefs: &NotificationPrefs, signal: &Signal) -> bool {
+    match signal {
+        Signal::NewShareInFeed { feed_hash, .. } => {
+            prefs.level_for_feed(Some(feed_hash)) == NotificationLevel::All
+        }
+        Signal::AddedToFeed { feed_hash, .. } => {
+            prefs.level_for_feed(Some(feed_hash)) != NotificationLevel::None
+        }
+        Signal::MemberJoined { feed_hash, .. } => {
+            prefs.level_for_feed(Some(feed_hash)) == NotificationLevel::All
+        }
+        Signal::Mentioned { .. } => prefs.level_for_feed(None) != NotificationLevel::None,
+        Signal::DigestReady { .. } => true,
+        Signal::CommentAdded { .. } | Signal::ReactionAdded { .. } => {
+            prefs.level_for_feed(None) == NotificationLevel::All
+        }
+    }
+}
+
+/// Whether live signals to the UI should be held back right now
+pub fn is_quiet_time(prefs: &NotificationPrefs) -> ExternResult<bool> {
+    let seconds = sys_time()?.as_seconds_and_nanos().0;
+    let hour = ((seconds / 3600) % 24) as u8;
+    Ok(prefs.is_quiet_hour(hour))
+}
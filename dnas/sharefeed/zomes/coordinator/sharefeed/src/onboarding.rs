@@ -0,0 +1,40 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+
+/// Returns the latest OnboardingState record on our source chain, if any
+fn latest_onboarding_state_record() -> ExternResult<Option<Record>> {
+    let filter = ChainQueryFilter::new()
+        .entry_type(UnitEntryTypes::OnboardingState.try_into()?)
+        .include_entries(true);
+    Ok(query(filter)?.into_iter().last())
+}
+
+/// My onboarding progress, empty if I haven't started
+#[hdk_extern]
+pub fn get_onboarding_state(_: ()) -> ExternResult<OnboardingState> {
+    trace_call!("get_onboarding_state");
+    let onboarding_state = match latest_onboarding_state_record()? {
+        Some(record) => record
+            .entry()
+            .to_app_option::<OnboardingState>()
+            .map_err(|e| wasm_error!(e))?
+            .unwrap_or_default(),
+        None => OnboardingState::default(),
+    };
+    Ok(onboarding_state)
+}
+
+/// Replaces my onboarding progress
+#[hdk_extern]
+pub fn set_onboarding_state(onboarding_state: OnboardingState) -> ExternResult<ActionHash> {
+    trace_call!("set_onboarding_state");
+    onboarding_state
+        .check_ids()
+        .map_err(SharefeedError::InvalidInput)?;
+    match latest_onboarding_state_record()? {
+        Some(record) => update_entry(record.action_address().clone(), &onboarding_state),
+        None => create_entry(&EntryTypes::OnboardingState(onboarding_state)),
+    }
+}
@@ -0,0 +1,71 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::read::ReadInput;
+
+/// Fails early when recording another open would go over
+/// `MAX_OPENS_PER_HOUR`, which validation would reject
+fn check_open_rate() -> ExternResult<()> {
+    let share_item_to_opens = ScopedLinkType::try_from(LinkTypes::ShareItemToOpens)?;
+    let window_start =
+        Timestamp::from_micros(sys_time()?.as_micros() - OPEN_RATE_WINDOW_SECS * 1_000_000);
+    let recent_opens = query(ChainQueryFilter::new().action_type(ActionType::CreateLink))?
+        .into_iter()
+        .filter(|record| match record.action() {
+            Action::CreateLink(create_link) => {
+                create_link.zome_index == share_item_to_opens.zome_index
+                    && create_link.link_type == share_item_to_opens.zome_type
+                    && create_link.timestamp >= window_start
+            }
+            _ => false,
+        })
+        .count();
+    if recent_opens >= MAX_OPENS_PER_HOUR {
+        return Err(SharefeedError::RateLimited(format!(
+            "Agents may record at most {} opens per hour",
+            MAX_OPENS_PER_HOUR
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Counts me as a reader of a share. Opening it again doesn't count twice.
+#[hdk_extern]
+pub fn record_open(share_item_hash: ActionHash) -> ExternResult<()> {
+    trace_call!("record_open");
+    let me = agent_info()?.agent_initial_pubkey;
+    let query = LinkQuery::try_new(share_item_hash.clone(), LinkTypes::ShareItemToOpens)?
+        .author(me.clone());
+    if !get_links(query, GetStrategy::Local)?.is_empty() {
+        return Ok(());
+    }
+    check_open_rate()?;
+    create_link(
+        share_item_hash,
+        me,
+        LinkTypes::ShareItemToOpens,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    Ok(())
+}
+
+/// Number of distinct agents who opened a share
+#[hdk_extern]
+pub fn get_open_count(share_item_hash: ReadInput<ActionHash>) -> ExternResult<usize> {
+    trace_call!("get_open_count");
+    let (share_item_hash, strategy) = share_item_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(share_item_hash, LinkTypes::ShareItemToOpens)?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut readers: Vec<AgentPubKey> = Vec::new();
+    for link in links {
+        if !readers.contains(&link.author) {
+            readers.push(link.author);
+        }
+    }
+    Ok(readers.len())
+}
@@ -0,0 +1,122 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::SharefeedError;
+use crate::read::ReadInput;
+
+/// Name of the bundled holochain-open-dev profiles coordinator zome
+const PROFILES_ZOME: &str = "profiles";
+
+/// The subset of the profiles zome's `Profile` entry that we read
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Profile {
+    pub nickname: String,
+    pub fields: BTreeMap<String, String>,
+}
+
+fn call_profiles_zome<I, O>(fn_name: &str, payload: I) -> ExternResult<O>
+where
+    I: Serialize + std::fmt::Debug,
+    O: serde::de::DeserializeOwned + std::fmt::Debug,
+{
+    match call(
+        CallTargetCell::Local,
+        PROFILES_ZOME,
+        fn_name.into(),
+        None,
+        payload,
+    )? {
+        ZomeCallResponse::Ok(result) => result.decode().map_err(|e| wasm_error!(e)),
+        other => Err(SharefeedError::Unreachable(format!(
+            "Call to profiles zome failed: {:?}",
+            other
+        ))
+        .into()),
+    }
+}
+
+pub fn get_agent_profile(agent: AgentPubKey) -> ExternResult<Option<Profile>> {
+    let record: Option<Record> = call_profiles_zome("get_agent_profile", agent)?;
+    match record {
+        Some(record) => record
+            .entry()
+            .to_app_option::<Profile>()
+            .map_err(|e| wasm_error!(e)),
+        None => Ok(None),
+    }
+}
+
+/// Finds the agents whose nickname exactly matches (case-insensitively)
+pub fn get_agents_for_nickname(nickname: &str) -> ExternResult<Vec<AgentPubKey>> {
+    let candidates: Vec<AgentPubKey> = call_profiles_zome("search_agents", nickname.to_string())?;
+
+    let mut agents: Vec<AgentPubKey> = Vec::new();
+    for agent in candidates {
+        if let Some(profile) = get_agent_profile(agent.clone())? {
+            if profile.nickname.eq_ignore_ascii_case(nickname) {
+                agents.push(agent);
+            }
+        }
+    }
+    Ok(agents)
+}
+
+/// Author details embedded in read responses so clients don't need a
+/// profile lookup per item
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct AuthorProfile {
+    pub nickname: String,
+    pub avatar: Option<String>,
+}
+
+/// Memoizes profile lookups for the duration of a single zome call.
+/// Lookup failures (e.g. no profile yet) are cached as `None`.
+#[derive(Default)]
+pub struct AuthorProfileCache {
+    profiles: HashMap<AgentPubKey, Option<AuthorProfile>>,
+}
+
+impl AuthorProfileCache {
+    pub fn get(&mut self, agent: &AgentPubKey) -> Option<AuthorProfile> {
+        self.profiles
+            .entry(agent.clone())
+            .or_insert_with(|| {
+                get_agent_profile(agent.clone())
+                    .ok()
+                    .flatten()
+                    .map(|profile| AuthorProfile {
+                        avatar: profile.fields.get("avatar").cloned(),
+                        nickname: profile.nickname,
+                    })
+            })
+            .clone()
+    }
+}
+
+/// Every agent that has used ShareFeed, as listed by their own init
+#[hdk_extern]
+pub fn get_agent_directory(input: ReadInput<()>) -> ExternResult<Vec<AgentPubKey>> {
+    trace_call!("get_agent_directory");
+    let (_, strategy) = input.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(
+            agent_directory_path().path_entry_hash()?,
+            LinkTypes::AgentDirectory,
+        )?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut agents: Vec<AgentPubKey> = Vec::new();
+    for link in links {
+        if let Ok(agent) = AgentPubKey::try_from(link.target) {
+            if !agents.contains(&agent) {
+                agents.push(agent);
+            }
+        }
+    }
+    Ok(agents)
+}
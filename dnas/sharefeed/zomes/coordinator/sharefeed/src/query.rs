@@ -0,0 +1,175 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::read::{ReadInput, ReadStrategy};
+use crate::share_item::{
+    dedup_links_by_target, localize_share_infos, share_item_infos_from_links,
+    time_path_for_timestamp, ShareItemInfo,
+};
+
+const DEFAULT_QUERY_LIMIT: usize = 50;
+/// Longest date range served from the time index alone
+const MAX_QUERY_DAYS: i64 = 26 * 7;
+/// Period searched when a query names no author, feed or date range
+const DEFAULT_QUERY_DAYS: i64 = 14;
+const DAY_MICROS: i64 = 24 * 60 * 60 * 1_000_000;
+
+/// Filters for `query_shares`; every filter that is set must match
+#[derive(Serialize, Deserialize, Debug, Clone, Default, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ShareFilter {
+    /// Shares carrying all of these tags, compared case-insensitively
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey | null"))]
+    #[schemars(with = "Option<AgentPubKeySchema>")]
+    pub author: Option<AgentPubKey>,
+    pub domain: Option<String>,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash | null"))]
+    #[schemars(with = "Option<ActionHashSchema>")]
+    pub feed_hash: Option<ActionHash>,
+    pub content_type: Option<String>,
+    /// Shared at or after this time (added to the feed, with `feed_hash`)
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub shared_after: Option<Timestamp>,
+    /// Shared before this time (added to the feed, with `feed_hash`)
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub shared_before: Option<Timestamp>,
+    pub limit: Option<usize>,
+}
+
+impl ShareFilter {
+    fn matches_time(&self, timestamp: Timestamp) -> bool {
+        self.shared_after.is_none_or(|after| timestamp >= after)
+            && self.shared_before.is_none_or(|before| timestamp < before)
+    }
+
+    /// Checks what a link's summary tag can tell without fetching the share.
+    /// Links without a summary pass and are checked once fetched.
+    fn matches_summary(&self, link: &Link) -> bool {
+        let Some(summary) = ShareLinkSummary::from_link_tag(&link.tag) else {
+            return true;
+        };
+        self.author
+            .as_ref()
+            .is_none_or(|author| &summary.author == author)
+            && self
+                .domain
+                .as_ref()
+                .is_none_or(|domain| summary.domain.as_deref() == Some(domain.as_str()))
+            && self
+                .content_type
+                .as_ref()
+                .is_none_or(|content_type| &summary.content_type == content_type)
+    }
+
+    fn matches_share(&self, share: &ShareItemInfo) -> bool {
+        let url = &share.share_item.url;
+        self.author
+            .as_ref()
+            .is_none_or(|author| &share.author == author)
+            && self
+                .domain
+                .as_ref()
+                .is_none_or(|domain| url_domain(url).as_deref() == Some(domain.as_str()))
+            && self.content_type.as_ref().is_none_or(|content_type| {
+                &content_type_for_share(&share.share_item) == content_type
+            })
+            && self.tags.iter().all(|tag| {
+                share
+                    .share_item
+                    .tags
+                    .iter()
+                    .any(|share_tag| share_tag.eq_ignore_ascii_case(tag))
+            })
+    }
+}
+
+/// Links from the narrowest index the filter allows: the feed, then the
+/// author, then the time index over the date range
+fn candidate_links(filter: &ShareFilter, strategy: ReadStrategy) -> ExternResult<Vec<Link>> {
+    if let Some(feed_hash) = &filter.feed_hash {
+        return get_links(
+            LinkQuery::try_new(feed_hash.clone(), LinkTypes::FeedToShare)?,
+            strategy.get_strategy(),
+        );
+    }
+    if let Some(author) = &filter.author {
+        let mut query = LinkQuery::try_new(author.clone(), LinkTypes::AuthorToShare)?;
+        if let Some(after) = filter.shared_after {
+            query = query.after(after);
+        }
+        return get_links(query, strategy.get_strategy());
+    }
+
+    let now = sys_time()?;
+    let end = filter.shared_before.unwrap_or(now);
+    let start = match filter.shared_after {
+        Some(start) => start,
+        None => Timestamp::from_micros(end.as_micros() - DEFAULT_QUERY_DAYS * DAY_MICROS),
+    };
+    if end.as_micros() - start.as_micros() > MAX_QUERY_DAYS * DAY_MICROS {
+        return Err(SharefeedError::InvalidInput(format!(
+            "Date ranges over {} days need an author or feed filter",
+            MAX_QUERY_DAYS
+        ))
+        .into());
+    }
+
+    // Every week bucket touched by the range, found a day at a time since
+    // buckets don't line up with calendar weeks
+    let mut paths: Vec<Path> = Vec::new();
+    let mut day = start.as_micros();
+    while day < end.as_micros() + DAY_MICROS {
+        let path = time_path_for_timestamp(Timestamp::from_micros(day.min(end.as_micros())));
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+        day += DAY_MICROS;
+    }
+
+    let mut links: Vec<Link> = Vec::new();
+    for path in paths {
+        links.extend(get_links(
+            LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::TimeIndex)?,
+            strategy.get_strategy(),
+        )?);
+    }
+    Ok(links)
+}
+
+/// Shares matching every filter that is set, newest first. The query is
+/// served from the narrowest index available, and link summaries weed out
+/// non-matches before any share is fetched.
+#[hdk_extern]
+pub fn query_shares(filter: ReadInput<ShareFilter>) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("query_shares");
+    let language = filter.language();
+    let (filter, strategy) = filter.into_parts();
+    let limit = filter.limit.unwrap_or(DEFAULT_QUERY_LIMIT);
+
+    let mut links: Vec<Link> = dedup_links_by_target(candidate_links(&filter, strategy)?)
+        .into_iter()
+        .filter(|link| filter.matches_time(link.timestamp) && filter.matches_summary(link))
+        .collect();
+    links.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    // Fetch in batches so a selective filter doesn't pull every candidate
+    let mut shares: Vec<ShareItemInfo> = Vec::new();
+    let mut remaining = links.into_iter().peekable();
+    while shares.len() < limit && remaining.peek().is_some() {
+        let batch: Vec<Link> = remaining.by_ref().take(limit).collect();
+        shares.extend(
+            share_item_infos_from_links(batch, strategy)?
+                .into_iter()
+                .filter(|share| filter.matches_share(share)),
+        );
+    }
+    shares.truncate(limit);
+
+    Ok(localize_share_infos(shares, language))
+}
@@ -0,0 +1,126 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::blocklist::get_blocked_agents;
+use crate::error::SharefeedError;
+use crate::input::check_reaction;
+use crate::read::ReadInput;
+use crate::signals::Signal;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ReactionInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub reaction_hash: ActionHash,
+    pub reaction: Reaction,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub author: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+#[hdk_extern]
+pub fn add_reaction(reaction: Reaction) -> ExternResult<Record> {
+    trace_call!("add_reaction");
+    check_reaction(&reaction, &sharefeed_properties()?)?;
+    let reaction_hash = create_entry(&EntryTypes::Reaction(reaction.clone()))?;
+    create_link(
+        reaction.share_item_hash.clone(),
+        reaction_hash.clone(),
+        LinkTypes::ShareItemToReactions,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+
+    let signal = Signal::ReactionAdded {
+        share_item_hash: reaction.share_item_hash.clone(),
+        reaction_hash: reaction_hash.clone(),
+        emoji: reaction.emoji.clone(),
+        author: agent_info()?.agent_initial_pubkey,
+    };
+    // The share's participants hear about it from post_commit
+    emit_signal(&signal)?;
+
+    let record = get(reaction_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the newly created Reaction")),
+    )?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_reactions(share_item_hash: ReadInput<ActionHash>) -> ExternResult<Vec<ReactionInfo>> {
+    trace_call!("get_reactions");
+    let (share_item_hash, strategy) = share_item_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(share_item_hash, LinkTypes::ShareItemToReactions)?,
+        strategy.get_strategy(),
+    )?;
+
+    let blocklist = get_blocked_agents(())?;
+    let mut reactions: Vec<ReactionInfo> = Vec::new();
+    for link in links {
+        let reaction_hash =
+            ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
+        if let Some(record) = get(reaction_hash.clone(), strategy.get_options())? {
+            if blocklist.hides(record.action().author()) {
+                continue;
+            }
+            if let Some(reaction) = record
+                .entry()
+                .to_app_option::<Reaction>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                reactions.push(ReactionInfo {
+                    reaction_hash,
+                    reaction,
+                    author: record.action().author().clone(),
+                    created_at: link.timestamp,
+                });
+            }
+        }
+    }
+
+    Ok(reactions)
+}
+
+/// Number of reactions on a share, from its links alone
+#[hdk_extern]
+pub fn count_reactions(share_item_hash: ReadInput<ActionHash>) -> ExternResult<usize> {
+    trace_call!("count_reactions");
+    let (share_item_hash, strategy) = share_item_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(share_item_hash, LinkTypes::ShareItemToReactions)?,
+        strategy.get_strategy(),
+    )?;
+    Ok(links.len())
+}
+
+#[hdk_extern]
+pub fn remove_reaction(reaction_hash: ActionHash) -> ExternResult<ActionHash> {
+    trace_call!("remove_reaction");
+    let record = get(reaction_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the Reaction to remove")),
+    )?;
+    let reaction: Reaction = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Record is not a Reaction",
+        )))?;
+
+    let links = get_links(
+        LinkQuery::try_new(reaction.share_item_hash, LinkTypes::ShareItemToReactions)?,
+        GetStrategy::Local,
+    )?;
+    for link in links {
+        if link.target == AnyLinkableHash::from(reaction_hash.clone()) {
+            delete_link(link.create_link_hash, GetOptions::local())?;
+        }
+    }
+
+    delete_entry(reaction_hash)
+}
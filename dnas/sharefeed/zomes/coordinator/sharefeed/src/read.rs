@@ -0,0 +1,81 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+
+/// Where read externs look for links and records
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum ReadStrategy {
+    /// Only what is already in the local cache; fast but may miss content
+    /// that hasn't synced yet
+    #[default]
+    Local,
+    /// Go to the network for anything not held locally
+    Network,
+}
+
+impl ReadStrategy {
+    pub fn get_strategy(self) -> GetStrategy {
+        match self {
+            ReadStrategy::Local => GetStrategy::Local,
+            ReadStrategy::Network => GetStrategy::Network,
+        }
+    }
+
+    pub fn get_options(self) -> GetOptions {
+        match self {
+            ReadStrategy::Local => GetOptions::local(),
+            ReadStrategy::Network => GetOptions::network(),
+        }
+    }
+}
+
+/// Input of the read externs. Clients can keep passing the bare input,
+/// which reads locally, or wrap it as `{ input, strategy }` to pick a
+/// strategy. Externs returning shares also take `{ input, language }` to
+/// have them rendered in a language.
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum ReadInput<T> {
+    // Ahead of `WithStrategy`, which would otherwise match and drop the
+    // language
+    Localized {
+        input: T,
+        #[serde(default)]
+        strategy: ReadStrategy,
+        /// Language code such as `de` or `pt-BR`
+        language: String,
+    },
+    WithStrategy {
+        input: T,
+        #[serde(default)]
+        strategy: ReadStrategy,
+    },
+    Plain(T),
+}
+
+impl<T> ReadInput<T> {
+    pub fn into_parts(self) -> (T, ReadStrategy) {
+        match self {
+            ReadInput::Localized {
+                input, strategy, ..
+            } => (input, strategy),
+            ReadInput::WithStrategy { input, strategy } => (input, strategy),
+            ReadInput::Plain(input) => (input, ReadStrategy::Local),
+        }
+    }
+
+    /// Language the caller asked for, if any
+    pub fn language(&self) -> Option<String> {
+        match self {
+            ReadInput::Localized { language, .. } => Some(language.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<T> for ReadInput<T> {
+    fn from(input: T) -> Self {
+        ReadInput::Plain(input)
+    }
+}
@@ -0,0 +1,101 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::input::check_report;
+use crate::membership::get_feed_stewards;
+use crate::read::ReadInput;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ReportInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub report_hash: ActionHash,
+    pub report: Report,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub reporter: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+/// Fails unless I'm one of the feed's stewards
+pub fn check_is_steward(feed_hash: &ActionHash) -> ExternResult<()> {
+    if !get_feed_stewards(feed_hash)?.contains(&agent_info()?.agent_initial_pubkey) {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only stewards of this feed can do this",
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Flags a share in a feed for the feed's stewards
+#[hdk_extern]
+pub fn report_share(report: Report) -> ExternResult<Record> {
+    trace_call!("report_share");
+    check_report(&report)?;
+    let in_feed = get_links(
+        LinkQuery::try_new(report.feed_hash.clone(), LinkTypes::FeedToShare)?,
+        GetStrategy::Network,
+    )?
+    .into_iter()
+    .any(|link| link.target == AnyLinkableHash::from(report.share_item_hash.clone()));
+    if !in_feed {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "The reported share is not in this feed",
+        ))
+        .into());
+    }
+
+    let report_hash = create_entry(&EntryTypes::Report(report.clone()))?;
+    create_link(
+        report.feed_hash,
+        report_hash.clone(),
+        LinkTypes::FeedToReports,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    let record = get(report_hash, GetOptions::local())?.ok_or(SharefeedError::NotFound(
+        String::from("Could not find the newly created Report"),
+    ))?;
+    Ok(record)
+}
+
+/// Every report filed against shares in a feed, oldest first. Only the
+/// feed's stewards may read them.
+#[hdk_extern]
+pub fn get_reports_for_feed(feed_hash: ReadInput<ActionHash>) -> ExternResult<Vec<ReportInfo>> {
+    trace_call!("get_reports_for_feed");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    check_is_steward(&feed_hash)?;
+    let links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToReports)?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut reports: Vec<ReportInfo> = Vec::new();
+    for link in links {
+        let report_hash =
+            ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
+        if let Some(record) = get(report_hash.clone(), strategy.get_options())? {
+            if let Some(report) = record
+                .entry()
+                .to_app_option::<Report>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                reports.push(ReportInfo {
+                    report_hash,
+                    report,
+                    reporter: record.action().author().clone(),
+                    created_at: link.timestamp,
+                });
+            }
+        }
+    }
+
+    reports.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(reports)
+}
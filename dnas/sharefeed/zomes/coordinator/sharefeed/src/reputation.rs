@@ -0,0 +1,119 @@
+use hdk::prelude::*;
+use sharefeed_integrity::*;
+
+use crate::follow::{get_author_share_links, get_following};
+use crate::read::ReadStrategy;
+
+const DAY_IN_MICROS: i64 = 24 * 60 * 60 * 1_000_000;
+
+/// Daily at 03:00 UTC
+const REPUTATION_REFRESH_SCHEDULE: &str = "0 0 3 * * *";
+
+/// Weight of one moderation removal relative to one reaction
+const REMOVAL_PENALTY: i64 = 5;
+
+fn latest_reputation_cache_record() -> ExternResult<Option<Record>> {
+    let filter = ChainQueryFilter::new()
+        .entry_type(UnitEntryTypes::ReputationCache.try_into()?)
+        .include_entries(true);
+    Ok(query(filter)?.into_iter().last())
+}
+
+fn get_reputation_cache() -> ExternResult<ReputationCache> {
+    let reputation_cache = match latest_reputation_cache_record()? {
+        Some(record) => record
+            .entry()
+            .to_app_option::<ReputationCache>()
+            .map_err(|e| wasm_error!(e))?
+            .unwrap_or_default(),
+        None => ReputationCache::default(),
+    };
+    Ok(reputation_cache)
+}
+
+fn save_reputation_cache(reputation_cache: ReputationCache) -> ExternResult<()> {
+    match latest_reputation_cache_record()? {
+        Some(record) => update_entry(record.action_address().clone(), &reputation_cache)?,
+        None => create_entry(&EntryTypes::ReputationCache(reputation_cache))?,
+    };
+    Ok(())
+}
+
+/// Counts reactions other agents left on the agent's shares
+pub fn compute_agent_reputation(agent: AgentPubKey) -> ExternResult<AgentReputation> {
+    let mut reactions_received: u32 = 0;
+    for share_link in get_author_share_links(agent.clone(), ReadStrategy::Local)? {
+        let reaction_links = get_links(
+            LinkQuery::try_new(share_link.target, LinkTypes::ShareItemToReactions)?,
+            GetStrategy::Local,
+        )?;
+        reactions_received += reaction_links
+            .iter()
+            .filter(|link| link.author != agent)
+            .count() as u32;
+    }
+    let removals: u32 = 0;
+
+    Ok(AgentReputation {
+        score: reactions_received as i64 - removals as i64 * REMOVAL_PENALTY,
+        agent,
+        reactions_received,
+        removals,
+        computed_at: sys_time()?,
+    })
+}
+
+/// Returns the cached score if it's less than a day old, otherwise
+/// recomputes it and refreshes the cache
+#[hdk_extern]
+pub fn get_agent_reputation(agent: AgentPubKey) -> ExternResult<AgentReputation> {
+    trace_call!("get_agent_reputation");
+    let now = sys_time()?;
+    let mut reputation_cache = get_reputation_cache()?;
+    if let Some(cached) = reputation_cache
+        .reputations
+        .iter()
+        .find(|reputation| reputation.agent == agent)
+    {
+        if now.as_micros() - cached.computed_at.as_micros() < DAY_IN_MICROS {
+            return Ok(cached.clone());
+        }
+    }
+
+    let reputation = compute_agent_reputation(agent.clone())?;
+    reputation_cache
+        .reputations
+        .retain(|cached| cached.agent != agent);
+    reputation_cache.reputations.push(reputation.clone());
+    save_reputation_cache(reputation_cache)?;
+    Ok(reputation)
+}
+
+#[hdk_extern(infallible)]
+pub fn scheduled_reputation_refresh(_: Option<Schedule>) -> Option<Schedule> {
+    trace_call!("scheduled_reputation_refresh");
+    if let Err(err) = refresh_reputation_cache() {
+        debug!("Failed to refresh reputation cache: {:?}", err);
+    }
+    Some(Schedule::Persisted(REPUTATION_REFRESH_SCHEDULE.to_string()))
+}
+
+/// Recomputes every cached agent plus everyone I follow
+fn refresh_reputation_cache() -> ExternResult<()> {
+    let mut agents: Vec<AgentPubKey> = get_reputation_cache()?
+        .reputations
+        .into_iter()
+        .map(|reputation| reputation.agent)
+        .collect();
+    for agent in get_following(())? {
+        if !agents.contains(&agent) {
+            agents.push(agent);
+        }
+    }
+
+    let mut reputations: Vec<AgentReputation> = Vec::new();
+    for agent in agents {
+        reputations.push(compute_agent_reputation(agent)?);
+    }
+    save_reputation_cache(ReputationCache { reputations })
+}
@@ -0,0 +1,153 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::HashSet;
+
+use crate::error::SharefeedError;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SavedItemInfo {
+    /// Latest version of the saved item
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub saved_item_hash: ActionHash,
+    pub saved_item: SavedItem,
+}
+
+/// Latest version of every saved item on my chain that hasn't been
+/// removed, in queue order
+fn query_saved_items() -> ExternResult<Vec<SavedItemInfo>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::SavedItem.try_into()?)
+            .include_entries(true),
+    )?;
+    let deleted: HashSet<ActionHash> =
+        query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::Delete(delete) => Some(delete.deletes_address.clone()),
+                _ => None,
+            })
+            .collect();
+    let superseded: HashSet<ActionHash> = records
+        .iter()
+        .filter_map(|record| match record.action() {
+            Action::Update(update) => Some(update.original_action_address.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let mut saved_items: Vec<SavedItemInfo> = Vec::new();
+    for record in records {
+        let saved_item_hash = record.action_address().clone();
+        if deleted.contains(&saved_item_hash) || superseded.contains(&saved_item_hash) {
+            continue;
+        }
+        if let Some(saved_item) = record
+            .entry()
+            .to_app_option::<SavedItem>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            saved_items.push(SavedItemInfo {
+                saved_item_hash,
+                saved_item,
+            });
+        }
+    }
+    // Stable, so items sharing a position stay in the order they were saved
+    saved_items.sort_by_key(|info| info.saved_item.position);
+    Ok(saved_items)
+}
+
+/// Adds a share to the back of my reading queue. Saving a share that's
+/// already queued leaves it where it is.
+#[hdk_extern]
+pub fn save_for_later(share_item_hash: ActionHash) -> ExternResult<SavedItemInfo> {
+    trace_call!("save_for_later");
+    let saved_items = query_saved_items()?;
+    if let Some(existing) = saved_items
+        .iter()
+        .find(|info| info.saved_item.share_item_hash == share_item_hash)
+    {
+        return Ok(existing.clone());
+    }
+
+    let saved_item = SavedItem {
+        share_item_hash,
+        position: saved_items
+            .last()
+            .map_or(0, |info| info.saved_item.position + 1),
+    };
+    let saved_item_hash = create_entry(&EntryTypes::SavedItem(saved_item.clone()))?;
+    Ok(SavedItemInfo {
+        saved_item_hash,
+        saved_item,
+    })
+}
+
+/// My reading queue, front first
+#[hdk_extern]
+pub fn get_saved(_: ()) -> ExternResult<Vec<SavedItemInfo>> {
+    trace_call!("get_saved");
+    query_saved_items()
+}
+
+/// Takes a share out of my reading queue
+#[hdk_extern]
+pub fn remove_saved(share_item_hash: ActionHash) -> ExternResult<()> {
+    trace_call!("remove_saved");
+    for info in query_saved_items()? {
+        if info.saved_item.share_item_hash == share_item_hash {
+            delete_entry(info.saved_item_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Puts my reading queue in the given order. The input must list every
+/// saved share exactly once; only items whose position changes are
+/// rewritten.
+#[hdk_extern]
+pub fn reorder_saved(share_item_hashes: Vec<ActionHash>) -> ExternResult<Vec<SavedItemInfo>> {
+    trace_call!("reorder_saved");
+    let saved_items = query_saved_items()?;
+    let unique: HashSet<&ActionHash> = share_item_hashes.iter().collect();
+    if unique.len() != share_item_hashes.len()
+        || share_item_hashes.len() != saved_items.len()
+        || !saved_items
+            .iter()
+            .all(|info| unique.contains(&info.saved_item.share_item_hash))
+    {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "The new order must list every saved share exactly once",
+        ))
+        .into());
+    }
+
+    let mut reordered: Vec<SavedItemInfo> = Vec::new();
+    for (position, share_item_hash) in share_item_hashes.into_iter().enumerate() {
+        let position = position as u32;
+        let Some(info) = saved_items
+            .iter()
+            .find(|info| info.saved_item.share_item_hash == share_item_hash)
+        else {
+            continue;
+        };
+        if info.saved_item.position == position {
+            reordered.push(info.clone());
+            continue;
+        }
+        let saved_item = SavedItem {
+            share_item_hash,
+            position,
+        };
+        let saved_item_hash = update_entry(info.saved_item_hash.clone(), &saved_item)?;
+        reordered.push(SavedItemInfo {
+            saved_item_hash,
+            saved_item,
+        });
+    }
+    Ok(reordered)
+}
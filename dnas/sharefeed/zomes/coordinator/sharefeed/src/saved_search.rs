@@ -0,0 +1,191 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::HashSet;
+
+use crate::error::SharefeedError;
+use crate::notification::{create_notification, query_notifications};
+use crate::read::ReadStrategy;
+use crate::search::{links_matching_all, query_tokens};
+
+/// Hourly, on the hour
+const SAVED_SEARCH_CHECK_SCHEDULE: &str = "0 0 * * * *";
+
+/// Most notifications a single saved search raises per check
+const MAX_MATCHES_PER_CHECK: usize = 10;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SavedSearchInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub saved_search_hash: ActionHash,
+    pub saved_search: SavedSearch,
+    /// When this version of the search was saved
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct UpdateSavedSearchInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub previous_saved_search_hash: ActionHash,
+    pub updated_saved_search: SavedSearch,
+}
+
+/// Latest version of every saved search on my chain that hasn't been deleted
+fn query_saved_searches() -> ExternResult<Vec<SavedSearchInfo>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::SavedSearch.try_into()?)
+            .include_entries(true),
+    )?;
+    let deleted: HashSet<ActionHash> =
+        query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::Delete(delete) => Some(delete.deletes_address.clone()),
+                _ => None,
+            })
+            .collect();
+    let superseded: HashSet<ActionHash> = records
+        .iter()
+        .filter_map(|record| match record.action() {
+            Action::Update(update) => Some(update.original_action_address.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let mut saved_searches: Vec<SavedSearchInfo> = Vec::new();
+    for record in records {
+        let saved_search_hash = record.action_address().clone();
+        if deleted.contains(&saved_search_hash) || superseded.contains(&saved_search_hash) {
+            continue;
+        }
+        if let Some(saved_search) = record
+            .entry()
+            .to_app_option::<SavedSearch>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            saved_searches.push(SavedSearchInfo {
+                saved_search_hash,
+                saved_search,
+                created_at: record.action().timestamp(),
+            });
+        }
+    }
+    Ok(saved_searches)
+}
+
+#[hdk_extern]
+pub fn create_saved_search(saved_search: SavedSearch) -> ExternResult<ActionHash> {
+    trace_call!("create_saved_search");
+    query_tokens(&saved_search.query)?;
+    create_entry(&EntryTypes::SavedSearch(saved_search))
+}
+
+#[hdk_extern]
+pub fn get_saved_searches(_: ()) -> ExternResult<Vec<SavedSearchInfo>> {
+    trace_call!("get_saved_searches");
+    query_saved_searches()
+}
+
+#[hdk_extern]
+pub fn update_saved_search(input: UpdateSavedSearchInput) -> ExternResult<ActionHash> {
+    trace_call!("update_saved_search");
+    query_tokens(&input.updated_saved_search.query)?;
+    if !query_saved_searches()?
+        .iter()
+        .any(|info| info.saved_search_hash == input.previous_saved_search_hash)
+    {
+        return Err(SharefeedError::NotFound(String::from(
+            "Could not find the SavedSearch, or it has since been updated",
+        ))
+        .into());
+    }
+    update_entry(
+        input.previous_saved_search_hash,
+        &input.updated_saved_search,
+    )
+}
+
+#[hdk_extern]
+pub fn delete_saved_search(saved_search_hash: ActionHash) -> ExternResult<ActionHash> {
+    trace_call!("delete_saved_search");
+    delete_entry(saved_search_hash)
+}
+
+#[hdk_extern(infallible)]
+pub fn scheduled_saved_search_check(_: Option<Schedule>) -> Option<Schedule> {
+    trace_call!("scheduled_saved_search_check");
+    if let Err(err) = check_saved_searches() {
+        debug!("Failed to check saved searches: {:?}", err);
+    }
+    Some(Schedule::Persisted(SAVED_SEARCH_CHECK_SCHEDULE.to_string()))
+}
+
+/// Notifies me about shares matching a saved search that were indexed
+/// after it was saved. Shares already notified about are skipped, so
+/// each match is raised once.
+fn check_saved_searches() -> ExternResult<()> {
+    let me = agent_info()?.agent_initial_pubkey;
+    let mut notified: HashSet<ActionHash> = query_notifications()?
+        .into_iter()
+        .filter(|info| info.notification.kind == NotificationKind::SavedSearchMatch)
+        .filter_map(|info| info.notification.share_item_hash)
+        .collect();
+
+    for info in query_saved_searches()? {
+        if !info.saved_search.notify {
+            continue;
+        }
+        let Ok(tokens) = query_tokens(&info.saved_search.query) else {
+            continue;
+        };
+        let feed_shares: Option<HashSet<AnyLinkableHash>> = match &info.saved_search.feed_hash {
+            Some(feed_hash) => Some(
+                get_links(
+                    LinkQuery::try_new(feed_hash.clone(), LinkTypes::FeedToShare)?,
+                    ReadStrategy::Local.get_strategy(),
+                )?
+                .into_iter()
+                .map(|link| link.target)
+                .collect(),
+            ),
+            None => None,
+        };
+
+        let matches = links_matching_all(&tokens, ReadStrategy::Local)?
+            .into_iter()
+            .filter(|link| link.timestamp > info.created_at && link.author != me)
+            .filter(|link| {
+                feed_shares
+                    .as_ref()
+                    .is_none_or(|feed_shares| feed_shares.contains(&link.target))
+            });
+        let mut raised = 0;
+        for link in matches {
+            if raised >= MAX_MATCHES_PER_CHECK {
+                break;
+            }
+            let Some(share_item_hash) = link.target.into_action_hash() else {
+                continue;
+            };
+            if !notified.insert(share_item_hash.clone()) {
+                continue;
+            }
+            create_notification(Notification {
+                kind: NotificationKind::SavedSearchMatch,
+                from: link.author,
+                feed_hash: info.saved_search.feed_hash.clone(),
+                share_item_hash: Some(share_item_hash),
+                read: false,
+            })?;
+            raised += 1;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,281 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::SharefeedError;
+use crate::read::{ReadInput, ReadStrategy};
+use crate::share_item::{localize_share_infos, share_item_infos_from_links, ShareItemInfo};
+
+const DEFAULT_SEARCH_PAGE_SIZE: usize = 20;
+/// Share of a fragment's trigrams a title must contain to match it
+const MIN_TRIGRAM_MATCH_PERCENT: usize = 60;
+
+/// Indexes a freshly created share under each word of its title and
+/// description
+pub fn index_share_item_words(
+    share_item_hash: &ActionHash,
+    share_item: &ShareItem,
+) -> ExternResult<()> {
+    for token in share_item_tokens(share_item) {
+        create_link(
+            word_path(&token).path_entry_hash()?,
+            share_item_hash.clone(),
+            LinkTypes::WordToShare,
+            SharefeedLinkTag::new().to_link_tag()?,
+        )?;
+    }
+    Ok(())
+}
+
+/// Indexes a freshly created share under each trigram of its title
+pub fn index_share_item_trigrams(
+    share_item_hash: &ActionHash,
+    share_item: &ShareItem,
+) -> ExternResult<()> {
+    for trigram in share_item_trigrams(share_item) {
+        create_link(
+            trigram_path(&trigram).path_entry_hash()?,
+            share_item_hash.clone(),
+            LinkTypes::TrigramToShare,
+            SharefeedLinkTag::new().to_link_tag()?,
+        )?;
+    }
+    Ok(())
+}
+
+/// Links to the shares indexed under every one of `tokens`, one per share,
+/// newest first. Each share is represented by its earliest index link.
+pub fn links_matching_all(tokens: &[String], strategy: ReadStrategy) -> ExternResult<Vec<Link>> {
+    let mut per_token: Vec<HashMap<AnyLinkableHash, Link>> = Vec::new();
+    for token in tokens {
+        let links = get_links(
+            LinkQuery::try_new(word_path(token).path_entry_hash()?, LinkTypes::WordToShare)?,
+            strategy.get_strategy(),
+        )?;
+        let mut earliest: HashMap<AnyLinkableHash, Link> = HashMap::new();
+        for link in links {
+            match earliest.get(&link.target) {
+                Some(existing) if existing.timestamp <= link.timestamp => {}
+                _ => {
+                    earliest.insert(link.target.clone(), link);
+                }
+            }
+        }
+        per_token.push(earliest);
+    }
+
+    // Intersect starting from the rarest word
+    per_token.sort_by_key(|links| links.len());
+    let mut per_token = per_token.into_iter();
+    let Some(first) = per_token.next() else {
+        return Ok(Vec::new());
+    };
+    let others: Vec<HashMap<AnyLinkableHash, Link>> = per_token.collect();
+
+    let mut matches: Vec<Link> = Vec::new();
+    for (target, mut link) in first {
+        let mut in_all = true;
+        for links in &others {
+            match links.get(&target) {
+                Some(other) => {
+                    if other.timestamp < link.timestamp {
+                        link = other.clone();
+                    }
+                }
+                None => {
+                    in_all = false;
+                    break;
+                }
+            }
+        }
+        if in_all {
+            matches.push(link);
+        }
+    }
+    matches.sort_by(|a, b| {
+        b.timestamp
+            .cmp(&a.timestamp)
+            .then_with(|| a.target.cmp(&b.target))
+    });
+    Ok(matches)
+}
+
+/// Words of a search query, or InvalidInput if it has none worth searching
+pub fn query_tokens(query: &str) -> Result<Vec<String>, SharefeedError> {
+    let tokens = tokenize(query);
+    if tokens.is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Search query must contain at least one word",
+        )));
+    }
+    Ok(tokens)
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SearchSharesInput {
+    pub query: String,
+    /// Only return shares indexed before this timestamp
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub cursor: Option<Timestamp>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SearchResultsPage {
+    pub shares: Vec<ShareItemInfo>,
+    /// Pass as `cursor` to fetch the next page; `None` on the last page
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub next_cursor: Option<Timestamp>,
+}
+
+/// One page of `links`, newest first, starting just before `cursor`
+pub fn search_results_page(
+    links: Vec<Link>,
+    cursor: Option<Timestamp>,
+    limit: Option<usize>,
+    strategy: ReadStrategy,
+) -> ExternResult<SearchResultsPage> {
+    let limit = limit.unwrap_or(DEFAULT_SEARCH_PAGE_SIZE);
+    let mut page: Vec<Link> = links
+        .into_iter()
+        .filter(|link| cursor.is_none_or(|cursor| link.timestamp < cursor))
+        .take(limit + 1)
+        .collect();
+
+    let next_cursor = if page.len() > limit {
+        page.truncate(limit);
+        page.last().map(|link| link.timestamp)
+    } else {
+        None
+    };
+    Ok(SearchResultsPage {
+        shares: share_item_infos_from_links(page, strategy)?,
+        next_cursor,
+    })
+}
+
+/// Shares whose title or description contains every word of the query,
+/// newest first
+#[hdk_extern]
+pub fn search_shares(input: ReadInput<SearchSharesInput>) -> ExternResult<SearchResultsPage> {
+    trace_call!("search_shares");
+    let language = input.language();
+    let (input, strategy) = input.into_parts();
+    let tokens = query_tokens(&input.query)?;
+    let links = links_matching_all(&tokens, strategy)?;
+    let mut page = search_results_page(links, input.cursor, input.limit, strategy)?;
+    page.shares = localize_share_infos(page.shares, language);
+    Ok(page)
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SearchFeedInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub query: String,
+    /// Only return shares indexed before this timestamp
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub cursor: Option<Timestamp>,
+    pub limit: Option<usize>,
+}
+
+/// `search_shares` limited to the shares in one feed
+#[hdk_extern]
+pub fn search_feed(input: ReadInput<SearchFeedInput>) -> ExternResult<SearchResultsPage> {
+    trace_call!("search_feed");
+    let language = input.language();
+    let (input, strategy) = input.into_parts();
+    let tokens = query_tokens(&input.query)?;
+
+    let feed_shares: HashSet<AnyLinkableHash> = get_links(
+        LinkQuery::try_new(input.feed_hash, LinkTypes::FeedToShare)?,
+        strategy.get_strategy(),
+    )?
+    .into_iter()
+    .map(|link| link.target)
+    .collect();
+    let links = links_matching_all(&tokens, strategy)?
+        .into_iter()
+        .filter(|link| feed_shares.contains(&link.target))
+        .collect();
+
+    let mut page = search_results_page(links, input.cursor, input.limit, strategy)?;
+    page.shares = localize_share_infos(page.shares, language);
+    Ok(page)
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SearchTitlesInput {
+    pub fragment: String,
+    pub limit: Option<usize>,
+}
+
+/// Shares whose title resembles `fragment`, best match first. Titles match
+/// when they contain most of the fragment's trigrams, so partial words and
+/// small typos still find them.
+#[hdk_extern]
+pub fn search_titles(input: ReadInput<SearchTitlesInput>) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("search_titles");
+    let (input, strategy) = input.into_parts();
+    let fragment_trigrams = trigrams(&input.fragment);
+    if fragment_trigrams.is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Title search needs a word of at least three characters",
+        ))
+        .into());
+    }
+
+    let mut scores: HashMap<AnyLinkableHash, (usize, Link)> = HashMap::new();
+    for trigram in &fragment_trigrams {
+        let links = get_links(
+            LinkQuery::try_new(
+                trigram_path(trigram).path_entry_hash()?,
+                LinkTypes::TrigramToShare,
+            )?,
+            strategy.get_strategy(),
+        )?;
+        let mut seen: HashSet<AnyLinkableHash> = HashSet::new();
+        for link in links {
+            if !seen.insert(link.target.clone()) {
+                continue;
+            }
+            scores
+                .entry(link.target.clone())
+                .and_modify(|(score, _)| *score += 1)
+                .or_insert((1, link));
+        }
+    }
+
+    let min_score = (fragment_trigrams.len() * MIN_TRIGRAM_MATCH_PERCENT).div_ceil(100);
+    let mut matches: Vec<(usize, Link)> = scores
+        .into_values()
+        .filter(|(score, _)| *score >= min_score)
+        .collect();
+    matches.sort_by(|(score_a, link_a), (score_b, link_b)| {
+        score_b
+            .cmp(score_a)
+            .then_with(|| link_b.timestamp.cmp(&link_a.timestamp))
+    });
+    matches.truncate(input.limit.unwrap_or(DEFAULT_SEARCH_PAGE_SIZE));
+
+    let ranks: Vec<AnyLinkableHash> = matches
+        .iter()
+        .map(|(_, link)| link.target.clone())
+        .collect();
+    let links: Vec<Link> = matches.into_iter().map(|(_, link)| link).collect();
+    let mut shares = share_item_infos_from_links(links, strategy)?;
+    shares.sort_by_key(|share| {
+        let target = AnyLinkableHash::from(share.action_hash.clone());
+        ranks.iter().position(|rank| rank == &target)
+    });
+    Ok(shares)
+}
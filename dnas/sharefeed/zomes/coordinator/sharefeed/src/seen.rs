@@ -0,0 +1,131 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::feed::{get_feed, get_feed_members};
+use crate::read::{ReadInput, ReadStrategy};
+
+/// Largest feed whose seen receipts are shown; past this, listing who has
+/// seen each share stops being useful and gets expensive
+pub const MAX_SEEN_RECEIPT_FEED_MEMBERS: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SeenReceiptInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SeenReceipt {
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub agent: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub seen_at: Timestamp,
+}
+
+/// Refuses unless the latest version of the feed takes seen receipts and
+/// I'm one of its members. Returns the feed's members.
+fn check_seen_receipts(
+    feed_hash: &ActionHash,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<AgentPubKey>> {
+    let read = ReadInput::WithStrategy {
+        input: feed_hash.clone(),
+        strategy,
+    };
+    let feed: Feed = get_feed(read.clone())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Feed",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    if !feed.seen_receipts {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "This feed doesn't take seen receipts",
+        ))
+        .into());
+    }
+    let members = get_feed_members(read)?;
+    if !members.contains(&agent_info()?.agent_initial_pubkey) {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Only members of this feed can use its seen receipts",
+        ))
+        .into());
+    }
+    Ok(members)
+}
+
+/// Tells the other members of a feed that I've seen one of its shares
+#[hdk_extern]
+pub fn mark_seen(input: SeenReceiptInput) -> ExternResult<()> {
+    trace_call!("mark_seen");
+    check_seen_receipts(&input.feed_hash, ReadStrategy::Network)?;
+    let share_target = AnyLinkableHash::from(input.share_item_hash.clone());
+    let query = LinkQuery::try_new(input.feed_hash.clone(), LinkTypes::FeedToSeenReceipts)?
+        .author(agent_info()?.agent_initial_pubkey);
+    if get_links(query, GetStrategy::Local)?
+        .iter()
+        .any(|link| link.target == share_target)
+    {
+        return Ok(());
+    }
+    create_link(
+        input.feed_hash,
+        input.share_item_hash,
+        LinkTypes::FeedToSeenReceipts,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    Ok(())
+}
+
+/// Members of a feed who have seen a share in it, earliest first. Only
+/// shown to members of feeds that take seen receipts and have at most
+/// `MAX_SEEN_RECEIPT_FEED_MEMBERS` members.
+#[hdk_extern]
+pub fn get_seen_by(input: ReadInput<SeenReceiptInput>) -> ExternResult<Vec<SeenReceipt>> {
+    trace_call!("get_seen_by");
+    let (input, strategy) = input.into_parts();
+    let members = check_seen_receipts(&input.feed_hash, strategy)?;
+    if members.len() > MAX_SEEN_RECEIPT_FEED_MEMBERS {
+        return Err(SharefeedError::InvalidInput(format!(
+            "Seen receipts are only shown in feeds of at most {} members",
+            MAX_SEEN_RECEIPT_FEED_MEMBERS
+        ))
+        .into());
+    }
+
+    let share_target = AnyLinkableHash::from(input.share_item_hash);
+    let mut links: Vec<Link> = get_links(
+        LinkQuery::try_new(input.feed_hash, LinkTypes::FeedToSeenReceipts)?,
+        strategy.get_strategy(),
+    )?
+    .into_iter()
+    .filter(|link| link.target == share_target && members.contains(&link.author))
+    .collect();
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    let mut receipts: Vec<SeenReceipt> = Vec::new();
+    for link in links {
+        if receipts.iter().any(|receipt| receipt.agent == link.author) {
+            continue;
+        }
+        receipts.push(SeenReceipt {
+            agent: link.author,
+            seen_at: link.timestamp,
+        });
+    }
+    Ok(receipts)
+}
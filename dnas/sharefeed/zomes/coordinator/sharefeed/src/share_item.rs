@@ -1,31 +1,185 @@
 use hdk::prelude::*;
+use schemars::JsonSchema;
 use sharefeed_integrity::*;
+use std::collections::HashSet;
 
-#[hdk_extern]
-pub fn create_share_item(share_item: ShareItem) -> ExternResult<Record> {
-    let share_item_hash = create_entry(&EntryTypes::ShareItem(share_item.clone()))?;
+use crate::batch::get_records;
+use crate::blocklist::get_blocked_agents;
+use crate::error::SharefeedError;
+use crate::fallback::RemoteFetcher;
+use crate::feed::{get_feeds_for_share, get_my_feeds};
+use crate::input::check_share_item;
+use crate::network_blocklist::{
+    check_network_blocklist, get_moderator_chain_heads, get_network_blocklist,
+};
+use crate::profiles::{AuthorProfile, AuthorProfileCache};
+use crate::read::{ReadInput, ReadStrategy};
 
-    // Create time-based index link
-    let timestamp = sys_time()?;
-    let path = time_path_for_timestamp(timestamp);
-    create_link(
-        path.path_entry_hash()?,
-        share_item_hash.clone(),
-        LinkTypes::TimeIndex,
-        (),
+/// Fails early when another share would put me over the network's
+/// `max_shares_per_hour`, which validation would reject
+fn check_share_rate(properties: &SharefeedProperties) -> ExternResult<()> {
+    if properties.max_shares_per_hour.is_none() {
+        return Ok(());
+    }
+    let window_start =
+        Timestamp::from_micros(sys_time()?.as_micros() - SHARE_RATE_WINDOW_SECS * 1_000_000);
+    let recent_shares = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::ShareItem.try_into()?)
+            .action_type(ActionType::Create),
+    )?
+    .into_iter()
+    .filter(|record| record.action().timestamp() >= window_start)
+    .count();
+    properties
+        .check_share_rate(recent_shares)
+        .map_err(|reason| SharefeedError::RateLimited(reason).into())
+}
+
+/// Fails early when sharing `share_item`'s URL again would put me over the
+/// network's `max_shares_per_url_per_day`, which validation would reject
+fn check_url_share_rate(
+    properties: &SharefeedProperties,
+    share_item: &ShareItem,
+) -> ExternResult<()> {
+    if properties.max_shares_per_url_per_day.is_none() {
+        return Ok(());
+    }
+    let window_start =
+        Timestamp::from_micros(sys_time()?.as_micros() - URL_FLOOD_WINDOW_SECS * 1_000_000);
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::ShareItem.try_into()?)
+            .include_entries(true),
     )?;
+    let deleted: Vec<ActionHash> = query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+        .into_iter()
+        .filter_map(|record| match record.action() {
+            Action::Delete(delete) => Some(delete.deletes_address.clone()),
+            _ => None,
+        })
+        .collect();
+    let mut recent_shares = 0;
+    for record in records {
+        if !matches!(record.action(), Action::Create(_))
+            || record.action().timestamp() < window_start
+            || deleted.contains(record.action_address())
+        {
+            continue;
+        }
+        if let Some(earlier_share_item) = record
+            .entry()
+            .to_app_option::<ShareItem>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            if earlier_share_item.has_same_url(share_item) {
+                recent_shares += 1;
+            }
+        }
+    }
+    properties
+        .check_url_share_rate(recent_shares)
+        .map_err(|reason| SharefeedError::RateLimited(reason).into())
+}
+
+#[hdk_extern]
+pub fn create_share_item(mut share_item: ShareItem) -> ExternResult<Record> {
+    trace_call!("create_share_item");
+    let properties = sharefeed_properties()?;
+    check_share_item(&share_item, &properties)?;
+    check_share_rate(&properties)?;
+    if share_item.has_url() {
+        check_url_share_rate(&properties, &share_item)?;
+    }
+    check_network_blocklist(&agent_info()?.agent_initial_pubkey, Some(&share_item.url))?;
+    share_item.moderator_chain_heads = get_moderator_chain_heads()?;
+
+    // Index links are created in post_commit
+    let share_item_hash = create_entry(&EntryTypes::ShareItem(share_item))?;
 
-    let record = get(share_item_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
-        WasmErrorInner::Guest(String::from("Could not find the newly created ShareItem"))
-    ))?;
+    let record = get(share_item_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the newly created ShareItem")),
+    )?;
     Ok(record)
 }
 
+/// How long a repeated share of the same URL counts as a duplicate
+const DEFAULT_IDEMPOTENCY_WINDOW_SECS: i64 = 10 * 60;
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct CreateShareItemIdempotentInput {
+    pub share_item: ShareItem,
+    /// Must be positive; 10 minutes when not given
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub window_secs: Option<i64>,
+}
+
+/// `create_share_item` that returns the latest revision of my existing
+/// share instead when I already shared the same normalized URL within the
+/// window, e.g. when a browser extension retries on a flaky connection.
+/// Shares I deleted since don't count, so a new one is created.
+#[hdk_extern]
+pub fn create_share_item_idempotent(input: CreateShareItemIdempotentInput) -> ExternResult<Record> {
+    trace_call!("create_share_item_idempotent");
+    let window_micros = input
+        .window_secs
+        .unwrap_or(DEFAULT_IDEMPOTENCY_WINDOW_SECS)
+        .checked_mul(1_000_000)
+        .filter(|window_micros| *window_micros > 0)
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "window_secs must be a positive number of seconds",
+        )))?;
+    if !input.share_item.has_url() {
+        return create_share_item(input.share_item);
+    }
+    let window_start =
+        Timestamp::from_micros(sys_time()?.as_micros().saturating_sub(window_micros));
+
+    // Only I can delete my shares, so my chain holds every delete
+    let deleted: HashSet<ActionHash> =
+        query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::Delete(delete) => Some(delete.deletes_address.clone()),
+                _ => None,
+            })
+            .collect();
+
+    let query = LinkQuery::try_new(
+        url_path(&input.share_item.url)?.path_entry_hash()?,
+        LinkTypes::UrlToShare,
+    )?
+    .author(agent_info()?.agent_initial_pubkey)
+    .after(window_start);
+    let latest_share_hash = get_links(query, GetStrategy::Local)?
+        .into_iter()
+        .filter_map(|link| {
+            let action_hash = ActionHash::try_from(link.target).ok()?;
+            Some((link.timestamp, action_hash))
+        })
+        .filter(|(_, action_hash)| !deleted.contains(action_hash))
+        .max_by(|(timestamp_a, _), (timestamp_b, _)| timestamp_a.cmp(timestamp_b))
+        .map(|(_, action_hash)| action_hash);
+
+    if let Some(share_hash) = latest_share_hash {
+        if let Some(record) = get_share_item(share_hash.into())? {
+            return Ok(record);
+        }
+    }
+
+    create_share_item(input.share_item)
+}
+
 #[hdk_extern]
-pub fn get_share_item(original_share_item_hash: ActionHash) -> ExternResult<Option<Record>> {
+pub fn get_share_item(
+    original_share_item_hash: ReadInput<ActionHash>,
+) -> ExternResult<Option<Record>> {
+    trace_call!("get_share_item");
+    let (original_share_item_hash, strategy) = original_share_item_hash.into_parts();
     let links = get_links(
         LinkQuery::try_new(original_share_item_hash.clone(), LinkTypes::ShareItemUpdates)?,
-        GetStrategy::Local,
+        strategy.get_strategy(),
     )?;
     let latest_link = links
         .into_iter()
@@ -34,18 +188,123 @@ pub fn get_share_item(original_share_item_hash: ActionHash) -> ExternResult<Opti
         Some(link) => ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?,
         None => original_share_item_hash.clone(),
     };
-    get(latest_share_item_hash, GetOptions::local())
+    get(latest_share_item_hash, strategy.get_options())
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct GetShareItemResolvedInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    /// Asked directly when the share can't be found on the DHT, if known
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey | null"))]
+    #[schemars(with = "Option<AgentPubKeySchema>")]
+    pub author: Option<AgentPubKey>,
+}
+
+/// Where a resolved record was found
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum RecordSource {
+    Local,
+    Network,
+    Author {
+        #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+        #[schemars(with = "AgentPubKeySchema")]
+        agent: AgentPubKey,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ResolvedRecord {
+    #[cfg_attr(feature = "ts", ts(type = "HcRecord"))]
+    #[schemars(with = "RecordSchema")]
+    pub record: Record,
+    pub source: RecordSource,
+}
+
+/// `get_share_item` for devices whose DHT view is still syncing: tries my
+/// local view, then the network, then asks the author. Shares found only
+/// through the author come back as originally created, without updates.
+#[hdk_extern]
+pub fn get_share_item_resolved(
+    input: GetShareItemResolvedInput,
+) -> ExternResult<Option<ResolvedRecord>> {
+    trace_call!("get_share_item_resolved");
+    let attempts = [
+        (ReadStrategy::Local, RecordSource::Local),
+        (ReadStrategy::Network, RecordSource::Network),
+    ];
+    for (strategy, source) in attempts {
+        let read = ReadInput::WithStrategy {
+            input: input.share_item_hash.clone(),
+            strategy,
+        };
+        if let Some(record) = get_share_item(read)? {
+            return Ok(Some(ResolvedRecord { record, source }));
+        }
+    }
+
+    let Some(author) = input.author else {
+        return Ok(None);
+    };
+    let record = RemoteFetcher::default().fetch_remote(&input.share_item_hash, &author)?;
+    Ok(record.map(|record| ResolvedRecord {
+        record,
+        source: RecordSource::Author { agent: author },
+    }))
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct UpdateShareItemInput {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub original_share_item_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub previous_share_item_hash: ActionHash,
     pub updated_share_item: ShareItem,
 }
 
 #[hdk_extern]
-pub fn update_share_item(input: UpdateShareItemInput) -> ExternResult<Record> {
+pub fn update_share_item(mut input: UpdateShareItemInput) -> ExternResult<Record> {
+    trace_call!("update_share_item");
+    check_share_item(&input.updated_share_item, &sharefeed_properties()?)?;
+    check_network_blocklist(
+        &agent_info()?.agent_initial_pubkey,
+        Some(&input.updated_share_item.url),
+    )?;
+    input.updated_share_item.moderator_chain_heads = get_moderator_chain_heads()?;
+    let latest = get_share_item(ReadInput::WithStrategy {
+        input: input.original_share_item_hash.clone(),
+        strategy: ReadStrategy::Network,
+    })?
+    .ok_or(SharefeedError::NotFound(String::from(
+        "Could not find the ShareItem to update",
+    )))?;
+    SharefeedError::check_latest_revision(
+        "ShareItem",
+        &input.previous_share_item_hash,
+        latest.action_address(),
+    )?;
+    let latest_share_item: ShareItem = latest
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a ShareItem entry",
+        )))?;
+    if !latest_share_item.has_same_url(&input.updated_share_item) {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "A share's URL cannot be changed",
+        ))
+        .into());
+    }
     let updated_share_item_hash = update_entry(
         input.previous_share_item_hash.clone(),
         &input.updated_share_item,
@@ -54,21 +313,23 @@ pub fn update_share_item(input: UpdateShareItemInput) -> ExternResult<Record> {
         input.original_share_item_hash.clone(),
         updated_share_item_hash.clone(),
         LinkTypes::ShareItemUpdates,
-        (),
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    let record = get(updated_share_item_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the newly updated ShareItem")),
     )?;
-    let record = get(updated_share_item_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
-        WasmErrorInner::Guest(String::from("Could not find the newly updated ShareItem"))
-    ))?;
     Ok(record)
 }
 
 #[hdk_extern]
 pub fn delete_share_item(original_share_item_hash: ActionHash) -> ExternResult<ActionHash> {
+    trace_call!("delete_share_item");
     delete_entry(original_share_item_hash)
 }
 
 // Time-based indexing helpers
-fn time_path_for_timestamp(timestamp: Timestamp) -> Path {
+/// Year and week bucket a timestamp falls into
+pub fn time_bucket_for_timestamp(timestamp: Timestamp) -> (i64, i64) {
     let seconds = timestamp.as_seconds_and_nanos().0;
     // Calculate year and week from unix timestamp
     // This is a simplified calculation - for production, use a proper date library
@@ -78,42 +339,118 @@ fn time_path_for_timestamp(timestamp: Timestamp) -> Path {
     let day_of_year = days_since_epoch % 365;
     let week = (day_of_year / 7) + 1;
 
+    (year, week)
+}
+
+pub fn time_path_for_timestamp(timestamp: Timestamp) -> Path {
+    let (year, week) = time_bucket_for_timestamp(timestamp);
     Path::from(format!("shares.{}.{:02}", year, week))
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct TimeRangeInput {
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
     pub year: i64,
     pub week: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct ShareItemInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
     pub action_hash: ActionHash,
     pub share_item: ShareItem,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
     pub created_at: Timestamp,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
     pub author: AgentPubKey,
+    pub author_profile: Option<AuthorProfile>,
 }
 
-#[hdk_extern]
-pub fn get_shares_for_week(input: TimeRangeInput) -> ExternResult<Vec<ShareItemInfo>> {
-    let path = Path::from(format!("shares.{}.{:02}", input.year, input.week));
+/// Renders shares in `language`, when one was asked for
+pub fn localize_share_infos(
+    share_items: Vec<ShareItemInfo>,
+    language: Option<String>,
+) -> Vec<ShareItemInfo> {
+    let Some(language) = language else {
+        return share_items;
+    };
+    share_items
+        .into_iter()
+        .map(|mut info| {
+            info.share_item = info.share_item.localized(&language);
+            info
+        })
+        .collect()
+}
 
-    let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::TimeIndex)?,
-        GetStrategy::Local,
-    )?;
+/// Collapses links that point at the same target, keeping the earliest
+pub fn dedup_links_by_target(mut links: Vec<Link>) -> Vec<Link> {
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    let mut seen: HashSet<AnyLinkableHash> = HashSet::new();
+    links.retain(|link| seen.insert(link.target.clone()));
+    links
+}
+
+/// Resolves links pointing at ShareItems into ShareItemInfos, newest first.
+/// Several links to the same share are collapsed into one item.
+/// Targets that can't be found with `strategy`, whose author I've blocked
+/// or muted, or that the network blocklist bans, are skipped.
+pub fn share_item_infos_from_links(
+    links: Vec<Link>,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<ShareItemInfo>> {
+    share_item_infos_with_fallback(links, strategy, None)
+}
+
+/// Like `share_item_infos_from_links`, but records that can't be found
+/// are fetched from their holders through `fetcher` when one is given
+pub fn share_item_infos_with_fallback(
+    links: Vec<Link>,
+    strategy: ReadStrategy,
+    mut fetcher: Option<&mut RemoteFetcher>,
+) -> ExternResult<Vec<ShareItemInfo>> {
+    let blocklist = get_blocked_agents(())?;
+    let network_blocklist = get_network_blocklist(().into())?;
+    let mut profiles = AuthorProfileCache::default();
+    let links = dedup_links_by_target(links);
+    let action_hashes = links
+        .iter()
+        .map(|link| ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err)))
+        .collect::<ExternResult<Vec<ActionHash>>>()?;
+    let records = get_records(action_hashes.clone(), strategy)?;
 
     let mut share_items: Vec<ShareItemInfo> = Vec::new();
-    for link in links {
-        let action_hash = ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
-        if let Some(record) = get(action_hash.clone(), GetOptions::local())? {
-            if let Some(share_item) = record.entry().to_app_option::<ShareItem>().map_err(|e| wasm_error!(e))? {
+    for ((link, action_hash), record) in links.into_iter().zip(action_hashes).zip(records) {
+        let record = match (record, fetcher.as_deref_mut()) {
+            (None, Some(fetcher)) => fetcher.fetch_remote(&action_hash, &link.author)?,
+            (record, _) => record,
+        };
+        if let Some(record) = record {
+            if blocklist.hides(record.action().author()) {
+                continue;
+            }
+            if let Some(share_item) = record
+                .entry()
+                .to_app_option::<ShareItem>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                let author = record.action().author().clone();
+                let banned_domain = url_domain(&share_item.url)
+                    .is_some_and(|domain| network_blocklist.bans_domain(&domain));
+                if network_blocklist.bans_agent(&author) || banned_domain {
+                    continue;
+                }
                 share_items.push(ShareItemInfo {
                     action_hash,
                     share_item,
                     created_at: link.timestamp,
-                    author: record.action().author().clone(),
+                    author_profile: profiles.get(&author),
+                    author,
                 });
             }
         }
@@ -125,8 +462,132 @@ pub fn get_shares_for_week(input: TimeRangeInput) -> ExternResult<Vec<ShareItemI
     Ok(share_items)
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ShareSummaryInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub action_hash: ActionHash,
+    pub summary: ShareLinkSummary,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+/// Resolves FeedToShare or TimeIndex links into summaries, newest first,
+/// using the summary in each link tag. Only links created without one
+/// (before tags carried summaries) need their record fetched.
+pub fn share_summaries_from_links(
+    links: Vec<Link>,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<ShareSummaryInfo>> {
+    let blocklist = get_blocked_agents(())?;
+    let network_blocklist = get_network_blocklist(().into())?;
+
+    let mut summaries: Vec<ShareSummaryInfo> = Vec::new();
+    let mut untagged: Vec<(ActionHash, Timestamp)> = Vec::new();
+    for link in dedup_links_by_target(links) {
+        let action_hash =
+            ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
+        match ShareLinkSummary::from_link_tag(&link.tag) {
+            Some(summary) => summaries.push(ShareSummaryInfo {
+                action_hash,
+                summary,
+                created_at: link.timestamp,
+            }),
+            None => untagged.push((action_hash, link.timestamp)),
+        }
+    }
+
+    let records = get_records(
+        untagged.iter().map(|(hash, _)| hash.clone()).collect(),
+        strategy,
+    )?;
+    for ((action_hash, created_at), record) in untagged.into_iter().zip(records) {
+        if let Some(record) = record {
+            if let Some(share_item) = record
+                .entry()
+                .to_app_option::<ShareItem>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                summaries.push(ShareSummaryInfo {
+                    action_hash,
+                    summary: ShareLinkSummary::new(&share_item, record.action().author().clone()),
+                    created_at,
+                });
+            }
+        }
+    }
+
+    summaries.retain(|info| {
+        let banned_domain = info
+            .summary
+            .domain
+            .as_ref()
+            .is_some_and(|domain| network_blocklist.bans_domain(domain));
+        !blocklist.hides(&info.summary.author)
+            && !network_blocklist.bans_agent(&info.summary.author)
+            && !banned_domain
+    });
+    summaries.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    Ok(summaries)
+}
+
+/// Lightweight `get_shares_for_week` that renders from link tags alone
+#[hdk_extern]
+pub fn get_share_summaries_for_week(
+    input: ReadInput<TimeRangeInput>,
+) -> ExternResult<Vec<ShareSummaryInfo>> {
+    trace_call!("get_share_summaries_for_week");
+    let (input, strategy) = input.into_parts();
+    let path = Path::from(format!("shares.{}.{:02}", input.year, input.week));
+
+    let links = get_links(
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::TimeIndex)?,
+        strategy.get_strategy(),
+    )?;
+
+    share_summaries_from_links(links, strategy)
+}
+
+#[hdk_extern]
+pub fn get_shares_for_week(input: ReadInput<TimeRangeInput>) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("get_shares_for_week");
+    let language = input.language();
+    let (input, strategy) = input.into_parts();
+    let path = Path::from(format!("shares.{}.{:02}", input.year, input.week));
+
+    let links = get_links(
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::TimeIndex)?,
+        strategy.get_strategy(),
+    )?;
+
+    Ok(localize_share_infos(
+        share_item_infos_from_links(links, strategy)?,
+        language,
+    ))
+}
+
+/// Number of shares created in the week, from the time index alone
+#[hdk_extern]
+pub fn count_shares_for_week(input: ReadInput<TimeRangeInput>) -> ExternResult<usize> {
+    trace_call!("count_shares_for_week");
+    let (input, strategy) = input.into_parts();
+    let path = Path::from(format!("shares.{}.{:02}", input.year, input.week));
+
+    let links = get_links(
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::TimeIndex)?,
+        strategy.get_strategy(),
+    )?;
+    Ok(links.len())
+}
+
 #[hdk_extern]
-pub fn get_recent_shares(_: ()) -> ExternResult<Vec<ShareItemInfo>> {
+pub fn get_recent_shares(input: ReadInput<()>) -> ExternResult<Vec<ShareItemInfo>> {
+    trace_call!("get_recent_shares");
+    let language = input.language();
+    let (_, strategy) = input.into_parts();
     // Get current time and calculate current week
     let timestamp = sys_time()?;
     let seconds = timestamp.as_seconds_and_nanos().0;
@@ -137,11 +598,20 @@ pub fn get_recent_shares(_: ()) -> ExternResult<Vec<ShareItemInfo>> {
     let week = ((day_of_year / 7) + 1) as u32;
 
     // Get shares from current week
-    let mut all_shares = get_shares_for_week(TimeRangeInput { year, week })?;
+    let mut all_shares = get_shares_for_week(ReadInput::WithStrategy {
+        input: TimeRangeInput { year, week },
+        strategy,
+    })?;
 
     // If we have fewer than 20 shares, also get from previous week
     if all_shares.len() < 20 && week > 1 {
-        let prev_shares = get_shares_for_week(TimeRangeInput { year, week: week - 1 })?;
+        let prev_shares = get_shares_for_week(ReadInput::WithStrategy {
+            input: TimeRangeInput {
+                year,
+                week: week - 1,
+            },
+            strategy,
+        })?;
         all_shares.extend(prev_shares);
     }
 
@@ -149,5 +619,85 @@ pub fn get_recent_shares(_: ()) -> ExternResult<Vec<ShareItemInfo>> {
     all_shares.sort_by(|a, b| b.created_at.cmp(&a.created_at));
     all_shares.truncate(50);
 
-    Ok(all_shares)
+    Ok(localize_share_infos(all_shares, language))
+}
+
+/// A feed another agent's share of the same URL appears in
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SharerFeed {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct OtherSharer {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub author: AgentPubKey,
+    pub author_profile: Option<AuthorProfile>,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub shared_at: Timestamp,
+    /// Only feeds that are public or that we are a member of
+    pub feeds: Vec<SharerFeed>,
+}
+
+/// Other agents who already shared `url`, found through the normalized URL
+/// anchor, so the UI can show "also shared by" instead of a duplicate share
+#[hdk_extern]
+pub fn get_other_sharers(url: ReadInput<String>) -> ExternResult<Vec<OtherSharer>> {
+    trace_call!("get_other_sharers");
+    let (url, strategy) = url.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(url_path(&url)?.path_entry_hash()?, LinkTypes::UrlToShare)?,
+        strategy.get_strategy(),
+    )?;
+
+    let me = agent_info()?.agent_initial_pubkey;
+    let my_feeds: Vec<ActionHash> = get_my_feeds(().into())?
+        .into_iter()
+        .map(|feed_info| feed_info.action_hash)
+        .collect();
+
+    let mut sharers: Vec<OtherSharer> = Vec::new();
+    for share in share_item_infos_from_links(links, strategy)? {
+        if share.author == me {
+            continue;
+        }
+
+        let mut feeds: Vec<SharerFeed> = Vec::new();
+        for feed_hash in get_feeds_for_share(share.action_hash.clone(), strategy)? {
+            if let Some(record) = get(feed_hash.clone(), strategy.get_options())? {
+                if let Some(feed) = record
+                    .entry()
+                    .to_app_option::<Feed>()
+                    .map_err(|e| wasm_error!(e))?
+                {
+                    if feed.is_public || my_feeds.contains(&feed_hash) {
+                        feeds.push(SharerFeed {
+                            feed_hash,
+                            name: feed.name,
+                        });
+                    }
+                }
+            }
+        }
+
+        sharers.push(OtherSharer {
+            share_item_hash: share.action_hash,
+            author: share.author,
+            author_profile: share.author_profile,
+            shared_at: share.created_at,
+            feeds,
+        });
+    }
+
+    Ok(sharers)
 }
@@ -0,0 +1,310 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::{
+    ActionHashSchema, AgentPubKeySchema, Comment, EntryTypes, Feed, LinkTypes, Reaction,
+};
+
+use crate::blocklist::get_blocked_agents;
+use crate::comment::get_share_participants;
+use crate::fallback::RemoteFetcher;
+use crate::feed::{announce_new_member, get_feed_members};
+use crate::index::get_entry_for_action;
+use crate::notification::{create_notification, notification_for_signal};
+use crate::notification_prefs::{get_notification_prefs, is_quiet_time, should_notify};
+use crate::read::{ReadInput, ReadStrategy};
+
+/// Maximum number of agents addressed by a single `send_remote_signal` call
+const REMOTE_SIGNAL_CHUNK_SIZE: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum Signal {
+    NewShareInFeed {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        feed_hash: ActionHash,
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        share_item_hash: ActionHash,
+        #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+        #[schemars(with = "AgentPubKeySchema")]
+        author: AgentPubKey,
+    },
+    AddedToFeed {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        feed_hash: ActionHash,
+        #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+        #[schemars(with = "AgentPubKeySchema")]
+        added_by: AgentPubKey,
+    },
+    Mentioned {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        share_item_hash: ActionHash,
+        #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+        #[schemars(with = "AgentPubKeySchema")]
+        mentioned_by: AgentPubKey,
+    },
+    DigestReady {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        digest_hash: ActionHash,
+    },
+    CommentAdded {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        share_item_hash: ActionHash,
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        comment_hash: ActionHash,
+        #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+        #[schemars(with = "AgentPubKeySchema")]
+        author: AgentPubKey,
+    },
+    MemberJoined {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        feed_hash: ActionHash,
+        #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+        #[schemars(with = "AgentPubKeySchema")]
+        member: AgentPubKey,
+        #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+        #[schemars(with = "AgentPubKeySchema")]
+        added_by: AgentPubKey,
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        member_link_hash: ActionHash,
+    },
+    ReactionAdded {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        share_item_hash: ActionHash,
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        reaction_hash: ActionHash,
+        emoji: String,
+        #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+        #[schemars(with = "AgentPubKeySchema")]
+        author: AgentPubKey,
+    },
+}
+
+/// A record named in a signal, from the network or else from the agent who
+/// sent it, who has just written it
+fn get_signal_record(
+    action_hash: &ActionHash,
+    sender: &AgentPubKey,
+) -> ExternResult<Option<Record>> {
+    if let Some(record) = get(action_hash.clone(), GetOptions::network())? {
+        return Ok(Some(record));
+    }
+    RemoteFetcher::default().fetch_remote(action_hash, sender)
+}
+
+fn is_author_of(action_hash: &ActionHash, sender: &AgentPubKey) -> ExternResult<bool> {
+    Ok(get_signal_record(action_hash, sender)?
+        .is_some_and(|record| record.action().author() == sender))
+}
+
+/// The entry of a record `sender` wrote, if it is a `T`
+fn get_entry_by<T>(action_hash: &ActionHash, sender: &AgentPubKey) -> ExternResult<Option<T>>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    Ok(get_signal_record(action_hash, sender)?
+        .filter(|record| record.action().author() == sender)
+        .and_then(|record| record.entry().to_app_option::<T>().ok().flatten()))
+}
+
+/// Whether `sender` wrote the FeedToMember link at `link_hash`, adding
+/// `member` to the feed at `feed_hash`
+fn is_member_link(
+    link_hash: &ActionHash,
+    feed_hash: &ActionHash,
+    member: &AgentPubKey,
+    sender: &AgentPubKey,
+) -> ExternResult<bool> {
+    let feed_to_member = ScopedLinkType::try_from(LinkTypes::FeedToMember)?;
+    let Some(record) = get_signal_record(link_hash, sender)? else {
+        return Ok(false);
+    };
+    Ok(match record.action() {
+        Action::CreateLink(create_link) => {
+            &create_link.author == sender
+                && create_link.zome_index == feed_to_member.zome_index
+                && create_link.link_type == feed_to_member.zome_type
+                && create_link.base_address == AnyLinkableHash::from(feed_hash.clone())
+                && create_link.target_address == AnyLinkableHash::from(member.clone())
+        }
+        _ => false,
+    })
+}
+
+/// Stewards of the feed as created, then anyone linked as a member
+fn is_feed_member(feed_hash: &ActionHash, sender: &AgentPubKey) -> ExternResult<bool> {
+    let feed = get_signal_record(feed_hash, sender)?
+        .map(|record| record.entry().to_app_option::<Feed>())
+        .transpose()
+        .map_err(|e| wasm_error!(e))?
+        .flatten();
+    let Some(feed) = feed else {
+        return Ok(false);
+    };
+    if feed.stewards.contains(sender) {
+        return Ok(true);
+    }
+    Ok(get_feed_members(ReadInput::WithStrategy {
+        input: feed_hash.clone(),
+        strategy: ReadStrategy::Network,
+    })?
+    .contains(sender))
+}
+
+/// Whether `sender` could have sent `signal`: a member of the feed it
+/// names, the author of the share it names, the author of the comment or
+/// reaction it names on that share, or whoever wrote the member link it
+/// names. Anything else is a forged notification.
+fn is_genuine(signal: &Signal, sender: &AgentPubKey) -> ExternResult<bool> {
+    match signal {
+        Signal::NewShareInFeed {
+            feed_hash,
+            share_item_hash,
+            author,
+        } => Ok(author == sender
+            && (is_feed_member(feed_hash, sender)? || is_author_of(share_item_hash, sender)?)),
+        Signal::AddedToFeed {
+            feed_hash,
+            added_by,
+        } => Ok(added_by == sender && is_feed_member(feed_hash, sender)?),
+        Signal::MemberJoined {
+            feed_hash,
+            member,
+            added_by,
+            member_link_hash,
+        } => Ok(added_by == sender
+            && is_member_link(member_link_hash, feed_hash, member, sender)?
+            && is_feed_member(feed_hash, sender)?),
+        Signal::Mentioned {
+            share_item_hash,
+            mentioned_by,
+        } => Ok(mentioned_by == sender && is_author_of(share_item_hash, sender)?),
+        // Digests are only ever signalled to the local UI
+        Signal::DigestReady { .. } => Ok(false),
+        Signal::CommentAdded {
+            share_item_hash,
+            comment_hash,
+            author,
+        } => Ok(author == sender
+            && get_entry_by::<Comment>(comment_hash, sender)?
+                .is_some_and(|comment| &comment.share_item_hash == share_item_hash)),
+        Signal::ReactionAdded {
+            share_item_hash,
+            reaction_hash,
+            author,
+            ..
+        } => Ok(author == sender
+            && get_entry_by::<Reaction>(reaction_hash, sender)?
+                .is_some_and(|reaction| &reaction.share_item_hash == share_item_hash)),
+    }
+}
+
+/// Persists a notification for the signal (so it survives a disconnected
+/// client) and forwards it to the local UI, honoring our NotificationPrefs.
+/// During quiet hours the notification is still stored but not pushed live.
+/// Comments and reactions on shares I muted are dropped, and so are
+/// signals the sender couldn't have sent, see `is_genuine`.
+#[hdk_extern]
+pub fn recv_remote_signal(signal: Signal) -> ExternResult<()> {
+    trace_call!("recv_remote_signal");
+    let sender = call_info()?.provenance;
+    let blocklist = get_blocked_agents(())?;
+    if blocklist.blocked.contains(&sender) {
+        return Ok(());
+    }
+    if let Signal::CommentAdded {
+        share_item_hash, ..
+    }
+    | Signal::ReactionAdded {
+        share_item_hash, ..
+    } = &signal
+    {
+        if blocklist.muted_shares.contains(share_item_hash) {
+            return Ok(());
+        }
+    }
+    if !is_genuine(&signal, &sender)? {
+        debug!("Dropping a signal {} couldn't have sent", sender);
+        return Ok(());
+    }
+
+    let prefs = get_notification_prefs(())?;
+    if !should_notify(&prefs, &signal) {
+        return Ok(());
+    }
+
+    if let Some(notification) = notification_for_signal(&signal, sender) {
+        create_notification(notification)?;
+    }
+
+    if is_quiet_time(&prefs)? {
+        return Ok(());
+    }
+    emit_signal(signal)
+}
+
+/// Tells a share's participants about a comment or reaction once it's
+/// committed, so they can fetch it from me to check I wrote it, and a
+/// feed's members about a member link, see `announce_new_member`. Called
+/// from `post_commit`.
+pub fn signal_committed_action(action: &SignedActionHashed) -> ExternResult<()> {
+    if let Action::CreateLink(create_link) = &action.hashed.content {
+        return announce_new_member(create_link, &action.hashed.hash);
+    }
+    if !matches!(action.hashed.content, Action::Create(_)) {
+        return Ok(());
+    }
+    let action_hash = &action.hashed.hash;
+    let author = action.hashed.content.author().clone();
+    let (share_item_hash, signal) = match get_entry_for_action(action_hash)? {
+        Some(EntryTypes::Comment(comment)) => (
+            comment.share_item_hash.clone(),
+            Signal::CommentAdded {
+                share_item_hash: comment.share_item_hash,
+                comment_hash: action_hash.clone(),
+                author,
+            },
+        ),
+        Some(EntryTypes::Reaction(reaction)) => (
+            reaction.share_item_hash.clone(),
+            Signal::ReactionAdded {
+                share_item_hash: reaction.share_item_hash,
+                reaction_hash: action_hash.clone(),
+                emoji: reaction.emoji,
+                author,
+            },
+        ),
+        _ => return Ok(()),
+    };
+    send_remote_signal_to_agents(&signal, get_share_participants(&share_item_hash)?)
+}
+
+/// Sends a remote signal to the given agents in chunks, skipping ourselves.
+/// Delivery is best-effort: failures are logged and never fail the caller.
+pub fn send_remote_signal_to_agents(signal: &Signal, agents: Vec<AgentPubKey>) -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let mut recipients: Vec<AgentPubKey> = Vec::new();
+    for agent in agents {
+        if agent != my_pubkey && !recipients.contains(&agent) {
+            recipients.push(agent);
+        }
+    }
+
+    for chunk in recipients.chunks(REMOTE_SIGNAL_CHUNK_SIZE) {
+        if let Err(err) = send_remote_signal(signal, chunk.to_vec()) {
+            debug!("Failed to send remote signal: {:?}", err);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::feed::{get_feed, save_feed_update, UpdateFeedInput};
+use crate::moderation::take_share_out_of_feed;
+use crate::read::{ReadInput, ReadStrategy};
+use crate::report::check_is_steward;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct StewardProposalInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub proposal_hash: ActionHash,
+    pub steward_proposal: StewardProposal,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub proposer: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+    /// Stewards who approved it
+    #[cfg_attr(feature = "ts", ts(type = "Array<AgentPubKey>"))]
+    #[schemars(with = "Vec<AgentPubKeySchema>")]
+    pub approvals: Vec<AgentPubKey>,
+}
+
+/// The Feed entry at `feed_hash`, whose stewards are the ones validation
+/// checks destructive actions against
+fn get_original_feed(feed_hash: &ActionHash) -> ExternResult<Feed> {
+    let feed: Feed = get(feed_hash.clone(), GetOptions::network())?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Could not find the Feed",
+        )))?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    Ok(feed)
+}
+
+/// Whether destructive actions on the feed need other stewards' approval
+pub fn needs_steward_approval(feed_hash: &ActionHash) -> ExternResult<bool> {
+    Ok(get_original_feed(feed_hash)?.needs_steward_approval())
+}
+
+/// The latest revision of a feed, read from the network
+fn get_latest_feed(feed_hash: &ActionHash) -> ExternResult<(Record, Feed)> {
+    let record = get_feed(ReadInput::WithStrategy {
+        input: feed_hash.clone(),
+        strategy: ReadStrategy::Network,
+    })?
+    .ok_or(SharefeedError::NotFound(String::from(
+        "Could not find the Feed",
+    )))?;
+    let feed: Feed = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Linked action must reference a Feed entry",
+        )))?;
+    Ok((record, feed))
+}
+
+/// Whether enough stewards signed off on a proposal for it to run: the
+/// feed's steward quorum for deleting it or removing shares, every steward
+/// of the revision it publishes for making it public
+fn is_signed_off(proposal: &StewardProposal, signers: &[AgentPubKey]) -> ExternResult<bool> {
+    match &proposal.kind {
+        StewardProposalKind::DeleteFeed | StewardProposalKind::RemoveShares { .. } => {
+            Ok(signers.len() >= get_original_feed(&proposal.feed_hash)?.required_signers())
+        }
+        StewardProposalKind::MakePublic { revision } => {
+            let feed = get_original_feed(revision)?;
+            Ok(feed
+                .stewards
+                .iter()
+                .all(|steward| signers.contains(steward)))
+        }
+    }
+}
+
+/// Carries out an approved proposal
+fn execute_steward_proposal(proposal: &StewardProposal) -> ExternResult<()> {
+    match &proposal.kind {
+        StewardProposalKind::DeleteFeed => {
+            delete_entry(proposal.feed_hash.clone())?;
+        }
+        StewardProposalKind::MakePublic { revision } => {
+            let (latest, mut feed) = get_latest_feed(&proposal.feed_hash)?;
+            if feed.is_public {
+                return Ok(());
+            }
+            // Validation only accepts the approvals for an update of the
+            // revision they were given for
+            if latest.action_address() != revision {
+                return Err(SharefeedError::InvalidInput(String::from(
+                    "The feed has changed since it was proposed to make it public",
+                ))
+                .into());
+            }
+            feed.is_public = true;
+            save_feed_update(
+                UpdateFeedInput {
+                    original_feed_hash: proposal.feed_hash.clone(),
+                    previous_feed_hash: revision.clone(),
+                    updated_feed: feed,
+                },
+                true,
+            )?;
+        }
+        StewardProposalKind::RemoveShares { share_item_hashes } => {
+            for share_item_hash in share_item_hashes {
+                take_share_out_of_feed(&proposal.feed_hash, share_item_hash)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Proposes an action on a feed I steward. Other stewards have to approve
+/// it with `approve_steward_proposal` before it runs.
+#[hdk_extern]
+pub fn propose_steward_action(steward_proposal: StewardProposal) -> ExternResult<Record> {
+    trace_call!("propose_steward_action");
+    check_is_steward(&steward_proposal.feed_hash)?;
+
+    let proposal_hash = create_entry(&EntryTypes::StewardProposal(steward_proposal.clone()))?;
+    create_link(
+        steward_proposal.feed_hash,
+        proposal_hash.clone(),
+        LinkTypes::FeedToStewardProposals,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+
+    let record = get(proposal_hash, GetOptions::local())?.ok_or(SharefeedError::NotFound(
+        String::from("Could not find the newly created StewardProposal"),
+    ))?;
+    Ok(record)
+}
+
+/// Approves another steward's proposal, and carries it out once enough
+/// stewards have signed off
+#[hdk_extern]
+pub fn approve_steward_proposal(proposal_hash: ActionHash) -> ExternResult<()> {
+    trace_call!("approve_steward_proposal");
+    let record = get(proposal_hash.clone(), GetOptions::network())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the StewardProposal")),
+    )?;
+    let proposal: StewardProposal = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Action must reference a StewardProposal entry",
+        )))?;
+    check_is_steward(&proposal.feed_hash)?;
+
+    let me = agent_info()?.agent_initial_pubkey;
+    if record.action().author() == &me {
+        return Err(SharefeedError::NotAuthorized(String::from(
+            "Stewards cannot approve their own proposals",
+        ))
+        .into());
+    }
+
+    let mut signers = vec![record.action().author().clone(), me.clone()];
+    let mut co_signed: Vec<ActionHash> = Vec::new();
+    for link in get_links(
+        LinkQuery::try_new(proposal_hash.clone(), LinkTypes::StewardProposalToApprovals)?,
+        GetStrategy::Network,
+    )? {
+        if link.author == me {
+            continue;
+        }
+        let Some(approval_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        co_signed.push(approval_hash);
+        if !signers.contains(&link.author) {
+            signers.push(link.author);
+        }
+    }
+
+    let approval_hash = create_entry(&EntryTypes::StewardApproval(StewardApproval {
+        proposal_hash: proposal_hash.clone(),
+        co_signed,
+    }))?;
+    create_link(
+        proposal_hash,
+        approval_hash,
+        LinkTypes::StewardProposalToApprovals,
+        SharefeedLinkTag::new().to_link_tag()?,
+    )?;
+    if is_signed_off(&proposal, &signers)? {
+        execute_steward_proposal(&proposal)?;
+    }
+    Ok(())
+}
+
+/// Takes back a proposal I made that hasn't been carried out
+#[hdk_extern]
+pub fn withdraw_steward_proposal(proposal_hash: ActionHash) -> ExternResult<()> {
+    trace_call!("withdraw_steward_proposal");
+    let record = get(proposal_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from("Could not find the StewardProposal")),
+    )?;
+    let proposal: StewardProposal = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::InvalidInput(String::from(
+            "Action must reference a StewardProposal entry",
+        )))?;
+    let query = LinkQuery::try_new(proposal.feed_hash, LinkTypes::FeedToStewardProposals)?
+        .author(agent_info()?.agent_initial_pubkey);
+    let proposal_target = AnyLinkableHash::from(proposal_hash);
+    for link in get_links(query, GetStrategy::Network)? {
+        if link.target == proposal_target {
+            delete_link(link.create_link_hash, GetOptions::network())?;
+        }
+    }
+    Ok(())
+}
+
+/// Proposals on a feed that haven't been withdrawn, oldest first
+#[hdk_extern]
+pub fn get_steward_proposals(
+    feed_hash: ReadInput<ActionHash>,
+) -> ExternResult<Vec<StewardProposalInfo>> {
+    trace_call!("get_steward_proposals");
+    let (feed_hash, strategy) = feed_hash.into_parts();
+    let links = get_links(
+        LinkQuery::try_new(feed_hash, LinkTypes::FeedToStewardProposals)?,
+        strategy.get_strategy(),
+    )?;
+
+    let mut proposals: Vec<StewardProposalInfo> = Vec::new();
+    for link in links {
+        let proposal_hash = ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        if proposals
+            .iter()
+            .any(|info| info.proposal_hash == proposal_hash)
+        {
+            continue;
+        }
+        let Some(record) = get(proposal_hash.clone(), strategy.get_options())? else {
+            continue;
+        };
+        let Some(steward_proposal) = record
+            .entry()
+            .to_app_option::<StewardProposal>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        proposals.push(StewardProposalInfo {
+            approvals: get_approvals(&proposal_hash, strategy)?,
+            proposal_hash,
+            steward_proposal,
+            proposer: record.action().author().clone(),
+            created_at: record.action().timestamp(),
+        });
+    }
+
+    proposals.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(proposals)
+}
+
+/// Stewards who approved a proposal, in the order they did
+fn get_approvals(
+    proposal_hash: &ActionHash,
+    strategy: ReadStrategy,
+) -> ExternResult<Vec<AgentPubKey>> {
+    let mut links = get_links(
+        LinkQuery::try_new(proposal_hash.clone(), LinkTypes::StewardProposalToApprovals)?,
+        strategy.get_strategy(),
+    )?;
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    let mut approvals: Vec<AgentPubKey> = Vec::new();
+    for link in links {
+        if !approvals.contains(&link.author) {
+            approvals.push(link.author);
+        }
+    }
+    Ok(approvals)
+}
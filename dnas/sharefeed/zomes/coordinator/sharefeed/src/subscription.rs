@@ -0,0 +1,318 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::HashSet;
+
+use crate::error::SharefeedError;
+use crate::feed::{get_my_feeds, with_feed_tags};
+use crate::notification::{create_notification, query_notifications};
+use crate::read::{ReadInput, ReadStrategy};
+use crate::share_item::{share_item_infos_from_links, ShareItemInfo};
+
+/// Hourly, at half past
+const SUBSCRIPTION_CHECK_SCHEDULE: &str = "0 30 * * * *";
+
+/// Most notifications raised per check
+const MAX_SUBSCRIPTION_MATCHES_PER_CHECK: usize = 20;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct TagSubscriptionInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub tag_subscription_hash: ActionHash,
+    pub tag_subscription: TagSubscription,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct DomainSubscriptionInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub domain_subscription_hash: ActionHash,
+    pub domain_subscription: DomainSubscription,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+}
+
+/// Every entry of `entry_type` on my chain that hasn't been deleted, with
+/// its action hash and creation time, oldest first
+fn query_subscriptions<T>(
+    entry_type: UnitEntryTypes,
+) -> ExternResult<Vec<(ActionHash, T, Timestamp)>>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(entry_type.try_into()?)
+            .include_entries(true),
+    )?;
+    let deleted: HashSet<ActionHash> =
+        query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::Delete(delete) => Some(delete.deletes_address.clone()),
+                _ => None,
+            })
+            .collect();
+
+    let mut subscriptions = Vec::new();
+    for record in records {
+        let action_hash = record.action_address().clone();
+        if deleted.contains(&action_hash) {
+            continue;
+        }
+        if let Some(subscription) = record
+            .entry()
+            .to_app_option::<T>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            subscriptions.push((action_hash, subscription, record.action().timestamp()));
+        }
+    }
+    Ok(subscriptions)
+}
+
+fn query_tag_subscriptions() -> ExternResult<Vec<TagSubscriptionInfo>> {
+    Ok(query_subscriptions(UnitEntryTypes::TagSubscription)?
+        .into_iter()
+        .map(
+            |(tag_subscription_hash, tag_subscription, created_at)| TagSubscriptionInfo {
+                tag_subscription_hash,
+                tag_subscription,
+                created_at,
+            },
+        )
+        .collect())
+}
+
+fn query_domain_subscriptions() -> ExternResult<Vec<DomainSubscriptionInfo>> {
+    Ok(query_subscriptions(UnitEntryTypes::DomainSubscription)?
+        .into_iter()
+        .map(
+            |(domain_subscription_hash, domain_subscription, created_at)| DomainSubscriptionInfo {
+                domain_subscription_hash,
+                domain_subscription,
+                created_at,
+            },
+        )
+        .collect())
+}
+
+/// Notifies me about new shares with `tag` in the feeds I belong to.
+/// Subscribing to a tag twice returns the existing subscription.
+#[hdk_extern]
+pub fn subscribe_to_tag(tag: String) -> ExternResult<TagSubscriptionInfo> {
+    trace_call!("subscribe_to_tag");
+    let tag = normalize_tag(&tag);
+    if tag.is_empty() {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "TagSubscription tag cannot be empty",
+        ))
+        .into());
+    }
+    if let Some(existing) = query_tag_subscriptions()?
+        .into_iter()
+        .find(|info| info.tag_subscription.tag == tag)
+    {
+        return Ok(existing);
+    }
+
+    let tag_subscription = TagSubscription { tag };
+    let tag_subscription_hash =
+        create_entry(&EntryTypes::TagSubscription(tag_subscription.clone()))?;
+    let record = get(tag_subscription_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from(
+            "Could not find the newly created TagSubscription",
+        )),
+    )?;
+    Ok(TagSubscriptionInfo {
+        tag_subscription_hash,
+        tag_subscription,
+        created_at: record.action().timestamp(),
+    })
+}
+
+#[hdk_extern]
+pub fn unsubscribe_from_tag(tag: String) -> ExternResult<()> {
+    trace_call!("unsubscribe_from_tag");
+    let tag = normalize_tag(&tag);
+    for info in query_tag_subscriptions()? {
+        if info.tag_subscription.tag == tag {
+            delete_entry(info.tag_subscription_hash)?;
+        }
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_tag_subscriptions(_: ()) -> ExternResult<Vec<TagSubscriptionInfo>> {
+    trace_call!("get_tag_subscriptions");
+    query_tag_subscriptions()
+}
+
+/// Notifies me about new shares from `domain` in the feeds I belong to. A
+/// full URL can be given in place of the domain. Subscribing to a domain
+/// twice returns the existing subscription.
+#[hdk_extern]
+pub fn subscribe_to_domain(domain: String) -> ExternResult<DomainSubscriptionInfo> {
+    trace_call!("subscribe_to_domain");
+    let domain = url_domain(domain.trim()).ok_or(SharefeedError::InvalidInput(String::from(
+        "DomainSubscription domain cannot be empty",
+    )))?;
+    if let Some(existing) = query_domain_subscriptions()?
+        .into_iter()
+        .find(|info| info.domain_subscription.domain == domain)
+    {
+        return Ok(existing);
+    }
+
+    let domain_subscription = DomainSubscription { domain };
+    let domain_subscription_hash =
+        create_entry(&EntryTypes::DomainSubscription(domain_subscription.clone()))?;
+    let record = get(domain_subscription_hash.clone(), GetOptions::local())?.ok_or(
+        SharefeedError::NotFound(String::from(
+            "Could not find the newly created DomainSubscription",
+        )),
+    )?;
+    Ok(DomainSubscriptionInfo {
+        domain_subscription_hash,
+        domain_subscription,
+        created_at: record.action().timestamp(),
+    })
+}
+
+#[hdk_extern]
+pub fn unsubscribe_from_domain(domain: String) -> ExternResult<()> {
+    trace_call!("unsubscribe_from_domain");
+    let Some(domain) = url_domain(domain.trim()) else {
+        return Ok(());
+    };
+    for info in query_domain_subscriptions()? {
+        if info.domain_subscription.domain == domain {
+            delete_entry(info.domain_subscription_hash)?;
+        }
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_domain_subscriptions(_: ()) -> ExternResult<Vec<DomainSubscriptionInfo>> {
+    trace_call!("get_domain_subscriptions");
+    query_domain_subscriptions()
+}
+
+#[hdk_extern(infallible)]
+pub fn scheduled_subscription_check(_: Option<Schedule>) -> Option<Schedule> {
+    trace_call!("scheduled_subscription_check");
+    if let Err(err) = check_subscriptions() {
+        debug!("Failed to check subscriptions: {:?}", err);
+    }
+    Some(Schedule::Persisted(SUBSCRIPTION_CHECK_SCHEDULE.to_string()))
+}
+
+/// Whether a share added to a feed at `info.created_at` matches a tag
+/// subscription made before then. The feed's default tags count.
+fn matches_tag_subscription(info: &ShareItemInfo, subscriptions: &[TagSubscriptionInfo]) -> bool {
+    let tags = normalize_tags(&info.share_item.tags);
+    subscriptions.iter().any(|subscription| {
+        subscription.created_at < info.created_at
+            && tags.contains(&subscription.tag_subscription.tag)
+    })
+}
+
+/// Whether a share added to a feed at `info.created_at` is from a domain
+/// subscribed to before then
+fn matches_domain_subscription(
+    info: &ShareItemInfo,
+    subscriptions: &[DomainSubscriptionInfo],
+) -> bool {
+    let Some(domain) = url_domain(&info.share_item.url) else {
+        return false;
+    };
+    subscriptions.iter().any(|subscription| {
+        subscription.created_at < info.created_at
+            && subscription.domain_subscription.domain == domain
+    })
+}
+
+/// Notifies me about shares added by others to the feeds I belong to that
+/// match one of my subscriptions. Shares already notified about are
+/// skipped, so each match is raised once, even when it matches both a tag
+/// and a domain.
+fn check_subscriptions() -> ExternResult<()> {
+    let tag_subscriptions = query_tag_subscriptions()?;
+    let domain_subscriptions = query_domain_subscriptions()?;
+    let Some(since) = tag_subscriptions
+        .iter()
+        .map(|info| info.created_at)
+        .chain(domain_subscriptions.iter().map(|info| info.created_at))
+        .min()
+    else {
+        return Ok(());
+    };
+    let me = agent_info()?.agent_initial_pubkey;
+    let mut notified: HashSet<ActionHash> = query_notifications()?
+        .into_iter()
+        .filter(|info| {
+            matches!(
+                info.notification.kind,
+                NotificationKind::TagSubscriptionMatch | NotificationKind::DomainSubscriptionMatch
+            )
+        })
+        .filter_map(|info| info.notification.share_item_hash)
+        .collect();
+
+    let mut raised = 0;
+    for feed in get_my_feeds(ReadInput::Plain(()))? {
+        let links: Vec<Link> = get_links(
+            LinkQuery::try_new(feed.action_hash.clone(), LinkTypes::FeedToShare)?,
+            ReadStrategy::Local.get_strategy(),
+        )?
+        .into_iter()
+        .filter(|link| link.timestamp > since && link.author != me)
+        .filter(|link| {
+            link.target
+                .clone()
+                .into_action_hash()
+                .is_some_and(|share_item_hash| !notified.contains(&share_item_hash))
+        })
+        .collect();
+        if links.is_empty() {
+            continue;
+        }
+        let share_items = with_feed_tags(
+            share_item_infos_from_links(links.clone(), ReadStrategy::Local)?,
+            &links,
+        );
+        for info in share_items {
+            if raised >= MAX_SUBSCRIPTION_MATCHES_PER_CHECK {
+                return Ok(());
+            }
+            let kind = if matches_tag_subscription(&info, &tag_subscriptions) {
+                NotificationKind::TagSubscriptionMatch
+            } else if matches_domain_subscription(&info, &domain_subscriptions) {
+                NotificationKind::DomainSubscriptionMatch
+            } else {
+                continue;
+            };
+            if !notified.insert(info.action_hash.clone()) {
+                continue;
+            }
+            create_notification(Notification {
+                kind,
+                from: info.author,
+                feed_hash: Some(feed.action_hash.clone()),
+                share_item_hash: Some(info.action_hash),
+                read: false,
+            })?;
+            raised += 1;
+        }
+    }
+    Ok(())
+}
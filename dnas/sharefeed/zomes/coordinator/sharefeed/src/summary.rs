@@ -0,0 +1,94 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::HashMap;
+
+use crate::follow::get_author_share_links;
+use crate::read::ReadInput;
+
+const TOP_TAGS_LIMIT: usize = 5;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct TagCount {
+    pub tag: String,
+    pub count: u32,
+}
+
+/// Aggregate shown on an agent's profile page
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct AgentSummary {
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub agent: AgentPubKey,
+    pub share_count: u32,
+    pub feeds_stewarded: u32,
+    pub feeds_joined: u32,
+    pub top_tags: Vec<TagCount>,
+}
+
+/// Builds the summary from the AuthorToShare and AgentToFeed indexes
+/// instead of walking the agent's source chain
+#[hdk_extern]
+pub fn get_agent_summary(agent: ReadInput<AgentPubKey>) -> ExternResult<AgentSummary> {
+    trace_call!("get_agent_summary");
+    let (agent, strategy) = agent.into_parts();
+    let share_links = get_author_share_links(agent.clone(), strategy)?;
+
+    let mut tag_counts: HashMap<String, u32> = HashMap::new();
+    for link in &share_links {
+        let action_hash =
+            ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(err))?;
+        if let Some(record) = get(action_hash, strategy.get_options())? {
+            if let Some(share_item) = record
+                .entry()
+                .to_app_option::<ShareItem>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                for tag in share_item.tags {
+                    *tag_counts.entry(tag.trim().to_lowercase()).or_default() += 1;
+                }
+            }
+        }
+    }
+    let mut top_tags: Vec<TagCount> = tag_counts
+        .into_iter()
+        .filter(|(tag, _)| !tag.is_empty())
+        .map(|(tag, count)| TagCount { tag, count })
+        .collect();
+    // Most used first, ties broken alphabetically so the order is stable
+    top_tags.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+    top_tags.truncate(TOP_TAGS_LIMIT);
+
+    let feed_links = get_links(
+        LinkQuery::try_new(agent.clone(), LinkTypes::AgentToFeed)?,
+        strategy.get_strategy(),
+    )?;
+    let mut feeds_stewarded = 0;
+    let mut feeds_joined = 0;
+    for link in feed_links {
+        let feed_hash = ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        if let Some(record) = get(feed_hash, strategy.get_options())? {
+            if let Some(feed) = record
+                .entry()
+                .to_app_option::<Feed>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                if feed.stewards.contains(&agent) {
+                    feeds_stewarded += 1;
+                } else {
+                    feeds_joined += 1;
+                }
+            }
+        }
+    }
+
+    Ok(AgentSummary {
+        agent,
+        share_count: share_links.len() as u32,
+        feeds_stewarded,
+        feeds_joined,
+        top_tags,
+    })
+}
@@ -0,0 +1,42 @@
+//! Entry/exit and timing logs for zome calls, compiled in only with the
+//! `tracing` feature
+
+/// Logs entry into the calling extern and, when the call returns, its
+/// duration. Expands to nothing without the `tracing` feature.
+macro_rules! trace_call {
+    ($name:expr) => {
+        #[cfg(feature = "tracing")]
+        let _call_timer = $crate::trace::CallTimer::start($name);
+    };
+}
+
+#[cfg(feature = "tracing")]
+pub struct CallTimer {
+    name: &'static str,
+    started_at: Option<hdk::prelude::Timestamp>,
+}
+
+#[cfg(feature = "tracing")]
+impl CallTimer {
+    pub fn start(name: &'static str) -> Self {
+        hdk::prelude::debug!("-> {}", name);
+        CallTimer {
+            name,
+            started_at: hdk::prelude::sys_time().ok(),
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for CallTimer {
+    fn drop(&mut self) {
+        match (self.started_at, hdk::prelude::sys_time()) {
+            (Some(started_at), Ok(now)) => hdk::prelude::debug!(
+                "<- {} ({} µs)",
+                self.name,
+                now.as_micros() - started_at.as_micros()
+            ),
+            _ => hdk::prelude::debug!("<- {}", self.name),
+        }
+    }
+}
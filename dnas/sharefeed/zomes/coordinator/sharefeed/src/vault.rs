@@ -0,0 +1,175 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+
+use crate::error::SharefeedError;
+use crate::share_item::get_share_item;
+
+/// Role of the optional long-term archive DNA in the hApp manifest
+pub const VAULT_ROLE: &str = "vault";
+
+/// Coordinator zome of the archive DNA that stores archived shares
+const VAULT_ZOME: &str = "vault";
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ArchiveToVaultInput {
+    /// Original action hash of the share to archive
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    /// Captured copy of the linked page, e.g. its readable text or HTML
+    pub snapshot: Option<String>,
+}
+
+/// What the vault DNA's `archive_share` receives
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct VaultShare {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    /// Latest version of the share
+    pub share_item: ShareItem,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub author: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub created_at: Timestamp,
+    pub snapshot: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct VaultArchiveInfo {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub vault_archive_hash: ActionHash,
+    pub vault_archive: VaultArchive,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub archived_at: Timestamp,
+}
+
+fn call_vault_zome<I, O>(fn_name: &str, payload: I) -> ExternResult<O>
+where
+    I: Serialize + std::fmt::Debug,
+    O: serde::de::DeserializeOwned + std::fmt::Debug,
+{
+    let response = call(
+        CallTargetCell::OtherRole(VAULT_ROLE.into()),
+        VAULT_ZOME,
+        fn_name.into(),
+        None,
+        payload,
+    )
+    .map_err(|err| {
+        SharefeedError::Unreachable(format!(
+            "No archive vault is installed alongside this DNA: {:?}",
+            err
+        ))
+    })?;
+    match response {
+        ZomeCallResponse::Ok(result) => result.decode().map_err(|e| wasm_error!(e)),
+        other => Err(SharefeedError::Unreachable(format!(
+            "Call to archive vault failed: {:?}",
+            other
+        ))
+        .into()),
+    }
+}
+
+fn query_vault_archives() -> ExternResult<Vec<VaultArchiveInfo>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::VaultArchive.try_into()?)
+            .include_entries(true),
+    )?;
+    let mut archives: Vec<VaultArchiveInfo> = Vec::new();
+    for record in records {
+        if let Some(vault_archive) = record
+            .entry()
+            .to_app_option::<VaultArchive>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            archives.push(VaultArchiveInfo {
+                vault_archive_hash: record.action_address().clone(),
+                vault_archive,
+                archived_at: record.action().timestamp(),
+            });
+        }
+    }
+    Ok(archives)
+}
+
+/// Copies a share, and optionally a snapshot of its page, into the
+/// long-term archive DNA so heavy content stays off the feed DHT. The
+/// vault's hash is recorded privately on my chain; archiving a share a
+/// second time returns the existing record.
+#[hdk_extern]
+pub fn archive_to_vault(input: ArchiveToVaultInput) -> ExternResult<VaultArchiveInfo> {
+    trace_call!("archive_to_vault");
+    if let Some(existing) = query_vault_archives()?
+        .into_iter()
+        .rev()
+        .find(|info| info.vault_archive.share_item_hash == input.share_item_hash)
+    {
+        return Ok(existing);
+    }
+
+    let original = get(input.share_item_hash.clone(), GetOptions::network())?
+        .ok_or(SharefeedError::NotFound(String::from("Share not found")))?;
+    let latest = get_share_item(input.share_item_hash.clone().into())?
+        .ok_or(SharefeedError::NotFound(String::from("Share not found")))?;
+    let share_item: ShareItem = latest
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(SharefeedError::NotFound(String::from(
+            "Record is not a ShareItem",
+        )))?;
+
+    let vault_hash: ActionHash = call_vault_zome(
+        "archive_share",
+        VaultShare {
+            share_item_hash: input.share_item_hash.clone(),
+            share_item,
+            author: original.action().author().clone(),
+            created_at: original.action().timestamp(),
+            snapshot: input.snapshot,
+        },
+    )?;
+
+    let vault_archive = VaultArchive {
+        share_item_hash: input.share_item_hash,
+        vault_hash,
+    };
+    let vault_archive_hash = create_entry(&EntryTypes::VaultArchive(vault_archive.clone()))?;
+    let record =
+        get(vault_archive_hash.clone(), GetOptions::local())?.ok_or(SharefeedError::NotFound(
+            String::from("Could not find the newly created VaultArchive"),
+        ))?;
+    Ok(VaultArchiveInfo {
+        vault_archive_hash,
+        vault_archive,
+        archived_at: record.action().timestamp(),
+    })
+}
+
+/// Where a share was archived in the vault, if I archived it
+#[hdk_extern]
+pub fn get_vault_archive(share_item_hash: ActionHash) -> ExternResult<Option<VaultArchiveInfo>> {
+    trace_call!("get_vault_archive");
+    Ok(query_vault_archives()?
+        .into_iter()
+        .rev()
+        .find(|info| info.vault_archive.share_item_hash == share_item_hash))
+}
+
+/// Every share I've archived to the vault, oldest first
+#[hdk_extern]
+pub fn get_my_vault_archives(_: ()) -> ExternResult<Vec<VaultArchiveInfo>> {
+    trace_call!("get_my_vault_archives");
+    query_vault_archives()
+}
@@ -0,0 +1,196 @@
+use hdk::prelude::*;
+use schemars::JsonSchema;
+use sharefeed_integrity::*;
+use std::collections::BTreeMap;
+
+use crate::error::SharefeedError;
+use crate::feed::{
+    add_share_to_feed, get_feed, get_feed_members, get_my_feeds, AddShareToFeedInput,
+};
+use crate::index::get_entry_for_action;
+use crate::read::ReadInput;
+use crate::search::{search_shares, SearchSharesInput};
+use crate::share_item::{create_share_item, get_share_item};
+
+/// Scheme prefix of a Weave Asset Locator, e.g. `weave-0.14://hrl/...`
+pub const WAL_SCHEME_PREFIX: &str = "weave-";
+
+const DEFAULT_ASSET_SEARCH_LIMIT: usize = 20;
+
+/// The kinds of asset ShareFeed offers to other Moss tools
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum AssetKind {
+    ShareItem,
+    Feed,
+}
+
+/// What Moss shows for an attached ShareFeed asset
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct AssetInfo {
+    pub kind: AssetKind,
+    pub name: String,
+    /// The share's favicon; the UI falls back to the ShareFeed icon
+    pub icon_src: Option<String>,
+    /// The link a share points at
+    pub url: Option<String>,
+}
+
+/// An asset matching a Moss search, turned into a WAL by the UI
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct AssetRef {
+    pub kind: AssetKind,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub hash: ActionHash,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SearchAssetsInput {
+    pub query: String,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct CreateShareFromWalInput {
+    /// Asset of another tool, as a WAL string
+    pub wal: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    /// Feed to add the new share to
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash | null"))]
+    #[schemars(with = "Option<ActionHashSchema>")]
+    pub target_feed: Option<ActionHash>,
+}
+
+/// Name and icon of a share or feed for Moss' asset previews. Private feeds
+/// are only described to their members.
+#[hdk_extern]
+pub fn get_asset_info(hash: ReadInput<ActionHash>) -> ExternResult<Option<AssetInfo>> {
+    trace_call!("get_asset_info");
+    let (hash, strategy) = hash.into_parts();
+    let read = ReadInput::WithStrategy {
+        input: hash.clone(),
+        strategy,
+    };
+    match get_entry_for_action(&hash)? {
+        Some(EntryTypes::ShareItem(_)) => {
+            let Some(record) = get_share_item(read)? else {
+                return Ok(None);
+            };
+            let share_item: Option<ShareItem> =
+                record.entry().to_app_option().map_err(|e| wasm_error!(e))?;
+            Ok(share_item.map(|share_item| AssetInfo {
+                kind: AssetKind::ShareItem,
+                name: share_item.title,
+                icon_src: share_item.favicon,
+                url: Some(share_item.url).filter(|url| !url.is_empty()),
+            }))
+        }
+        Some(EntryTypes::Feed(_)) => {
+            let Some(record) = get_feed(read)? else {
+                return Ok(None);
+            };
+            let Some(feed) = record
+                .entry()
+                .to_app_option::<Feed>()
+                .map_err(|e| wasm_error!(e))?
+            else {
+                return Ok(None);
+            };
+            if !feed.is_public
+                && !get_feed_members(hash.into())?.contains(&agent_info()?.agent_initial_pubkey)
+            {
+                return Ok(None);
+            }
+            Ok(Some(AssetInfo {
+                kind: AssetKind::Feed,
+                name: feed.name,
+                icon_src: None,
+                url: None,
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Moss' search hook: my feeds whose name contains the query, followed by
+/// shares matching it
+#[hdk_extern]
+pub fn search_assets(input: ReadInput<SearchAssetsInput>) -> ExternResult<Vec<AssetRef>> {
+    trace_call!("search_assets");
+    let (input, strategy) = input.into_parts();
+    let limit = input.limit.unwrap_or(DEFAULT_ASSET_SEARCH_LIMIT);
+    let needle = input.query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut assets: Vec<AssetRef> = get_my_feeds(ReadInput::WithStrategy {
+        input: (),
+        strategy,
+    })?
+    .into_iter()
+    .filter(|info| info.feed.name.to_lowercase().contains(&needle))
+    .map(|info| AssetRef {
+        kind: AssetKind::Feed,
+        hash: info.action_hash,
+    })
+    .take(limit)
+    .collect();
+
+    if assets.len() < limit {
+        let page = search_shares(ReadInput::WithStrategy {
+            input: SearchSharesInput {
+                query: input.query,
+                cursor: None,
+                limit: Some(limit - assets.len()),
+            },
+            strategy,
+        })?;
+        assets.extend(page.shares.into_iter().map(|info| AssetRef {
+            kind: AssetKind::ShareItem,
+            hash: info.action_hash,
+        }));
+    }
+    Ok(assets)
+}
+
+/// Shares an asset of another Moss tool, with its WAL as the share's URL
+#[hdk_extern]
+pub fn create_share_from_wal(input: CreateShareFromWalInput) -> ExternResult<Record> {
+    trace_call!("create_share_from_wal");
+    if !input.wal.starts_with(WAL_SCHEME_PREFIX) || !input.wal.contains("://") {
+        return Err(SharefeedError::InvalidInput(String::from(
+            "Expected a WAL such as weave-0.14://hrl/...",
+        ))
+        .into());
+    }
+    let record = create_share_item(ShareItem {
+        url: input.wal,
+        title: input.title,
+        description: input.description,
+        selection: None,
+        favicon: None,
+        thumbnail: None,
+        tags: input.tags,
+        provenance: None,
+        kind: ShareKind::Link,
+        body: None,
+        translations: BTreeMap::new(),
+        moderator_chain_heads: Vec::new(),
+    })?;
+    if let Some(feed_hash) = input.target_feed {
+        add_share_to_feed(AddShareToFeedInput {
+            feed_hash,
+            share_item_hash: record.action_address().clone(),
+        })?;
+    }
+    Ok(record)
+}
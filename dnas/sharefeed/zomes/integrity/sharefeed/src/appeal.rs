@@ -0,0 +1,86 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{must_get_entry, must_get_linked_entry, validate_entry_size, ActionHashSchema};
+
+/// A member's request that stewards reconsider a moderation action
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Appeal {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub moderation_action_hash: ActionHash,
+    pub reason: String,
+}
+
+pub fn validate_create_appeal(
+    _action: EntryCreationAction,
+    appeal: Appeal,
+) -> ExternResult<ValidateCallbackResult> {
+    if appeal.reason.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "An appeal must give a reason",
+        )));
+    }
+    let (_, moderation_action) = must_get_entry::<crate::ModerationAction>(
+        appeal.moderation_action_hash.clone(),
+        "Appeal must reference a ModerationAction entry",
+    )?;
+    if matches!(
+        moderation_action.kind,
+        crate::ModerationKind::DecideAppeal { .. }
+    ) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Decisions on appeals cannot be appealed again",
+        )));
+    }
+    validate_entry_size(appeal)
+}
+
+pub fn validate_update_appeal(
+    _action: Update,
+    _appeal: Appeal,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Appeals cannot be updated",
+    )))
+}
+
+pub fn validate_create_link_moderation_action_to_appeals(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, appeal) = must_get_linked_entry::<crate::Appeal>(
+        target_address,
+        "Linked action must reference an Appeal entry",
+    )?;
+    if AnyLinkableHash::from(appeal.moderation_action_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "ModerationActionToAppeals base must be the appealed ModerationAction",
+        )));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the appellant can file their appeal",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_moderation_action_to_appeals(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the appellant can withdraw an appeal",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,138 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{
+    must_get_chain_before, must_get_feed_stewards, must_get_linked_entry, validate_entry_size,
+    ActionHashSchema, AgentPubKeySchema, LinkTypes,
+};
+
+/// Longest reason accepted on a ban
+pub const MAX_BAN_REASON_CHARS: usize = 1000;
+
+/// A steward's ban of an agent from a feed. The ban is lifted by deleting
+/// its FeedToBans link.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Ban {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub agent: AgentPubKey,
+    pub reason: String,
+}
+
+/// Agents `author` banned from `feed_hash` on their chain up to
+/// `prev_action`, leaving out bans they lifted since. Bans by other
+/// stewards aren't on this chain, so those are enforced by the coordinator.
+pub fn must_get_bans_on_chain(
+    author: &AgentPubKey,
+    prev_action: &ActionHash,
+    feed_hash: &ActionHash,
+) -> ExternResult<Vec<AgentPubKey>> {
+    let feed_to_bans = ScopedLinkType::try_from(LinkTypes::FeedToBans)?;
+    let feed_address = AnyLinkableHash::from(feed_hash.clone());
+    let chain = must_get_chain_before(author, prev_action)?;
+
+    let mut bans: Vec<(ActionHash, AnyLinkableHash)> = Vec::new();
+    let mut lifted: Vec<ActionHash> = Vec::new();
+    for action_hashed in chain {
+        match action_hashed.content {
+            Action::CreateLink(create_link)
+                if create_link.zome_index == feed_to_bans.zome_index
+                    && create_link.link_type == feed_to_bans.zome_type
+                    && create_link.base_address == feed_address =>
+            {
+                bans.push((action_hashed.hash, create_link.target_address));
+            }
+            Action::DeleteLink(delete_link) => lifted.push(delete_link.link_add_address),
+            _ => {}
+        }
+    }
+
+    let mut banned: Vec<AgentPubKey> = Vec::new();
+    for (link_hash, ban_address) in bans {
+        if lifted.contains(&link_hash) {
+            continue;
+        }
+        let (_, ban) =
+            must_get_linked_entry::<Ban>(ban_address, "Linked action must reference a Ban entry")?;
+        banned.push(ban.agent);
+    }
+    Ok(banned)
+}
+
+pub fn validate_create_ban(
+    action: EntryCreationAction,
+    ban: Ban,
+) -> ExternResult<ValidateCallbackResult> {
+    let stewards = must_get_feed_stewards(ban.feed_hash.clone())?;
+    if !stewards.contains(action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can ban its members",
+        )));
+    }
+    if stewards.contains(&ban.agent) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Stewards cannot be banned from their feed",
+        )));
+    }
+    if ban.reason.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A ban must give a reason",
+        )));
+    }
+    if ban.reason.chars().count() > MAX_BAN_REASON_CHARS {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Ban reason cannot exceed {} characters",
+            MAX_BAN_REASON_CHARS
+        )));
+    }
+    validate_entry_size(ban)
+}
+
+pub fn validate_update_ban(_action: Update, _ban: Ban) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Bans cannot be updated",
+    )))
+}
+
+pub fn validate_create_link_feed_to_bans(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, ban) =
+        must_get_linked_entry::<Ban>(target_address, "Linked action must reference a Ban entry")?;
+    if AnyLinkableHash::from(ban.feed_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "FeedToBans base must be the Feed the agent is banned from",
+        )));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the steward who issued a ban can link it",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_feed_to_bans(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (_, ban) =
+        must_get_linked_entry::<Ban>(target, "Linked action must reference a Ban entry")?;
+    if !must_get_feed_stewards(ban.feed_hash)?.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can lift a ban",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
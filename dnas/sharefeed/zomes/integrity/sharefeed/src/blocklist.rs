@@ -0,0 +1,43 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{ActionHashSchema, AgentPubKeySchema};
+
+/// Private list of agents whose content I don't want to see. Blocked
+/// agents additionally can't reach me through signals or notifications.
+/// Muted shares no longer notify me about their comments and reactions.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, Default, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Blocklist {
+    #[cfg_attr(feature = "ts", ts(type = "Array<AgentPubKey>"))]
+    #[schemars(with = "Vec<AgentPubKeySchema>")]
+    pub blocked: Vec<AgentPubKey>,
+    #[cfg_attr(feature = "ts", ts(type = "Array<AgentPubKey>"))]
+    #[schemars(with = "Vec<AgentPubKeySchema>")]
+    pub muted: Vec<AgentPubKey>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "Array<ActionHash>"))]
+    #[schemars(with = "Vec<ActionHashSchema>")]
+    pub muted_shares: Vec<ActionHash>,
+}
+
+impl Blocklist {
+    pub fn hides(&self, agent: &AgentPubKey) -> bool {
+        self.blocked.contains(agent) || self.muted.contains(agent)
+    }
+}
+
+pub fn validate_create_blocklist(
+    _action: EntryCreationAction,
+    _blocklist: Blocklist,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_blocklist(
+    _action: Update,
+    _blocklist: Blocklist,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,135 @@
+//! Links every agent's `init` writes: its entry in the agent directory,
+//! the path tree down to the current week's time index and, on a
+//! progenitor's first run, the network's home feed.
+
+use hdi::prelude::*;
+
+use crate::{must_get_linked_entry, sharefeed_properties, Feed};
+
+/// Anchor every agent links itself from on its first zome call
+pub const AGENT_DIRECTORY_ANCHOR: &str = "agents";
+
+/// Anchor the network's home feed is linked from
+pub const HOME_FEED_ANCHOR: &str = "home_feed";
+
+pub fn agent_directory_path() -> Path {
+    Path::from(AGENT_DIRECTORY_ANCHOR)
+}
+
+pub fn home_feed_path() -> Path {
+    Path::from(HOME_FEED_ANCHOR)
+}
+
+pub fn validate_create_link_agent_directory(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if AnyLinkableHash::from(agent_directory_path().path_entry_hash()?) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "AgentDirectory base must be the agent directory anchor",
+        )));
+    }
+    if AnyLinkableHash::from(action.author) != target_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Agents can only list themselves in the directory",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_agent_directory(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the agent can take itself out of the directory",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_home_feed(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if AnyLinkableHash::from(home_feed_path().path_entry_hash()?) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "HomeFeed base must be the home feed anchor",
+        )));
+    }
+    let properties = sharefeed_properties()?;
+    if properties.home_feed.is_none() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "This network has no home feed",
+        )));
+    }
+    if !properties.is_progenitor(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only a progenitor can set the network's home feed",
+        )));
+    }
+    let (record, _) =
+        must_get_linked_entry::<Feed>(target_address, "Linked action must reference a Feed entry")?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "The home feed must be created by the progenitor who links it",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_home_feed(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the progenitor who linked it can unlink the home feed",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// A link between two components of a time index path. The tag holds the
+/// child component, as `TypedPath::ensure` writes it.
+pub fn validate_create_link_time_path(
+    _action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if EntryHash::try_from(target_address).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "TimePath target must be a path entry",
+        )));
+    }
+    if tag.0.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "TimePath links must name the path component they lead to",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_time_path(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "TimePath links cannot be deleted",
+    )))
+}
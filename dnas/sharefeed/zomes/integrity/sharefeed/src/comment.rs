@@ -0,0 +1,93 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{must_get_entry, must_get_linked_entry, validate_entry_size, ActionHashSchema};
+
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Comment {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    pub content: String,
+}
+
+pub fn validate_create_comment(
+    _action: EntryCreationAction,
+    comment: Comment,
+) -> ExternResult<ValidateCallbackResult> {
+    if !crate::sharefeed_properties()?.features.comments {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Comments are turned off on this network",
+        )));
+    }
+    if comment.content.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Comment content cannot be empty".to_string(),
+        ));
+    }
+    must_get_entry::<crate::ShareItem>(
+        comment.share_item_hash,
+        "Comment must reference a ShareItem entry",
+    )?;
+    validate_entry_size(comment)
+}
+
+pub fn validate_update_comment(
+    action: Update,
+    comment: Comment,
+) -> ExternResult<ValidateCallbackResult> {
+    let original_record = must_get_valid_record(action.original_action_address)?;
+    if original_record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a comment can edit it".to_string(),
+        ));
+    }
+    let original_comment: Comment = original_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Updated action must reference a Comment entry"
+        ))))?;
+    if original_comment.share_item_hash != comment.share_item_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A comment cannot be moved to another share".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_share_item_to_comments(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (_, comment) = must_get_linked_entry::<crate::Comment>(
+        target_address,
+        "Linked action must reference a Comment entry",
+    )?;
+    if AnyLinkableHash::from(comment.share_item_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "ShareItemToComments base must be the commented ShareItem",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_share_item_to_comments(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author of a comment can remove it",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,168 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{
+    must_get_entry, must_get_feed_stewards, must_get_linked_entry, validate_entry_size,
+    ActionHashSchema,
+};
+
+/// Most shares a compilation may list
+pub const MAX_COMPILATION_SHARES: usize = 50;
+
+/// A steward's newsletter-style roundup of a week's shares in a feed
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Compilation {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub title: String,
+    /// Introduction shown above the shares
+    pub note: Option<String>,
+    /// ISO week the compilation covers
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub year: i64,
+    pub week: u32,
+    /// Original action hashes of the shares, in the order they are presented
+    #[cfg_attr(feature = "ts", ts(type = "Array<ActionHash>"))]
+    #[schemars(with = "Vec<ActionHashSchema>")]
+    pub share_item_hashes: Vec<ActionHash>,
+}
+
+pub fn validate_create_compilation(
+    action: EntryCreationAction,
+    compilation: Compilation,
+) -> ExternResult<ValidateCallbackResult> {
+    if !must_get_feed_stewards(compilation.feed_hash.clone())?.contains(action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can compile its shares",
+        )));
+    }
+    if compilation.title.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Compilation title cannot be empty",
+        )));
+    }
+    if !(1..=53).contains(&compilation.week) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Compilation week must be between 1 and 53",
+        )));
+    }
+    if compilation.share_item_hashes.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A compilation must include at least one share",
+        )));
+    }
+    if compilation.share_item_hashes.len() > MAX_COMPILATION_SHARES {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "A compilation can include at most {} shares",
+            MAX_COMPILATION_SHARES
+        )));
+    }
+    for (index, share_item_hash) in compilation.share_item_hashes.iter().enumerate() {
+        if compilation.share_item_hashes[..index].contains(share_item_hash) {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "A compilation can include a share only once",
+            )));
+        }
+        must_get_entry::<crate::ShareItem>(
+            share_item_hash.clone(),
+            "Compilation must reference ShareItem entries",
+        )?;
+    }
+    validate_entry_size(compilation)
+}
+
+pub fn validate_update_compilation(
+    _action: Update,
+    _compilation: Compilation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Compilations cannot be updated",
+    )))
+}
+
+pub fn validate_create_link_feed_to_compilations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, compilation) = must_get_linked_entry::<Compilation>(
+        target_address,
+        "Linked action must reference a Compilation entry",
+    )?;
+    if AnyLinkableHash::from(compilation.feed_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "FeedToCompilations base must be the compiled Feed",
+        )));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the steward who compiled it can link a compilation",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_feed_to_compilations(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (_, compilation) = must_get_linked_entry::<Compilation>(
+        target,
+        "Linked action must reference a Compilation entry",
+    )?;
+    if !must_get_feed_stewards(compilation.feed_hash)?.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can remove its compilations",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_share_item_to_compilations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, compilation) = must_get_linked_entry::<Compilation>(
+        target_address,
+        "Linked action must reference a Compilation entry",
+    )?;
+    if !compilation
+        .share_item_hashes
+        .into_iter()
+        .any(|share_item_hash| AnyLinkableHash::from(share_item_hash) == base_address)
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "ShareItemToCompilations base must be a share the compilation includes",
+        )));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the steward who compiled it can link a compilation",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_share_item_to_compilations(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the steward who compiled it can unlink a compilation",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
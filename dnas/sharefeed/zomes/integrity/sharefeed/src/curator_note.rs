@@ -0,0 +1,94 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{
+    must_get_entry, must_get_feed_stewards, must_get_linked_entry, validate_entry_size,
+    ActionHashSchema,
+};
+
+/// Editorial context a steward attaches to a share in their feed, e.g.
+/// "paywalled, see the archive link"
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct CuratorNote {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    pub content: String,
+}
+
+pub fn validate_create_curator_note(
+    action: EntryCreationAction,
+    curator_note: CuratorNote,
+) -> ExternResult<ValidateCallbackResult> {
+    if !must_get_feed_stewards(curator_note.feed_hash.clone())?.contains(action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can add curator notes to it",
+        )));
+    }
+    if curator_note.content.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Curator note content cannot be empty",
+        )));
+    }
+    must_get_entry::<crate::ShareItem>(
+        curator_note.share_item_hash.clone(),
+        "CuratorNote must reference a ShareItem entry",
+    )?;
+    validate_entry_size(curator_note)
+}
+
+pub fn validate_update_curator_note(
+    _action: Update,
+    _curator_note: CuratorNote,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Curator notes cannot be updated",
+    )))
+}
+
+pub fn validate_create_link_share_item_to_curator_notes(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, curator_note) = must_get_linked_entry::<CuratorNote>(
+        target_address,
+        "Linked action must reference a CuratorNote entry",
+    )?;
+    if AnyLinkableHash::from(curator_note.share_item_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "ShareItemToCuratorNotes base must be the annotated ShareItem",
+        )));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the steward who wrote it can link a curator note",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_share_item_to_curator_notes(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (_, curator_note) = must_get_linked_entry::<CuratorNote>(
+        target,
+        "Linked action must reference a CuratorNote entry",
+    )?;
+    if !must_get_feed_stewards(curator_note.feed_hash)?.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can remove its curator notes",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
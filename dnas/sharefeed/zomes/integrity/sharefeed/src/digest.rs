@@ -0,0 +1,51 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{ActionHashSchema, TimestampSchema};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct DigestFeedSection {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub feed_name: String,
+    #[cfg_attr(feature = "ts", ts(type = "Array<ActionHash>"))]
+    #[schemars(with = "Vec<ActionHashSchema>")]
+    pub share_item_hashes: Vec<ActionHash>,
+}
+
+/// Private summary of the shares posted to my feeds over a period
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Digest {
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub period_start: Timestamp,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub period_end: Timestamp,
+    pub sections: Vec<DigestFeedSection>,
+}
+
+pub fn validate_create_digest(
+    _action: EntryCreationAction,
+    digest: Digest,
+) -> ExternResult<ValidateCallbackResult> {
+    if digest.period_start > digest.period_end {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Digest period cannot end before it starts".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_digest(
+    _action: Update,
+    _digest: Digest,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Digests cannot be updated".to_string(),
+    ))
+}
@@ -1,12 +1,189 @@
 use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{
+    must_get_chain_before, must_get_entry, must_get_feed_stewards, must_get_linked_entry,
+    must_get_signers_on_chain, normalize_tag, normalize_tags, validate_entry_size,
+    AgentPubKeySchema, LinkTypes, ShareItem, StewardProposalKind,
+};
 
 #[hdk_entry_helper]
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct Feed {
     pub name: String,
     pub description: Option<String>,
+    #[cfg_attr(feature = "ts", ts(type = "Array<AgentPubKey>"))]
+    #[schemars(with = "Vec<AgentPubKeySchema>")]
     pub stewards: Vec<AgentPubKey>,
     pub is_public: bool,
+    /// Shares added by members who aren't stewards wait in the moderation
+    /// queue until a steward approves them
+    #[serde(default)]
+    pub requires_approval: bool,
+    /// Terms that hold or keep out shares added by members who aren't
+    /// stewards
+    #[serde(default)]
+    pub keyword_filters: Vec<KeywordFilter>,
+    /// Members may publish which shares they've seen, for feeds small
+    /// enough to show them
+    #[serde(default)]
+    pub seen_receipts: bool,
+    /// Tags merged into every share added to the feed. They're recorded on
+    /// the FeedToShare link, so the share itself is left as posted.
+    #[serde(default)]
+    pub default_tags: Vec<String>,
+    /// Existing members are told whenever someone joins. Welcome in small
+    /// feeds, noise in large ones.
+    #[serde(default)]
+    pub announce_new_members: bool,
+    /// How many stewards, the proposer included, must sign off on a
+    /// `StewardProposal` before it runs. Defaults to
+    /// `DEFAULT_STEWARD_QUORUM`, capped at the number of stewards. Set when
+    /// the feed is created and can't be changed. Making the feed public
+    /// always needs every steward.
+    #[serde(default)]
+    pub steward_quorum: Option<u32>,
+}
+
+/// Most default tags a feed may have
+pub const MAX_FEED_DEFAULT_TAGS: usize = 10;
+
+/// Stewards who must sign off on a proposal when the feed doesn't set
+/// its own quorum
+pub const DEFAULT_STEWARD_QUORUM: u32 = 2;
+
+/// Most shares added by others a steward may take out of one feed in a
+/// day on their own. Beyond that, removals need a `RemoveShares` proposal,
+/// so a single steward key can't empty the feed link by link.
+pub const MAX_SHARE_REMOVALS_PER_DAY: usize = 20;
+
+/// Span over which `MAX_SHARE_REMOVALS_PER_DAY` is counted
+pub const SHARE_REMOVAL_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+/// What happens to a share that contains a filtered keyword
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum KeywordFilterAction {
+    /// Wait in the moderation queue for a steward
+    Hold,
+    /// Can't be added to the feed at all
+    Reject,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct KeywordFilter {
+    /// Matched case-insensitively anywhere in the share's URL, title,
+    /// description, selection or tags
+    pub keyword: String,
+    pub action: KeywordFilterAction,
+}
+
+impl KeywordFilter {
+    pub fn matches(&self, share_item: &ShareItem) -> bool {
+        let keyword = self.keyword.trim().to_lowercase();
+        if keyword.is_empty() {
+            return false;
+        }
+        [
+            Some(&share_item.url),
+            Some(&share_item.title),
+            share_item.description.as_ref(),
+            share_item.selection.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(share_item.tags.iter())
+        .any(|text| text.to_lowercase().contains(&keyword))
+    }
+}
+
+impl Feed {
+    fn distinct_steward_count(&self) -> usize {
+        let mut stewards: Vec<&AgentPubKey> = self.stewards.iter().collect();
+        stewards.sort();
+        stewards.dedup();
+        stewards.len()
+    }
+
+    /// How many stewards must sign off on a `StewardProposal`, see
+    /// `steward_quorum`
+    pub fn required_signers(&self) -> usize {
+        let quorum = self.steward_quorum.unwrap_or(DEFAULT_STEWARD_QUORUM) as usize;
+        quorum.clamp(1, self.distinct_steward_count().max(1))
+    }
+
+    /// Whether destructive actions need other stewards to approve them
+    /// through a `StewardProposal`
+    pub fn needs_steward_approval(&self) -> bool {
+        self.required_signers() > 1
+    }
+
+    /// Checks that a quorum the feed sets can be met by its stewards
+    pub fn check_steward_quorum(&self) -> Result<(), String> {
+        let Some(quorum) = self.steward_quorum else {
+            return Ok(());
+        };
+        let stewards = self.distinct_steward_count();
+        if quorum == 0 || quorum as usize > stewards {
+            return Err(format!(
+                "Feed steward quorum must be between 1 and its {} stewards",
+                stewards
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks the feed's default tags against `MAX_FEED_DEFAULT_TAGS` and
+    /// that none are blank
+    pub fn check_default_tags(&self) -> Result<(), String> {
+        if self.default_tags.len() > MAX_FEED_DEFAULT_TAGS {
+            return Err(format!(
+                "Feed can have at most {} default tags",
+                MAX_FEED_DEFAULT_TAGS
+            ));
+        }
+        if self
+            .default_tags
+            .iter()
+            .any(|tag| normalize_tag(tag).is_empty())
+        {
+            return Err(String::from("Feed default tags cannot be empty"));
+        }
+        Ok(())
+    }
+
+    /// A share's own tags followed by the feed's default tags, normalized
+    pub fn tags_for_share(&self, share_item: &ShareItem) -> Vec<String> {
+        let tags: Vec<String> = share_item
+            .tags
+            .iter()
+            .chain(self.default_tags.iter())
+            .cloned()
+            .collect();
+        normalize_tags(&tags)
+    }
+
+    /// The strictest action of the keyword filters a share trips, if any
+    pub fn keyword_filter_action(&self, share_item: &ShareItem) -> Option<KeywordFilterAction> {
+        let tripped: Vec<&KeywordFilter> = self
+            .keyword_filters
+            .iter()
+            .filter(|filter| filter.matches(share_item))
+            .collect();
+        if tripped
+            .iter()
+            .any(|filter| filter.action == KeywordFilterAction::Reject)
+        {
+            Some(KeywordFilterAction::Reject)
+        } else if tripped.is_empty() {
+            None
+        } else {
+            Some(KeywordFilterAction::Hold)
+        }
+    }
 }
 
 pub fn validate_create_feed(
@@ -25,21 +202,120 @@ pub fn validate_create_feed(
             "Feed must have at least one steward".to_string(),
         ));
     }
-    Ok(ValidateCallbackResult::Valid)
+    if feed
+        .keyword_filters
+        .iter()
+        .any(|filter| filter.keyword.trim().is_empty())
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Feed keyword filters cannot be empty".to_string(),
+        ));
+    }
+    if let Err(reason) = feed.check_default_tags() {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    if let Err(reason) = feed.check_steward_quorum() {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    validate_entry_size(feed)
 }
 
-pub fn validate_update_feed(
-    _action: Update,
-    _feed: Feed,
-) -> ExternResult<ValidateCallbackResult> {
+/// The hash of the Feed as first created, following updates back from
+/// any revision
+pub fn must_get_original_feed_hash(feed_hash: ActionHash) -> ExternResult<ActionHash> {
+    let mut feed_hash = feed_hash;
+    while let Action::Update(update) = must_get_action(feed_hash.clone())?.action() {
+        feed_hash = update.original_action_address.clone();
+    }
+    Ok(feed_hash)
+}
+
+pub fn validate_update_feed(action: Update, feed: Feed) -> ExternResult<ValidateCallbackResult> {
+    if let Err(reason) = feed.check_steward_quorum() {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    let (_, previous_feed) = must_get_entry::<Feed>(
+        action.original_action_address.clone(),
+        "Updated action must reference a Feed entry",
+    )?;
+    // Stewards are checked against the revision being updated, so nobody
+    // can write themselves in, or the others out, on the way to a change
+    if !previous_feed.stewards.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can update it",
+        )));
+    }
+    if previous_feed
+        .stewards
+        .iter()
+        .any(|steward| steward != &action.author && !feed.stewards.contains(steward))
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Stewards can step down, but can't remove other stewards",
+        )));
+    }
+    // Proposals read the quorum from the feed as created, so it's fixed
+    if feed.steward_quorum != previous_feed.steward_quorum {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A feed's steward quorum can't be changed",
+        )));
+    }
+    // Publishing a members-only feed needs the consent of every steward of
+    // the revision being updated, given to a proposal naming that revision
+    if !previous_feed.is_public
+        && feed.is_public
+        && previous_feed
+            .stewards
+            .iter()
+            .any(|steward| steward != &action.author)
+    {
+        let signers = must_get_signers_on_chain(
+            &action.author,
+            &action.prev_action,
+            &must_get_original_feed_hash(action.original_action_address)?,
+            |kind| {
+                matches!(kind, StewardProposalKind::MakePublic { revision }
+                    if revision == &action.original_action_address)
+            },
+        )?;
+        if !previous_feed
+            .stewards
+            .iter()
+            .all(|steward| signers.contains(steward))
+        {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "Making a members-only feed public needs every steward's approval",
+            )));
+        }
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_delete_feed(
-    _action: Delete,
+    action: Delete,
     _original_action: EntryCreationAction,
-    _original_feed: Feed,
+    original_feed: Feed,
 ) -> ExternResult<ValidateCallbackResult> {
+    if !original_feed.stewards.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can delete it",
+        )));
+    }
+    if !original_feed.needs_steward_approval() {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let signers = must_get_signers_on_chain(
+        &action.author,
+        &action.prev_action,
+        &action.deletes_address,
+        |kind| kind == &StewardProposalKind::DeleteFeed,
+    )?;
+    if signers.len() < original_feed.required_signers() {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Deleting this feed needs the approval of {} stewards",
+            original_feed.required_signers()
+        )));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -49,24 +325,8 @@ pub fn validate_create_link_feed_updates(
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::try_from(base_address).map_err(|err| wasm_error!(err))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _feed: crate::Feed = record
-        .entry()
-        .to_app_option()
-        .map_err(|e| wasm_error!(e))?
-        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
-            "Linked action must reference an entry"
-        ))))?;
-    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(err))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _feed: crate::Feed = record
-        .entry()
-        .to_app_option()
-        .map_err(|e| wasm_error!(e))?
-        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
-            "Linked action must reference an entry"
-        ))))?;
+    must_get_linked_entry::<crate::Feed>(base_address, "Linked action must reference an entry")?;
+    must_get_linked_entry::<crate::Feed>(target_address, "Linked action must reference an entry")?;
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -84,49 +344,194 @@ pub fn validate_delete_link_feed_updates(
 
 // Feed membership link validations
 pub fn validate_create_link_feed_to_share(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, share_item) = must_get_linked_entry::<crate::ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    if crate::is_duplicate_link(&action)? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "This share is already in the feed",
+        )));
+    }
+    // Only the feed as created can be reached deterministically here, so its
+    // filters are enforced; filters added by later updates are enforced by
+    // the coordinator
+    let (_, feed) = must_get_linked_entry::<crate::Feed>(
+        base_address,
+        "Link base must reference a Feed entry",
+    )?;
+    if !feed.stewards.contains(&action.author) && feed.keyword_filter_action(&share_item).is_some()
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "This share matches one of the feed's keyword filters",
+        )));
+    }
+    // A steward can't put back shares of someone they banned
+    let feed_hash =
+        ActionHash::try_from(action.base_address.clone()).map_err(|err| wasm_error!(err))?;
+    if crate::must_get_bans_on_chain(&action.author, &action.prev_action, &feed_hash)?
+        .contains(record.action().author())
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "The author of this share is banned from the feed",
+        )));
+    }
+    let moderator_chain_heads = crate::SharefeedLinkTag::from_link_tag(&tag)
+        .map(|link_tag| link_tag.moderator_chain_heads)
+        .unwrap_or_default();
+    if let ValidateCallbackResult::Invalid(reason) = crate::validate_network_blocklist(
+        &action.author,
+        &action.prev_action,
+        &moderator_chain_heads,
+        &[&action.author, record.action().author()],
+        Some(&share_item.url),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    Ok(crate::validate_share_link_summary(
+        &tag,
+        &share_item,
+        record.action().author(),
+    ))
+}
+
+/// How many FeedToShare links authored by others `action`'s author deleted
+/// from the same feed in the day before it
+fn count_recent_share_removals(action: &DeleteLink) -> ExternResult<usize> {
+    let feed_to_share = ScopedLinkType::try_from(LinkTypes::FeedToShare)?;
+    let window_start = Timestamp::from_micros(
+        action.timestamp.as_micros() - SHARE_REMOVAL_WINDOW_SECS * 1_000_000,
+    );
+    let mut removals = 0;
+    for action_hashed in must_get_chain_before(&action.author, &action.prev_action)? {
+        let Action::DeleteLink(delete_link) = &action_hashed.content else {
+            continue;
+        };
+        if delete_link.base_address != action.base_address || delete_link.timestamp < window_start {
+            continue;
+        }
+        if let Action::CreateLink(create_link) =
+            must_get_action(delete_link.link_add_address.clone())?.action()
+        {
+            if create_link.zome_index == feed_to_share.zome_index
+                && create_link.link_type == feed_to_share.zome_type
+                && create_link.author != action.author
+            {
+                removals += 1;
+            }
+        }
+    }
+    Ok(removals)
+}
+
+/// Whoever added a share can take it out again. Stewards of the feed can
+/// take out anyone's, up to `MAX_SHARE_REMOVALS_PER_DAY` a day unless a
+/// `RemoveShares` proposal naming the share reached the feed's quorum.
+pub fn validate_delete_link_feed_to_share(
+    action: DeleteLink,
+    original_action: CreateLink,
+    base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author == original_action.author {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let feed_hash = ActionHash::try_from(base).map_err(|err| wasm_error!(err))?;
+    let (_, feed) =
+        must_get_entry::<Feed>(feed_hash.clone(), "Link base must reference a Feed entry")?;
+    if !feed.stewards.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the member who added a share or a steward can remove it from a feed",
+        )));
+    }
+    if !feed.needs_steward_approval()
+        || count_recent_share_removals(&action)? < MAX_SHARE_REMOVALS_PER_DAY
+    {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let share_item_hash = ActionHash::try_from(target).map_err(|err| wasm_error!(err))?;
+    let signers = must_get_signers_on_chain(
+        &action.author,
+        &action.prev_action,
+        &feed_hash,
+        |kind| match kind {
+            StewardProposalKind::RemoveShares { share_item_hashes } => {
+                share_item_hashes.contains(&share_item_hash)
+            }
+            _ => false,
+        },
+    )?;
+    if signers.len() < feed.required_signers() {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Stewards can remove at most {} shares added by others from a feed per day \
+             without a RemoveShares proposal",
+            MAX_SHARE_REMOVALS_PER_DAY
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_share_item_to_feed(
     _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(err))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _share_item: crate::ShareItem = record
-        .entry()
-        .to_app_option()
-        .map_err(|e| wasm_error!(e))?
-        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
-            "Linked action must reference a ShareItem entry"
-        ))))?;
+    must_get_linked_entry::<crate::ShareItem>(
+        base_address,
+        "Link base must reference a ShareItem entry",
+    )?;
+    must_get_linked_entry::<crate::Feed>(
+        target_address,
+        "Linked action must reference a Feed entry",
+    )?;
     Ok(ValidateCallbackResult::Valid)
 }
 
-pub fn validate_delete_link_feed_to_share(
-    _action: DeleteLink,
-    _original_action: CreateLink,
+/// Removed alongside the FeedToShare link it mirrors, so by the member who
+/// added the share or a steward of the feed
+pub fn validate_delete_link_share_item_to_feed(
+    action: DeleteLink,
+    original_action: CreateLink,
     _base: AnyLinkableHash,
-    _target: AnyLinkableHash,
+    target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // Allow deleting FeedToShare links (this is how we remove shares from feeds)
+    if action.author == original_action.author {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let feed_hash = ActionHash::try_from(target).map_err(|err| wasm_error!(err))?;
+    if !must_get_feed_stewards(feed_hash)?.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the member who added a share or a steward can remove it from a feed",
+        )));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_create_link_agent_to_feed(
-    _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    action: CreateLink,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(err))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _feed: crate::Feed = record
-        .entry()
-        .to_app_option()
-        .map_err(|e| wasm_error!(e))?
-        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
-            "Linked action must reference a Feed entry"
-        ))))?;
+    // Agents only list feeds under their own key, so nobody can push a
+    // feed into someone else's "my feeds"
+    if base_address != AnyLinkableHash::from(action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "AgentToFeed base must be the link author",
+        )));
+    }
+    must_get_linked_entry::<crate::Feed>(
+        target_address,
+        "Linked action must reference a Feed entry",
+    )?;
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -140,22 +545,98 @@ pub fn validate_delete_link_agent_to_feed(
     Ok(ValidateCallbackResult::Valid)
 }
 
+/// Members are added by a steward of the feed as created, or add
+/// themselves to a feed that was created public. Stewards named only by
+/// later revisions, and feeds made public later, can't be reached here, so
+/// those are left to the coordinator.
+///
+/// Bans are looked up on the chain of every steward, up to the chain heads
+/// the link's tag names for the others. Bans issued after the head an
+/// author names are enforced by the coordinator.
 pub fn validate_create_link_feed_to_member(
-    _action: CreateLink,
-    _base_address: AnyLinkableHash,
-    _target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // Target should be an AgentPubKey
+    let Ok(member) = AgentPubKey::try_from(target_address) else {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "FeedToMember target must be an AgentPubKey",
+        )));
+    };
+    let (record, feed) = must_get_linked_entry::<crate::Feed>(
+        base_address,
+        "Link base must reference a Feed entry",
+    )?;
+    let is_steward = feed.stewards.contains(&action.author);
+    if !is_steward && !(member == action.author && feed.is_public) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards can add members to a feed, unless it's public",
+        )));
+    }
+    if crate::is_duplicate_link(&action)? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "This agent is already a member of the feed",
+        )));
+    }
+    // Stewards can't be banned, which also covers the links create_feed
+    // makes before any steward could have acted on the feed
+    if feed.stewards.contains(&member) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let chain_heads = crate::SharefeedLinkTag::from_link_tag(&tag)
+        .map(|link_tag| link_tag.steward_chain_heads)
+        .unwrap_or_default();
+    let mut checked: Vec<&AgentPubKey> = Vec::new();
+    for steward in &feed.stewards {
+        if checked.contains(&steward) {
+            continue;
+        }
+        checked.push(steward);
+        let chain_head = if steward == &action.author {
+            action.prev_action.clone()
+        } else {
+            let mut found = None;
+            for chain_head in &chain_heads {
+                if must_get_action(chain_head.clone())?.action().author() == steward {
+                    found = Some(chain_head.clone());
+                    break;
+                }
+            }
+            let Some(chain_head) = found else {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "FeedToMember tag must name a chain head for every steward",
+                )));
+            };
+            chain_head
+        };
+        if crate::must_get_bans_on_chain(steward, &chain_head, record.action_address())?
+            .contains(&member)
+        {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "This agent is banned from the feed",
+            )));
+        }
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_delete_link_feed_to_member(
-    _action: DeleteLink,
+    action: DeleteLink,
     _original_action: CreateLink,
-    _base: AnyLinkableHash,
-    _target: AnyLinkableHash,
+    base: AnyLinkableHash,
+    target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
+    // Anyone may leave a feed, stewards or not
+    if target == AnyLinkableHash::from(action.author.clone()) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let feed_hash = ActionHash::try_from(base).map_err(|err| wasm_error!(err))?;
+    if !must_get_feed_stewards(feed_hash)?.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can remove its other members",
+        )));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
@@ -0,0 +1,75 @@
+use hdi::prelude::*;
+
+use crate::must_get_linked_entry;
+
+pub fn validate_create_link_author_to_share(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if base_address != AnyLinkableHash::from(action.author.clone()) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "AuthorToShare base must be the link author",
+        )));
+    }
+    let (record, _) = must_get_linked_entry::<crate::ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "AuthorToShare can only index your own shares",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_author_to_share(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author can remove their share index links",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_agent_to_following(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if base_address != AnyLinkableHash::from(action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "You can only follow agents from your own pubkey",
+        )));
+    }
+    if AgentPubKey::try_from(target_address).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "AgentToFollowing target must be an agent",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_agent_to_following(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the follower can unfollow",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
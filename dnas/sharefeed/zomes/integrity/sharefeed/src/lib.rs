@@ -2,9 +2,80 @@ pub mod share_item;
 pub use share_item::*;
 pub mod feed;
 pub use feed::*;
+pub mod notification;
+pub use notification::*;
+pub mod mention;
+pub use mention::*;
+pub mod notification_prefs;
+pub use notification_prefs::*;
+pub mod digest;
+pub use digest::*;
+pub mod comment;
+pub use comment::*;
+pub mod reaction;
+pub use reaction::*;
+pub mod follow;
+pub use follow::*;
+pub mod blocklist;
+pub use blocklist::*;
+pub mod properties;
+pub use properties::*;
+pub mod network_blocklist;
+pub use network_blocklist::*;
+pub mod reputation;
+pub use reputation::*;
+pub mod membership;
+pub use membership::*;
+pub mod link_summary;
+pub use link_summary::*;
+pub mod link_dedup;
+pub use link_dedup::*;
+pub mod link_tag;
+pub use link_tag::*;
+pub mod validation;
+pub use validation::*;
+pub mod search;
+pub use search::*;
+pub mod saved_search;
+pub use saved_search::*;
+pub mod vault;
+pub use vault::*;
+pub mod saved_item;
+pub use saved_item::*;
+pub mod report;
+pub use report::*;
+pub mod moderation;
+pub use moderation::*;
+pub mod appeal;
+pub use appeal::*;
+pub mod ban;
+pub use ban::*;
+pub mod bootstrap;
+pub use bootstrap::*;
+pub mod tags;
+pub use tags::*;
+pub mod opens;
+pub use opens::*;
+pub mod seen;
+pub use seen::*;
+pub mod compilation;
+pub use compilation::*;
+pub mod curator_note;
+pub use curator_note::*;
+pub mod subscription;
+pub use subscription::*;
+pub mod steward_proposal;
+pub use steward_proposal::*;
+pub mod onboarding;
+pub use onboarding::*;
+pub mod schema;
+pub use schema::*;
 
 use hdi::prelude::*;
 
+/// Version of this integrity zome, from its crate
+pub const INTEGRITY_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[hdk_entry_types]
@@ -12,6 +83,41 @@ use hdi::prelude::*;
 pub enum EntryTypes {
     ShareItem(ShareItem),
     Feed(Feed),
+    #[entry_type(visibility = "private")]
+    Notification(Notification),
+    #[entry_type(visibility = "private")]
+    NotificationPrefs(NotificationPrefs),
+    #[entry_type(visibility = "private")]
+    Digest(Digest),
+    Comment(Comment),
+    Reaction(Reaction),
+    #[entry_type(visibility = "private")]
+    Blocklist(Blocklist),
+    NetworkBlocklist(NetworkBlocklist),
+    #[entry_type(visibility = "private")]
+    ReputationCache(ReputationCache),
+    #[entry_type(visibility = "private")]
+    MembershipRequest(MembershipRequest),
+    #[entry_type(visibility = "private")]
+    SavedSearch(SavedSearch),
+    #[entry_type(visibility = "private")]
+    VaultArchive(VaultArchive),
+    Report(Report),
+    ModerationAction(ModerationAction),
+    Appeal(Appeal),
+    Ban(Ban),
+    Compilation(Compilation),
+    #[entry_type(visibility = "private")]
+    SavedItem(SavedItem),
+    CuratorNote(CuratorNote),
+    #[entry_type(visibility = "private")]
+    TagSubscription(TagSubscription),
+    #[entry_type(visibility = "private")]
+    DomainSubscription(DomainSubscription),
+    StewardProposal(StewardProposal),
+    StewardApproval(StewardApproval),
+    #[entry_type(visibility = "private")]
+    OnboardingState(OnboardingState),
 }
 
 #[derive(Serialize, Deserialize)]
@@ -26,8 +132,77 @@ pub enum LinkTypes {
 
     // Feed membership
     FeedToShare,
+    ShareItemToFeed,
     AgentToFeed,
     FeedToMember,
+
+    // Agents mentioned in a share
+    AgentToMention,
+
+    // Discussion on a share
+    ShareItemToComments,
+    ShareItemToReactions,
+
+    // Per-author share index and follows
+    AuthorToShare,
+    AgentToFollowing,
+
+    // Network moderation, from the "network_blocklist" path
+    NetworkBlocklists,
+
+    // Normalized URL anchor to every share of that URL
+    UrlToShare,
+
+    // Full-text search, from "words.<shard>.<word>" anchors
+    WordToShare,
+
+    // Fuzzy title search, from "trigrams.<trigram>" anchors
+    TrigramToShare,
+
+    // Tag uses per time bucket, from "tags.<year>.<week>" anchors
+    TagTimeIndex,
+
+    // URL anchors shared per time bucket, from "shared_urls.<year>.<week>"
+    UrlTimeIndex,
+
+    // Member reports on shares, for the feed's stewards
+    FeedToReports,
+
+    // Steward decisions in a feed, and shares waiting for one
+    FeedToModerationActions,
+    FeedToPendingShare,
+
+    // Appeals filed against a moderation action
+    ModerationActionToAppeals,
+
+    // Agents banned from a feed, by their Ban entry
+    FeedToBans,
+
+    // Every agent, from the "agents" anchor
+    AgentDirectory,
+
+    // Path tree of the "shares.<year>.<week>" time index
+    TimePath,
+
+    // Agents who opened a share, one link each
+    ShareItemToOpens,
+
+    // Shares a member has marked as seen in a feed, authored by the member
+    FeedToSeenReceipts,
+
+    // Weekly compilations of a feed, and the ones a share is included in
+    FeedToCompilations,
+    ShareItemToCompilations,
+
+    // Steward notes on a share, each scoped to one of their feeds
+    ShareItemToCuratorNotes,
+
+    // Destructive actions a steward proposed on a feed, and their approvals
+    FeedToStewardProposals,
+    StewardProposalToApprovals,
+
+    // Network home feed anchor -> the feed a progenitor created for it
+    HomeFeed,
 }
 
 #[hdk_extern]
@@ -53,6 +228,108 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 EntryTypes::Feed(feed) => {
                     validate_create_feed(EntryCreationAction::Create(action), feed)
                 }
+                EntryTypes::Notification(notification) => {
+                    validate_create_notification(EntryCreationAction::Create(action), notification)
+                }
+                EntryTypes::NotificationPrefs(notification_prefs) => {
+                    validate_create_notification_prefs(
+                        EntryCreationAction::Create(action),
+                        notification_prefs,
+                    )
+                }
+                EntryTypes::Digest(digest) => {
+                    validate_create_digest(EntryCreationAction::Create(action), digest)
+                }
+                EntryTypes::Comment(comment) => {
+                    validate_create_comment(EntryCreationAction::Create(action), comment)
+                }
+                EntryTypes::Reaction(reaction) => {
+                    validate_create_reaction(EntryCreationAction::Create(action), reaction)
+                }
+                EntryTypes::Blocklist(blocklist) => {
+                    validate_create_blocklist(EntryCreationAction::Create(action), blocklist)
+                }
+                EntryTypes::NetworkBlocklist(network_blocklist) => {
+                    validate_create_network_blocklist(
+                        EntryCreationAction::Create(action),
+                        network_blocklist,
+                    )
+                }
+                EntryTypes::ReputationCache(reputation_cache) => {
+                    validate_create_reputation_cache(
+                        EntryCreationAction::Create(action),
+                        reputation_cache,
+                    )
+                }
+                EntryTypes::MembershipRequest(membership_request) => {
+                    validate_create_membership_request(
+                        EntryCreationAction::Create(action),
+                        membership_request,
+                    )
+                }
+                EntryTypes::SavedSearch(saved_search) => {
+                    validate_create_saved_search(EntryCreationAction::Create(action), saved_search)
+                }
+                EntryTypes::VaultArchive(vault_archive) => {
+                    validate_create_vault_archive(
+                        EntryCreationAction::Create(action),
+                        vault_archive,
+                    )
+                }
+                EntryTypes::Report(report) => {
+                    validate_create_report(EntryCreationAction::Create(action), report)
+                }
+                EntryTypes::ModerationAction(moderation_action) => {
+                    validate_create_moderation_action(
+                        EntryCreationAction::Create(action),
+                        moderation_action,
+                    )
+                }
+                EntryTypes::Appeal(appeal) => {
+                    validate_create_appeal(EntryCreationAction::Create(action), appeal)
+                }
+                EntryTypes::Ban(ban) => {
+                    validate_create_ban(EntryCreationAction::Create(action), ban)
+                }
+                EntryTypes::Compilation(compilation) => {
+                    validate_create_compilation(EntryCreationAction::Create(action), compilation)
+                }
+                EntryTypes::SavedItem(saved_item) => {
+                    validate_create_saved_item(EntryCreationAction::Create(action), saved_item)
+                }
+                EntryTypes::CuratorNote(curator_note) => {
+                    validate_create_curator_note(EntryCreationAction::Create(action), curator_note)
+                }
+                EntryTypes::TagSubscription(tag_subscription) => {
+                    validate_create_tag_subscription(
+                        EntryCreationAction::Create(action),
+                        tag_subscription,
+                    )
+                }
+                EntryTypes::DomainSubscription(domain_subscription) => {
+                    validate_create_domain_subscription(
+                        EntryCreationAction::Create(action),
+                        domain_subscription,
+                    )
+                }
+                EntryTypes::StewardProposal(steward_proposal) => {
+                    validate_create_steward_proposal(
+                        EntryCreationAction::Create(action),
+                        steward_proposal,
+                    )
+                }
+                EntryTypes::StewardApproval(steward_approval) => {
+                    validate_create_steward_approval(
+                        EntryCreationAction::Create(action),
+                        steward_approval,
+                    )
+                }
+                EntryTypes::OnboardingState(onboarding_state) => {
+                    validate_create_onboarding_state(
+                        EntryCreationAction::Create(action),
+                        onboarding_state,
+                    )
+                }
             },
             OpEntry::UpdateEntry { app_entry, action, .. } => match app_entry {
                 EntryTypes::ShareItem(share_item) => {
@@ -61,6 +338,108 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 EntryTypes::Feed(feed) => {
                     validate_create_feed(EntryCreationAction::Update(action), feed)
                 }
+                EntryTypes::Notification(notification) => {
+                    validate_create_notification(EntryCreationAction::Update(action), notification)
+                }
+                EntryTypes::NotificationPrefs(notification_prefs) => {
+                    validate_create_notification_prefs(
+                        EntryCreationAction::Update(action),
+                        notification_prefs,
+                    )
+                }
+                EntryTypes::Digest(digest) => {
+                    validate_create_digest(EntryCreationAction::Update(action), digest)
+                }
+                EntryTypes::Comment(comment) => {
+                    validate_create_comment(EntryCreationAction::Update(action), comment)
+                }
+                EntryTypes::Reaction(reaction) => {
+                    validate_create_reaction(EntryCreationAction::Update(action), reaction)
+                }
+                EntryTypes::Blocklist(blocklist) => {
+                    validate_create_blocklist(EntryCreationAction::Update(action), blocklist)
+                }
+                EntryTypes::NetworkBlocklist(network_blocklist) => {
+                    validate_create_network_blocklist(
+                        EntryCreationAction::Update(action),
+                        network_blocklist,
+                    )
+                }
+                EntryTypes::ReputationCache(reputation_cache) => {
+                    validate_create_reputation_cache(
+                        EntryCreationAction::Update(action),
+                        reputation_cache,
+                    )
+                }
+                EntryTypes::MembershipRequest(membership_request) => {
+                    validate_create_membership_request(
+                        EntryCreationAction::Update(action),
+                        membership_request,
+                    )
+                }
+                EntryTypes::SavedSearch(saved_search) => {
+                    validate_create_saved_search(EntryCreationAction::Update(action), saved_search)
+                }
+                EntryTypes::VaultArchive(vault_archive) => {
+                    validate_create_vault_archive(
+                        EntryCreationAction::Update(action),
+                        vault_archive,
+                    )
+                }
+                EntryTypes::Report(report) => {
+                    validate_create_report(EntryCreationAction::Update(action), report)
+                }
+                EntryTypes::ModerationAction(moderation_action) => {
+                    validate_create_moderation_action(
+                        EntryCreationAction::Update(action),
+                        moderation_action,
+                    )
+                }
+                EntryTypes::Appeal(appeal) => {
+                    validate_create_appeal(EntryCreationAction::Update(action), appeal)
+                }
+                EntryTypes::Ban(ban) => {
+                    validate_create_ban(EntryCreationAction::Update(action), ban)
+                }
+                EntryTypes::Compilation(compilation) => {
+                    validate_create_compilation(EntryCreationAction::Update(action), compilation)
+                }
+                EntryTypes::SavedItem(saved_item) => {
+                    validate_create_saved_item(EntryCreationAction::Update(action), saved_item)
+                }
+                EntryTypes::CuratorNote(curator_note) => {
+                    validate_create_curator_note(EntryCreationAction::Update(action), curator_note)
+                }
+                EntryTypes::TagSubscription(tag_subscription) => {
+                    validate_create_tag_subscription(
+                        EntryCreationAction::Update(action),
+                        tag_subscription,
+                    )
+                }
+                EntryTypes::DomainSubscription(domain_subscription) => {
+                    validate_create_domain_subscription(
+                        EntryCreationAction::Update(action),
+                        domain_subscription,
+                    )
+                }
+                EntryTypes::StewardProposal(steward_proposal) => {
+                    validate_create_steward_proposal(
+                        EntryCreationAction::Update(action),
+                        steward_proposal,
+                    )
+                }
+                EntryTypes::StewardApproval(steward_approval) => {
+                    validate_create_steward_approval(
+                        EntryCreationAction::Update(action),
+                        steward_approval,
+                    )
+                }
+                EntryTypes::OnboardingState(onboarding_state) => {
+                    validate_create_onboarding_state(
+                        EntryCreationAction::Update(action),
+                        onboarding_state,
+                    )
+                }
             },
             _ => Ok(ValidateCallbackResult::Valid),
         },
@@ -68,10 +447,111 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             OpUpdate::Entry { app_entry, action } => match app_entry {
                 EntryTypes::ShareItem(share_item) => validate_update_share_item(action, share_item),
                 EntryTypes::Feed(feed) => validate_update_feed(action, feed),
+                EntryTypes::Notification(notification) => {
+                    validate_update_notification(action, notification)
+                }
+                EntryTypes::NotificationPrefs(notification_prefs) => {
+                    validate_update_notification_prefs(action, notification_prefs)
+                }
+                EntryTypes::Digest(digest) => validate_update_digest(action, digest),
+                EntryTypes::Comment(comment) => validate_update_comment(action, comment),
+                EntryTypes::Reaction(reaction) => validate_update_reaction(action, reaction),
+                EntryTypes::Blocklist(blocklist) => validate_update_blocklist(action, blocklist),
+                EntryTypes::NetworkBlocklist(network_blocklist) => {
+                    validate_update_network_blocklist(action, network_blocklist)
+                }
+                EntryTypes::ReputationCache(reputation_cache) => {
+                    validate_update_reputation_cache(action, reputation_cache)
+                }
+                EntryTypes::MembershipRequest(membership_request) => {
+                    validate_update_membership_request(action, membership_request)
+                }
+                EntryTypes::SavedSearch(saved_search) => {
+                    validate_update_saved_search(action, saved_search)
+                }
+                EntryTypes::VaultArchive(vault_archive) => {
+                    validate_update_vault_archive(action, vault_archive)
+                }
+                EntryTypes::Report(report) => validate_update_report(action, report),
+                EntryTypes::ModerationAction(moderation_action) => {
+                    validate_update_moderation_action(action, moderation_action)
+                }
+                EntryTypes::Appeal(appeal) => validate_update_appeal(action, appeal),
+                EntryTypes::Ban(ban) => validate_update_ban(action, ban),
+                EntryTypes::Compilation(compilation) => {
+                    validate_update_compilation(action, compilation)
+                }
+                EntryTypes::SavedItem(saved_item) => validate_update_saved_item(action, saved_item),
+                EntryTypes::CuratorNote(curator_note) => {
+                    validate_update_curator_note(action, curator_note)
+                }
+                EntryTypes::TagSubscription(tag_subscription) => {
+                    validate_update_tag_subscription(action, tag_subscription)
+                }
+                EntryTypes::DomainSubscription(domain_subscription) => {
+                    validate_update_domain_subscription(action, domain_subscription)
+                }
+                EntryTypes::StewardProposal(steward_proposal) => {
+                    validate_update_steward_proposal(action, steward_proposal)
+                }
+                EntryTypes::StewardApproval(steward_approval) => {
+                    validate_update_steward_approval(action, steward_approval)
+                }
+                EntryTypes::OnboardingState(onboarding_state) => {
+                    validate_update_onboarding_state(action, onboarding_state)
+                }
             },
             _ => Ok(ValidateCallbackResult::Valid),
         },
-        FlatOp::RegisterDelete(_delete_entry) => Ok(ValidateCallbackResult::Valid),
+        FlatOp::RegisterDelete(delete_entry) => {
+            let original_record =
+                must_get_valid_record(delete_entry.action.deletes_address.clone())?;
+            let original_action = match original_record.action().clone() {
+                Action::Create(create) => EntryCreationAction::Create(create),
+                Action::Update(update) => EntryCreationAction::Update(update),
+                _ => {
+                    return Ok(ValidateCallbackResult::Invalid(String::from(
+                        "Original action for a delete must be a Create or Update action",
+                    )));
+                }
+            };
+            let EntryType::App(app_entry_def) = original_action.entry_type() else {
+                return Ok(ValidateCallbackResult::Valid);
+            };
+            // Private entries never leave their author's chain
+            let Some(entry) = original_record.entry().as_option() else {
+                return Ok(ValidateCallbackResult::Valid);
+            };
+            match EntryTypes::deserialize_from_type(
+                app_entry_def.zome_index,
+                app_entry_def.entry_index,
+                entry,
+            )? {
+                Some(EntryTypes::Feed(original_feed)) => {
+                    validate_delete_feed(delete_entry.action, original_action, original_feed)
+                }
+                Some(EntryTypes::ShareItem(original_share_item)) => validate_delete_share_item(
+                    delete_entry.action,
+                    original_action,
+                    original_share_item,
+                ),
+                _ => Ok(ValidateCallbackResult::Valid),
+            }
+        }
+        // Every link created in this DNA carries a tag in a known format,
+        // except TimePath links, whose tag is the path component
+        // `TypedPath::ensure` writes
+        FlatOp::RegisterCreateLink {
+            ref link_type,
+            ref tag,
+            ..
+        } if !matches!(link_type, LinkTypes::TimePath)
+            && SharefeedLinkTag::from_link_tag(tag).is_none() =>
+        {
+            Ok(ValidateCallbackResult::Invalid(String::from(
+                "Link tag must be a SharefeedLinkTag",
+            )))
+        }
         FlatOp::RegisterCreateLink {
             link_type,
             base_address,
@@ -79,7 +559,9 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             tag,
             action,
         } => match link_type {
-            LinkTypes::TimeIndex => Ok(ValidateCallbackResult::Valid),
+            LinkTypes::TimeIndex => {
+                validate_create_link_time_index(action, base_address, target_address, tag)
+            }
             LinkTypes::ShareItemUpdates => validate_create_link_share_item_updates(
                 action,
                 base_address,
@@ -98,6 +580,121 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             LinkTypes::FeedToMember => {
                 validate_create_link_feed_to_member(action, base_address, target_address, tag)
             }
+            LinkTypes::AgentToMention => {
+                validate_create_link_agent_to_mention(action, base_address, target_address, tag)
+            }
+            LinkTypes::ShareItemToComments => validate_create_link_share_item_to_comments(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ShareItemToReactions => validate_create_link_share_item_to_reactions(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AuthorToShare => {
+                validate_create_link_author_to_share(action, base_address, target_address, tag)
+            }
+            LinkTypes::AgentToFollowing => {
+                validate_create_link_agent_to_following(action, base_address, target_address, tag)
+            }
+            LinkTypes::NetworkBlocklists => {
+                validate_create_link_network_blocklists(action, base_address, target_address, tag)
+            }
+            LinkTypes::ShareItemToFeed => {
+                validate_create_link_share_item_to_feed(action, base_address, target_address, tag)
+            }
+            LinkTypes::UrlToShare => {
+                validate_create_link_url_to_share(action, base_address, target_address, tag)
+            }
+            LinkTypes::WordToShare => {
+                validate_create_link_word_to_share(action, base_address, target_address, tag)
+            }
+            LinkTypes::TrigramToShare => {
+                validate_create_link_trigram_to_share(action, base_address, target_address, tag)
+            }
+            LinkTypes::TagTimeIndex => {
+                validate_create_link_tag_time_index(action, base_address, target_address, tag)
+            }
+            LinkTypes::UrlTimeIndex => {
+                validate_create_link_url_time_index(action, base_address, target_address, tag)
+            }
+            LinkTypes::FeedToReports => {
+                validate_create_link_feed_to_reports(action, base_address, target_address, tag)
+            }
+            LinkTypes::FeedToModerationActions => validate_create_link_feed_to_moderation_actions(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::FeedToPendingShare => validate_create_link_feed_to_pending_share(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ModerationActionToAppeals => {
+                validate_create_link_moderation_action_to_appeals(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::FeedToBans => {
+                validate_create_link_feed_to_bans(action, base_address, target_address, tag)
+            }
+            LinkTypes::AgentDirectory => {
+                validate_create_link_agent_directory(action, base_address, target_address, tag)
+            }
+            LinkTypes::TimePath => {
+                validate_create_link_time_path(action, base_address, target_address, tag)
+            }
+            LinkTypes::ShareItemToOpens => {
+                validate_create_link_share_item_to_opens(action, base_address, target_address, tag)
+            }
+            LinkTypes::FeedToSeenReceipts => validate_create_link_feed_to_seen_receipts(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::FeedToCompilations => {
+                validate_create_link_feed_to_compilations(action, base_address, target_address, tag)
+            }
+            LinkTypes::ShareItemToCompilations => validate_create_link_share_item_to_compilations(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ShareItemToCuratorNotes => validate_create_link_share_item_to_curator_notes(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::FeedToStewardProposals => validate_create_link_feed_to_steward_proposals(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::StewardProposalToApprovals => {
+                validate_create_link_steward_proposal_to_approvals(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::HomeFeed => {
+                validate_create_link_home_feed(action, base_address, target_address, tag)
+            }
         },
         FlatOp::RegisterDeleteLink {
             link_type,
@@ -143,6 +740,199 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 target_address,
                 tag,
             ),
+            LinkTypes::AgentToMention => validate_delete_link_agent_to_mention(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ShareItemToComments => validate_delete_link_share_item_to_comments(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ShareItemToReactions => validate_delete_link_share_item_to_reactions(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AuthorToShare => validate_delete_link_author_to_share(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToFollowing => validate_delete_link_agent_to_following(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::NetworkBlocklists => validate_delete_link_network_blocklists(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ShareItemToFeed => validate_delete_link_share_item_to_feed(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::UrlToShare => validate_delete_link_url_to_share(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::WordToShare => validate_delete_link_word_to_share(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::TrigramToShare => validate_delete_link_trigram_to_share(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::TagTimeIndex => validate_delete_link_tag_time_index(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::UrlTimeIndex => validate_delete_link_url_time_index(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::FeedToReports => validate_delete_link_feed_to_reports(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::FeedToModerationActions => validate_delete_link_feed_to_moderation_actions(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::FeedToPendingShare => validate_delete_link_feed_to_pending_share(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ModerationActionToAppeals => {
+                validate_delete_link_moderation_action_to_appeals(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::FeedToBans => validate_delete_link_feed_to_bans(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentDirectory => validate_delete_link_agent_directory(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::TimePath => validate_delete_link_time_path(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ShareItemToOpens => validate_delete_link_share_item_to_opens(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::FeedToSeenReceipts => validate_delete_link_feed_to_seen_receipts(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::FeedToCompilations => validate_delete_link_feed_to_compilations(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ShareItemToCompilations => validate_delete_link_share_item_to_compilations(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ShareItemToCuratorNotes => validate_delete_link_share_item_to_curator_notes(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::FeedToStewardProposals => validate_delete_link_feed_to_steward_proposals(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::StewardProposalToApprovals => {
+                validate_delete_link_steward_proposal_to_approvals(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::HomeFeed => validate_delete_link_home_feed(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
         },
         FlatOp::StoreRecord(store_record) => match store_record {
             OpRecord::CreateEntry { app_entry, action } => match app_entry {
@@ -152,6 +942,108 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 EntryTypes::Feed(feed) => {
                     validate_create_feed(EntryCreationAction::Create(action), feed)
                 }
+                EntryTypes::Notification(notification) => {
+                    validate_create_notification(EntryCreationAction::Create(action), notification)
+                }
+                EntryTypes::NotificationPrefs(notification_prefs) => {
+                    validate_create_notification_prefs(
+                        EntryCreationAction::Create(action),
+                        notification_prefs,
+                    )
+                }
+                EntryTypes::Digest(digest) => {
+                    validate_create_digest(EntryCreationAction::Create(action), digest)
+                }
+                EntryTypes::Comment(comment) => {
+                    validate_create_comment(EntryCreationAction::Create(action), comment)
+                }
+                EntryTypes::Reaction(reaction) => {
+                    validate_create_reaction(EntryCreationAction::Create(action), reaction)
+                }
+                EntryTypes::Blocklist(blocklist) => {
+                    validate_create_blocklist(EntryCreationAction::Create(action), blocklist)
+                }
+                EntryTypes::NetworkBlocklist(network_blocklist) => {
+                    validate_create_network_blocklist(
+                        EntryCreationAction::Create(action),
+                        network_blocklist,
+                    )
+                }
+                EntryTypes::ReputationCache(reputation_cache) => {
+                    validate_create_reputation_cache(
+                        EntryCreationAction::Create(action),
+                        reputation_cache,
+                    )
+                }
+                EntryTypes::MembershipRequest(membership_request) => {
+                    validate_create_membership_request(
+                        EntryCreationAction::Create(action),
+                        membership_request,
+                    )
+                }
+                EntryTypes::SavedSearch(saved_search) => {
+                    validate_create_saved_search(EntryCreationAction::Create(action), saved_search)
+                }
+                EntryTypes::VaultArchive(vault_archive) => {
+                    validate_create_vault_archive(
+                        EntryCreationAction::Create(action),
+                        vault_archive,
+                    )
+                }
+                EntryTypes::Report(report) => {
+                    validate_create_report(EntryCreationAction::Create(action), report)
+                }
+                EntryTypes::ModerationAction(moderation_action) => {
+                    validate_create_moderation_action(
+                        EntryCreationAction::Create(action),
+                        moderation_action,
+                    )
+                }
+                EntryTypes::Appeal(appeal) => {
+                    validate_create_appeal(EntryCreationAction::Create(action), appeal)
+                }
+                EntryTypes::Ban(ban) => {
+                    validate_create_ban(EntryCreationAction::Create(action), ban)
+                }
+                EntryTypes::Compilation(compilation) => {
+                    validate_create_compilation(EntryCreationAction::Create(action), compilation)
+                }
+                EntryTypes::SavedItem(saved_item) => {
+                    validate_create_saved_item(EntryCreationAction::Create(action), saved_item)
+                }
+                EntryTypes::CuratorNote(curator_note) => {
+                    validate_create_curator_note(EntryCreationAction::Create(action), curator_note)
+                }
+                EntryTypes::TagSubscription(tag_subscription) => {
+                    validate_create_tag_subscription(
+                        EntryCreationAction::Create(action),
+                        tag_subscription,
+                    )
+                }
+                EntryTypes::DomainSubscription(domain_subscription) => {
+                    validate_create_domain_subscription(
+                        EntryCreationAction::Create(action),
+                        domain_subscription,
+                    )
+                }
+                EntryTypes::StewardProposal(steward_proposal) => {
+                    validate_create_steward_proposal(
+                        EntryCreationAction::Create(action),
+                        steward_proposal,
+                    )
+                }
+                EntryTypes::StewardApproval(steward_approval) => {
+                    validate_create_steward_approval(
+                        EntryCreationAction::Create(action),
+                        steward_approval,
+                    )
+                }
+                EntryTypes::OnboardingState(onboarding_state) => {
+                    validate_create_onboarding_state(
+                        EntryCreationAction::Create(action),
+                        onboarding_state,
+                    )
+                }
             },
             OpRecord::UpdateEntry {
                 app_entry, action, ..
@@ -162,6 +1054,108 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 EntryTypes::Feed(feed) => {
                     validate_create_feed(EntryCreationAction::Update(action), feed)
                 }
+                EntryTypes::Notification(notification) => {
+                    validate_create_notification(EntryCreationAction::Update(action), notification)
+                }
+                EntryTypes::NotificationPrefs(notification_prefs) => {
+                    validate_create_notification_prefs(
+                        EntryCreationAction::Update(action),
+                        notification_prefs,
+                    )
+                }
+                EntryTypes::Digest(digest) => {
+                    validate_create_digest(EntryCreationAction::Update(action), digest)
+                }
+                EntryTypes::Comment(comment) => {
+                    validate_create_comment(EntryCreationAction::Update(action), comment)
+                }
+                EntryTypes::Reaction(reaction) => {
+                    validate_create_reaction(EntryCreationAction::Update(action), reaction)
+                }
+                EntryTypes::Blocklist(blocklist) => {
+                    validate_create_blocklist(EntryCreationAction::Update(action), blocklist)
+                }
+                EntryTypes::NetworkBlocklist(network_blocklist) => {
+                    validate_create_network_blocklist(
+                        EntryCreationAction::Update(action),
+                        network_blocklist,
+                    )
+                }
+                EntryTypes::ReputationCache(reputation_cache) => {
+                    validate_create_reputation_cache(
+                        EntryCreationAction::Update(action),
+                        reputation_cache,
+                    )
+                }
+                EntryTypes::MembershipRequest(membership_request) => {
+                    validate_create_membership_request(
+                        EntryCreationAction::Update(action),
+                        membership_request,
+                    )
+                }
+                EntryTypes::SavedSearch(saved_search) => {
+                    validate_create_saved_search(EntryCreationAction::Update(action), saved_search)
+                }
+                EntryTypes::VaultArchive(vault_archive) => {
+                    validate_create_vault_archive(
+                        EntryCreationAction::Update(action),
+                        vault_archive,
+                    )
+                }
+                EntryTypes::Report(report) => {
+                    validate_create_report(EntryCreationAction::Update(action), report)
+                }
+                EntryTypes::ModerationAction(moderation_action) => {
+                    validate_create_moderation_action(
+                        EntryCreationAction::Update(action),
+                        moderation_action,
+                    )
+                }
+                EntryTypes::Appeal(appeal) => {
+                    validate_create_appeal(EntryCreationAction::Update(action), appeal)
+                }
+                EntryTypes::Ban(ban) => {
+                    validate_create_ban(EntryCreationAction::Update(action), ban)
+                }
+                EntryTypes::Compilation(compilation) => {
+                    validate_create_compilation(EntryCreationAction::Update(action), compilation)
+                }
+                EntryTypes::SavedItem(saved_item) => {
+                    validate_create_saved_item(EntryCreationAction::Update(action), saved_item)
+                }
+                EntryTypes::CuratorNote(curator_note) => {
+                    validate_create_curator_note(EntryCreationAction::Update(action), curator_note)
+                }
+                EntryTypes::TagSubscription(tag_subscription) => {
+                    validate_create_tag_subscription(
+                        EntryCreationAction::Update(action),
+                        tag_subscription,
+                    )
+                }
+                EntryTypes::DomainSubscription(domain_subscription) => {
+                    validate_create_domain_subscription(
+                        EntryCreationAction::Update(action),
+                        domain_subscription,
+                    )
+                }
+                EntryTypes::StewardProposal(steward_proposal) => {
+                    validate_create_steward_proposal(
+                        EntryCreationAction::Update(action),
+                        steward_proposal,
+                    )
+                }
+                EntryTypes::StewardApproval(steward_approval) => {
+                    validate_create_steward_approval(
+                        EntryCreationAction::Update(action),
+                        steward_approval,
+                    )
+                }
+                EntryTypes::OnboardingState(onboarding_state) => {
+                    validate_create_onboarding_state(
+                        EntryCreationAction::Update(action),
+                        onboarding_state,
+                    )
+                }
             },
             OpRecord::DeleteEntry { .. } => Ok(ValidateCallbackResult::Valid),
             OpRecord::CreateLink { .. } => Ok(ValidateCallbackResult::Valid),
@@ -191,6 +1185,10 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     )),
                 }
             }
+            OpActivity::CreateEntry {
+                action,
+                app_entry_type: Some(UnitEntryTypes::ShareItem),
+            } => validate_share_item_rate(action),
             _ => Ok(ValidateCallbackResult::Valid),
         },
     }
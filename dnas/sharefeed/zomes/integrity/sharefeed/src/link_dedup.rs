@@ -0,0 +1,31 @@
+use hdi::prelude::*;
+
+use crate::must_get_chain_before;
+
+/// Whether the author already has a live link with the same type, base and
+/// target earlier on their chain. Tags aren't compared, since the chain
+/// heads they carry move on between writes. Only the author's own chain is
+/// consulted, so this stays deterministic; links by different authors
+/// can't be compared at validation time.
+pub fn is_duplicate_link(action: &CreateLink) -> ExternResult<bool> {
+    let chain = must_get_chain_before(&action.author, &action.prev_action)?;
+
+    let mut duplicates: Vec<ActionHash> = Vec::new();
+    let mut deleted: Vec<ActionHash> = Vec::new();
+    for action_hashed in chain {
+        match action_hashed.content {
+            Action::CreateLink(create_link)
+                if create_link.zome_index == action.zome_index
+                    && create_link.link_type == action.link_type
+                    && create_link.base_address == action.base_address
+                    && create_link.target_address == action.target_address =>
+            {
+                duplicates.push(action_hashed.hash);
+            }
+            Action::DeleteLink(delete_link) => deleted.push(delete_link.link_add_address),
+            _ => {}
+        }
+    }
+
+    Ok(duplicates.iter().any(|hash| !deleted.contains(hash)))
+}
@@ -0,0 +1,93 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{url_domain, AgentPubKeySchema, ShareItem, ShareKind, SharefeedLinkTag};
+
+const MAX_SUMMARY_TITLE_CHARS: usize = 120;
+
+/// Compact description of a share carried in FeedToShare and TimeIndex
+/// link tags, so listings can render from links alone
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ShareLinkSummary {
+    pub title: String,
+    pub domain: Option<String>,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub author: AgentPubKey,
+    pub content_type: String,
+}
+
+impl ShareLinkSummary {
+    pub fn new(share_item: &ShareItem, author: AgentPubKey) -> Self {
+        ShareLinkSummary {
+            title: share_item
+                .title
+                .chars()
+                .take(MAX_SUMMARY_TITLE_CHARS)
+                .collect(),
+            domain: url_domain(&share_item.url),
+            author,
+            content_type: content_type_for_share(share_item),
+        }
+    }
+
+    pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+        SharefeedLinkTag::new()
+            .with_summary(self.clone())
+            .to_link_tag()
+    }
+
+    /// `None` for links created without a summary
+    pub fn from_link_tag(tag: &LinkTag) -> Option<Self> {
+        SharefeedLinkTag::from_link_tag(tag).and_then(|link_tag| link_tag.summary)
+    }
+}
+
+/// Coarse kind of content a share carries: `note` for notes, otherwise
+/// guessed from its URL
+pub fn content_type_for_share(share_item: &ShareItem) -> String {
+    match share_item.kind {
+        ShareKind::Note => String::from("note"),
+        ShareKind::Link => content_type_for_url(&share_item.url),
+    }
+}
+
+/// Coarse kind of content behind a URL, guessed from its file extension
+pub fn content_type_for_url(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let extension = path
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_lowercase())
+        .unwrap_or_default();
+    let content_type = match extension.as_str() {
+        "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg" => "image",
+        "mp4" | "webm" | "mov" => "video",
+        "mp3" | "ogg" | "wav" => "audio",
+        "pdf" => "pdf",
+        _ => "link",
+    };
+    content_type.to_string()
+}
+
+/// A summary tag, when present, must describe exactly the linked share
+pub fn validate_share_link_summary(
+    tag: &LinkTag,
+    share_item: &ShareItem,
+    share_author: &AgentPubKey,
+) -> ValidateCallbackResult {
+    let Some(link_tag) = SharefeedLinkTag::from_link_tag(tag) else {
+        return ValidateCallbackResult::Invalid(String::from(
+            "Link tag must be a SharefeedLinkTag",
+        ));
+    };
+    match link_tag.summary {
+        None => ValidateCallbackResult::Valid,
+        Some(summary) if summary == ShareLinkSummary::new(share_item, share_author.clone()) => {
+            ValidateCallbackResult::Valid
+        }
+        Some(_) => ValidateCallbackResult::Invalid(String::from(
+            "Link tag must be the summary of the linked ShareItem",
+        )),
+    }
+}
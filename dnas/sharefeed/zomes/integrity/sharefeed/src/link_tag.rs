@@ -0,0 +1,110 @@
+use hdi::prelude::*;
+
+use crate::ShareLinkSummary;
+
+/// Version written into new link tags
+pub const LINK_TAG_VERSION: u8 = 1;
+
+/// Payload of every link tag this DNA writes. New metadata is added as
+/// `#[serde(default)]` fields, so tags written by older versions still
+/// decode and older readers skip fields they don't know about.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, PartialEq)]
+pub struct SharefeedLinkTag {
+    pub version: u8,
+    #[serde(default)]
+    pub summary: Option<ShareLinkSummary>,
+    /// The share tag a TagTimeIndex link was made for
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// A FeedToShare link's share tags merged with the feed's default tags
+    #[serde(default)]
+    pub feed_tags: Vec<String>,
+    /// The latest action the author of a FeedToMember link had seen on each
+    /// steward's chain, so validation can look for bans up to there
+    #[serde(default)]
+    pub steward_chain_heads: Vec<ActionHash>,
+    /// The latest action the author of a FeedToShare link had seen on each
+    /// network moderator's chain, which validation reads the network
+    /// blocklist up to
+    #[serde(default)]
+    pub moderator_chain_heads: Vec<ActionHash>,
+}
+
+impl Default for SharefeedLinkTag {
+    fn default() -> Self {
+        SharefeedLinkTag {
+            version: LINK_TAG_VERSION,
+            summary: None,
+            tag: None,
+            feed_tags: vec![],
+            steward_chain_heads: vec![],
+            moderator_chain_heads: vec![],
+        }
+    }
+}
+
+impl SharefeedLinkTag {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_summary(mut self, summary: ShareLinkSummary) -> Self {
+        self.summary = Some(summary);
+        self
+    }
+
+    pub fn with_tag(mut self, tag: String) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    pub fn with_feed_tags(mut self, feed_tags: Vec<String>) -> Self {
+        self.feed_tags = feed_tags;
+        self
+    }
+
+    pub fn with_steward_chain_heads(mut self, steward_chain_heads: Vec<ActionHash>) -> Self {
+        self.steward_chain_heads = steward_chain_heads;
+        self
+    }
+
+    pub fn with_moderator_chain_heads(mut self, moderator_chain_heads: Vec<ActionHash>) -> Self {
+        self.moderator_chain_heads = moderator_chain_heads;
+        self
+    }
+
+    pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+        let bytes = SerializedBytes::try_from(self.clone()).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(bytes.bytes().clone()))
+    }
+
+    /// Decodes any tag this DNA has written: empty tags and bare summaries
+    /// from before the versioned format come back as version 0.
+    /// `None` if the tag isn't in any known format.
+    pub fn from_link_tag(tag: &LinkTag) -> Option<Self> {
+        if tag.0.is_empty() {
+            return Some(SharefeedLinkTag {
+                version: 0,
+                summary: None,
+                tag: None,
+                feed_tags: vec![],
+                steward_chain_heads: vec![],
+                moderator_chain_heads: vec![],
+            });
+        }
+        let bytes = SerializedBytes::from(UnsafeBytes::from(tag.0.clone()));
+        if let Ok(link_tag) = SharefeedLinkTag::try_from(bytes.clone()) {
+            return Some(link_tag);
+        }
+        ShareLinkSummary::try_from(bytes)
+            .ok()
+            .map(|summary| SharefeedLinkTag {
+                version: 0,
+                summary: Some(summary),
+                tag: None,
+                feed_tags: vec![],
+                steward_chain_heads: vec![],
+                moderator_chain_heads: vec![],
+            })
+    }
+}
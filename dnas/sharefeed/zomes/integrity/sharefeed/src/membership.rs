@@ -0,0 +1,40 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{ActionHashSchema, AgentPubKeySchema};
+
+/// Request to join a feed, delivered to a steward by remote call and kept
+/// on the steward's chain until they act on it
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct MembershipRequest {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub requester: AgentPubKey,
+    pub message: Option<String>,
+}
+
+pub fn validate_create_membership_request(
+    action: EntryCreationAction,
+    membership_request: MembershipRequest,
+) -> ExternResult<ValidateCallbackResult> {
+    if &membership_request.requester == action.author() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Membership requests are stored by the steward receiving them",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_membership_request(
+    _action: Update,
+    _membership_request: MembershipRequest,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Membership requests cannot be updated",
+    )))
+}
@@ -0,0 +1,36 @@
+use hdi::prelude::*;
+
+use crate::must_get_linked_entry;
+
+pub fn validate_create_link_agent_to_mention(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if AgentPubKey::try_from(base_address).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "AgentToMention base must be an agent",
+        )));
+    }
+    must_get_linked_entry::<crate::ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_agent_to_mention(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author of a mention can delete it",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
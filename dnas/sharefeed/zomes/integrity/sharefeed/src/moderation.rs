@@ -0,0 +1,223 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{must_get_entry, must_get_linked_entry, validate_entry_size, ActionHashSchema};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum AppealOutcome {
+    /// The appealed action was reversed where possible
+    Accepted,
+    /// The appealed action stands
+    Upheld,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum ReportOutcome {
+    /// Nothing wrong with the share; it stays in the feed
+    Dismissed,
+    /// The share was taken out of the feed
+    ShareRemoved,
+}
+
+/// What a steward did
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum ModerationKind {
+    ResolveReport {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        report_hash: ActionHash,
+        outcome: ReportOutcome,
+    },
+    ApproveShare {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        share_item_hash: ActionHash,
+    },
+    RejectShare {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        share_item_hash: ActionHash,
+    },
+    /// A steward took out a share someone else added to the feed
+    RemoveShare {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        share_item_hash: ActionHash,
+    },
+    BanMember {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        ban_hash: ActionHash,
+    },
+    /// The ban was lifted
+    UnbanMember {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        ban_hash: ActionHash,
+    },
+    DecideAppeal {
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        appeal_hash: ActionHash,
+        outcome: AppealOutcome,
+    },
+}
+
+/// Public record of a steward's decision in a feed, kept so members can see
+/// how their feed is moderated
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ModerationAction {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub kind: ModerationKind,
+    pub note: Option<String>,
+}
+
+pub fn validate_create_moderation_action(
+    action: EntryCreationAction,
+    moderation_action: ModerationAction,
+) -> ExternResult<ValidateCallbackResult> {
+    let (_, feed) = must_get_entry::<crate::Feed>(
+        moderation_action.feed_hash.clone(),
+        "ModerationAction must reference a Feed entry",
+    )?;
+    if !feed.stewards.contains(action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can moderate it",
+        )));
+    }
+    match &moderation_action.kind {
+        ModerationKind::ResolveReport { report_hash, .. } => {
+            let (_, report) = must_get_entry::<crate::Report>(
+                report_hash.clone(),
+                "ResolveReport must reference a Report entry",
+            )?;
+            if report.feed_hash != moderation_action.feed_hash {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "A report can only be resolved in the feed it was filed in",
+                )));
+            }
+        }
+        ModerationKind::DecideAppeal { appeal_hash, .. } => {
+            let (_, appeal) = must_get_entry::<crate::Appeal>(
+                appeal_hash.clone(),
+                "DecideAppeal must reference an Appeal entry",
+            )?;
+            let (_, appealed) = must_get_entry::<ModerationAction>(
+                appeal.moderation_action_hash,
+                "Appeal must reference a ModerationAction entry",
+            )?;
+            if appealed.feed_hash != moderation_action.feed_hash {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "An appeal can only be decided in the feed it was filed in",
+                )));
+            }
+        }
+        ModerationKind::BanMember { ban_hash } | ModerationKind::UnbanMember { ban_hash } => {
+            let (_, ban) = must_get_entry::<crate::Ban>(
+                ban_hash.clone(),
+                "Moderated ban must reference a Ban entry",
+            )?;
+            if ban.feed_hash != moderation_action.feed_hash {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "A ban can only be logged in the feed it applies to",
+                )));
+            }
+        }
+        ModerationKind::ApproveShare { share_item_hash }
+        | ModerationKind::RejectShare { share_item_hash }
+        | ModerationKind::RemoveShare { share_item_hash } => {
+            must_get_entry::<crate::ShareItem>(
+                share_item_hash.clone(),
+                "Moderated share must reference a ShareItem entry",
+            )?;
+        }
+    }
+    validate_entry_size(moderation_action)
+}
+
+pub fn validate_update_moderation_action(
+    _action: Update,
+    _moderation_action: ModerationAction,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Moderation actions cannot be updated",
+    )))
+}
+
+pub fn validate_create_link_feed_to_moderation_actions(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, moderation_action) = must_get_linked_entry::<crate::ModerationAction>(
+        target_address,
+        "Linked action must reference a ModerationAction entry",
+    )?;
+    if AnyLinkableHash::from(moderation_action.feed_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "FeedToModerationActions base must be the moderated Feed",
+        )));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the steward who took a moderation action can log it",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_feed_to_moderation_actions(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "The moderation log cannot be edited",
+    )))
+}
+
+pub fn validate_create_link_feed_to_pending_share(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, share_item) = must_get_linked_entry::<crate::ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    if crate::is_duplicate_link(&action)? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "This share is already waiting for approval",
+        )));
+    }
+    Ok(crate::validate_share_link_summary(
+        &tag,
+        &share_item,
+        record.action().author(),
+    ))
+}
+
+pub fn validate_delete_link_feed_to_pending_share(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    // Removed by the steward reviewing it, or by whoever submitted it
+    Ok(ValidateCallbackResult::Valid)
+}
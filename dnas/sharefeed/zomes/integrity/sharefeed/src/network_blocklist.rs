@@ -0,0 +1,242 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{
+    must_get_chain_before, must_get_entry, must_get_linked_entry, url_domain, AgentPubKeySchema,
+    LinkTypes, ShareItem, SharefeedLinkTag, UnitEntryTypes,
+};
+
+use crate::sharefeed_properties;
+
+/// Network-wide bans, published by the moderators named in DNA properties.
+/// The most recent entry by a moderator supersedes earlier ones.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, Default, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct NetworkBlocklist {
+    #[cfg_attr(feature = "ts", ts(type = "Array<AgentPubKey>"))]
+    #[schemars(with = "Vec<AgentPubKeySchema>")]
+    pub banned_agents: Vec<AgentPubKey>,
+    pub banned_domains: Vec<String>,
+}
+
+impl NetworkBlocklist {
+    pub fn bans_agent(&self, agent: &AgentPubKey) -> bool {
+        self.banned_agents.contains(agent)
+    }
+
+    /// Matches the domain itself and any of its subdomains
+    pub fn bans_domain(&self, domain: &str) -> bool {
+        domain_is_listed(domain, &self.banned_domains)
+    }
+}
+
+/// Whether `domain` is one of `listed` or a subdomain of one
+pub fn domain_is_listed(domain: &str, listed: &[String]) -> bool {
+    listed.iter().any(|listed| {
+        let listed = listed.to_lowercase();
+        domain == listed || domain.ends_with(&format!(".{}", listed))
+    })
+}
+
+/// Author and sequence number of each moderator chain head named by
+/// `writer`'s latest ShareItem or FeedToShare link on their chain up to
+/// `prev_action`
+fn must_get_moderator_chain_floor(
+    writer: &AgentPubKey,
+    prev_action: &ActionHash,
+) -> ExternResult<Vec<(AgentPubKey, u32)>> {
+    let share_item_type: EntryType = UnitEntryTypes::ShareItem.try_into()?;
+    let feed_to_share = ScopedLinkType::try_from(LinkTypes::FeedToShare)?;
+    let previous_write = must_get_chain_before(writer, prev_action)?
+        .into_iter()
+        .filter(|action_hashed| match &action_hashed.content {
+            Action::Create(create) => create.entry_type == share_item_type,
+            Action::Update(update) => update.entry_type == share_item_type,
+            Action::CreateLink(create_link) => {
+                create_link.zome_index == feed_to_share.zome_index
+                    && create_link.link_type == feed_to_share.zome_type
+            }
+            _ => false,
+        })
+        .max_by_key(|action_hashed| action_hashed.content.action_seq());
+
+    let Some(previous_write) = previous_write else {
+        return Ok(vec![]);
+    };
+    let previous_heads = match &previous_write.content {
+        Action::CreateLink(create_link) => SharefeedLinkTag::from_link_tag(&create_link.tag)
+            .map(|link_tag| link_tag.moderator_chain_heads)
+            .unwrap_or_default(),
+        _ => {
+            let (_, share_item) = must_get_entry::<ShareItem>(
+                previous_write.hash,
+                "Action must reference a ShareItem entry",
+            )?;
+            share_item.moderator_chain_heads
+        }
+    };
+
+    let mut floor: Vec<(AgentPubKey, u32)> = Vec::new();
+    for previous_head in previous_heads {
+        let action = must_get_action(previous_head)?;
+        floor.push((
+            action.action().author().clone(),
+            action.action().action_seq(),
+        ));
+    }
+    Ok(floor)
+}
+
+/// Checks `agents` and `url` against the latest NetworkBlocklist a moderator
+/// published. Each moderator's chain is read up to the head `writer` named
+/// for them in `chain_heads`, or up to `prev_action` for `writer`'s own.
+/// A named head can't be older than the one `writer` named for the same
+/// moderator in their previous write, so lists can't be dodged by naming
+/// stale heads. Lists published after the heads a writer names are
+/// enforced by the coordinator.
+pub fn validate_network_blocklist(
+    writer: &AgentPubKey,
+    prev_action: &ActionHash,
+    chain_heads: &[ActionHash],
+    agents: &[&AgentPubKey],
+    url: Option<&str>,
+) -> ExternResult<ValidateCallbackResult> {
+    let network_blocklist_type: EntryType = UnitEntryTypes::NetworkBlocklist.try_into()?;
+    let mut moderators: Vec<AgentPubKey> = Vec::new();
+    for moderator in sharefeed_properties()?.moderators {
+        let moderator = AgentPubKey::from(moderator);
+        if !moderators.contains(&moderator) {
+            moderators.push(moderator);
+        }
+    }
+
+    // Only looked up once a moderator other than the writer needs it
+    let mut floor: Option<Vec<(AgentPubKey, u32)>> = None;
+    let mut latest: Option<(Timestamp, ActionHash)> = None;
+    for moderator in &moderators {
+        let chain_head = if moderator == writer {
+            prev_action.clone()
+        } else {
+            let mut found = None;
+            for chain_head in chain_heads {
+                let action = must_get_action(chain_head.clone())?;
+                if action.action().author() == moderator {
+                    found = Some((chain_head.clone(), action.action().action_seq()));
+                    break;
+                }
+            }
+            let Some((chain_head, action_seq)) = found else {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "Writes must name a chain head for every network moderator",
+                )));
+            };
+            if floor.is_none() {
+                floor = Some(must_get_moderator_chain_floor(writer, prev_action)?);
+            }
+            if floor
+                .iter()
+                .flatten()
+                .any(|(author, floor_seq)| author == moderator && action_seq < *floor_seq)
+            {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "Moderator chain heads can't be older than the ones named in the previous write",
+                )));
+            }
+            chain_head
+        };
+        for action_hashed in must_get_chain_before(moderator, &chain_head)? {
+            let published_at = match &action_hashed.content {
+                Action::Create(create) if create.entry_type == network_blocklist_type => {
+                    create.timestamp
+                }
+                Action::Update(update) if update.entry_type == network_blocklist_type => {
+                    update.timestamp
+                }
+                _ => continue,
+            };
+            if latest
+                .as_ref()
+                .map_or(true, |(latest_at, _)| published_at > *latest_at)
+            {
+                latest = Some((published_at, action_hashed.hash));
+            }
+        }
+    }
+
+    let Some((_, network_blocklist_hash)) = latest else {
+        return Ok(ValidateCallbackResult::Valid);
+    };
+    let (_, network_blocklist) = must_get_entry::<NetworkBlocklist>(
+        network_blocklist_hash,
+        "Action must reference a NetworkBlocklist entry",
+    )?;
+    if agents
+        .iter()
+        .any(|agent| network_blocklist.bans_agent(agent))
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "This agent has been banned from the network",
+        )));
+    }
+    if let Some(domain) = url.and_then(url_domain) {
+        if network_blocklist.bans_domain(&domain) {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "Links to {} are not allowed on this network",
+                domain
+            )));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+fn validate_moderator(author: &AgentPubKey) -> ExternResult<ValidateCallbackResult> {
+    if !sharefeed_properties()?.is_moderator(author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only network moderators can publish the network blocklist",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_network_blocklist(
+    action: EntryCreationAction,
+    _network_blocklist: NetworkBlocklist,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_moderator(action.author())
+}
+
+pub fn validate_update_network_blocklist(
+    action: Update,
+    _network_blocklist: NetworkBlocklist,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_moderator(&action.author)
+}
+
+pub fn validate_create_link_network_blocklists(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if let ValidateCallbackResult::Invalid(reason) = validate_moderator(&action.author)? {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    must_get_linked_entry::<crate::NetworkBlocklist>(
+        target_address,
+        "Linked action must reference a NetworkBlocklist entry",
+    )?;
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_network_blocklists(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "NetworkBlocklists links cannot be deleted",
+    )))
+}
@@ -0,0 +1,55 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{ActionHashSchema, AgentPubKeySchema};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum NotificationKind {
+    NewShareInFeed,
+    AddedToFeed,
+    Mention,
+    Comment,
+    Reaction,
+    SavedSearchMatch,
+    /// A share with a tag I subscribed to appeared in one of my feeds
+    TagSubscriptionMatch,
+    /// A share from a domain I subscribed to appeared in one of my feeds
+    DomainSubscriptionMatch,
+    /// Someone joined a feed I'm a member of that announces new members
+    MemberJoined,
+}
+
+/// Private, per-agent record of something that happened while the client
+/// may not have been connected. Created by the recipient's own cell.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Notification {
+    pub kind: NotificationKind,
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub from: AgentPubKey,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash | null"))]
+    #[schemars(with = "Option<ActionHashSchema>")]
+    pub feed_hash: Option<ActionHash>,
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash | null"))]
+    #[schemars(with = "Option<ActionHashSchema>")]
+    pub share_item_hash: Option<ActionHash>,
+    pub read: bool,
+}
+
+pub fn validate_create_notification(
+    _action: EntryCreationAction,
+    _notification: Notification,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_notification(
+    _action: Update,
+    _notification: Notification,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,88 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::ActionHashSchema;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum NotificationLevel {
+    #[default]
+    All,
+    MentionsOnly,
+    None,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FeedNotificationLevel {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub level: NotificationLevel,
+}
+
+/// Hours are in UTC. A range where `start_hour > end_hour` wraps past midnight.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct QuietHours {
+    pub start_hour: u8,
+    pub end_hour: u8,
+}
+
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, Default, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct NotificationPrefs {
+    pub default_level: NotificationLevel,
+    pub feed_levels: Vec<FeedNotificationLevel>,
+    pub quiet_hours: Option<QuietHours>,
+}
+
+impl NotificationPrefs {
+    pub fn level_for_feed(&self, feed_hash: Option<&ActionHash>) -> NotificationLevel {
+        feed_hash
+            .and_then(|feed_hash| {
+                self.feed_levels
+                    .iter()
+                    .find(|feed_level| &feed_level.feed_hash == feed_hash)
+            })
+            .map(|feed_level| feed_level.level.clone())
+            .unwrap_or_else(|| self.default_level.clone())
+    }
+
+    pub fn is_quiet_hour(&self, hour: u8) -> bool {
+        match &self.quiet_hours {
+            Some(QuietHours {
+                start_hour,
+                end_hour,
+            }) if start_hour <= end_hour => hour >= *start_hour && hour < *end_hour,
+            Some(QuietHours {
+                start_hour,
+                end_hour,
+            }) => hour >= *start_hour || hour < *end_hour,
+            None => false,
+        }
+    }
+}
+
+pub fn validate_create_notification_prefs(
+    _action: EntryCreationAction,
+    prefs: NotificationPrefs,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(quiet_hours) = &prefs.quiet_hours {
+        if quiet_hours.start_hour > 23 || quiet_hours.end_hour > 23 {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Quiet hours must be between 0 and 23".to_string(),
+            ));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_notification_prefs(
+    action: Update,
+    prefs: NotificationPrefs,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_create_notification_prefs(EntryCreationAction::Update(action), prefs)
+}
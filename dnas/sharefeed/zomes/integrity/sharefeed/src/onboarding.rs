@@ -0,0 +1,48 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+/// Private record of how far I got through onboarding. It lives on my
+/// source chain, so it follows me to every device I use. Step and tip ids
+/// are up to the client.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, Default, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct OnboardingState {
+    pub completed_steps: Vec<String>,
+    pub dismissed_tips: Vec<String>,
+}
+
+impl OnboardingState {
+    /// Checks that no step or tip id is blank
+    pub fn check_ids(&self) -> Result<(), String> {
+        if self
+            .completed_steps
+            .iter()
+            .chain(self.dismissed_tips.iter())
+            .any(|id| id.trim().is_empty())
+        {
+            return Err(String::from("Onboarding step and tip ids cannot be empty"));
+        }
+        Ok(())
+    }
+}
+
+pub fn validate_create_onboarding_state(
+    _action: EntryCreationAction,
+    onboarding_state: OnboardingState,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(match onboarding_state.check_ids() {
+        Ok(()) => ValidateCallbackResult::Valid,
+        Err(reason) => ValidateCallbackResult::Invalid(reason),
+    })
+}
+
+pub fn validate_update_onboarding_state(
+    _action: Update,
+    onboarding_state: OnboardingState,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(match onboarding_state.check_ids() {
+        Ok(()) => ValidateCallbackResult::Valid,
+        Err(reason) => ValidateCallbackResult::Invalid(reason),
+    })
+}
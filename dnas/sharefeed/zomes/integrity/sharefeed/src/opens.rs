@@ -0,0 +1,69 @@
+//! Open counters: each agent that opens a share links it to themselves
+//! once, so the number of links is the number of distinct readers.
+
+use hdi::prelude::*;
+
+use crate::{must_get_chain_before, must_get_linked_entry};
+
+/// Span over which `MAX_OPENS_PER_HOUR` is counted
+pub const OPEN_RATE_WINDOW_SECS: i64 = 60 * 60;
+
+/// Most shares an agent may record opening in any hour
+pub const MAX_OPENS_PER_HOUR: usize = 120;
+
+pub fn validate_create_link_share_item_to_opens(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if target_address != AnyLinkableHash::from(action.author.clone()) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Agents can only record their own opens",
+        )));
+    }
+    must_get_linked_entry::<crate::ShareItem>(
+        base_address,
+        "ShareItemToOpens base must be a ShareItem",
+    )?;
+
+    let window_start =
+        Timestamp::from_micros(action.timestamp.as_micros() - OPEN_RATE_WINDOW_SECS * 1_000_000);
+    let mut recent_opens = 0;
+    for action_hashed in must_get_chain_before(&action.author, &action.prev_action)? {
+        let Action::CreateLink(create_link) = action_hashed.content else {
+            continue;
+        };
+        if create_link.zome_index != action.zome_index || create_link.link_type != action.link_type
+        {
+            continue;
+        }
+        if create_link.base_address == action.base_address {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "An agent's open of a share is only counted once",
+            )));
+        }
+        if create_link.timestamp >= window_start {
+            recent_opens += 1;
+        }
+    }
+    if recent_opens >= MAX_OPENS_PER_HOUR {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Agents may record at most {} opens per hour",
+            MAX_OPENS_PER_HOUR
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_share_item_to_opens(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Recorded opens cannot be removed",
+    )))
+}
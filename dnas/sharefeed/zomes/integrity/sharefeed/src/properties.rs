@@ -0,0 +1,216 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+use std::cell::OnceCell;
+
+use crate::{domain_is_listed, url_domain, AgentPubKeyB64Schema, ShareItem};
+
+/// Span over which `max_shares_per_hour` is counted
+pub const SHARE_RATE_WINDOW_SECS: i64 = 60 * 60;
+
+/// Span over which `max_shares_per_url_per_day` is counted
+pub const URL_FLOOD_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+/// Optional features a network can switch off. Everything is on unless the
+/// properties say otherwise.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SharefeedFeatures {
+    pub comments: bool,
+    pub reactions: bool,
+}
+
+impl Default for SharefeedFeatures {
+    fn default() -> Self {
+        Self {
+            comments: true,
+            reactions: true,
+        }
+    }
+}
+
+/// Network configuration read from the DNA's `properties` block
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, Default, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SharefeedProperties {
+    /// Agents allowed to publish the network-wide moderation list
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "Array<AgentPubKeyB64>"))]
+    #[schemars(with = "Vec<AgentPubKeyB64Schema>")]
+    pub moderators: Vec<AgentPubKeyB64>,
+    /// Agents who set this network up
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "Array<AgentPubKeyB64>"))]
+    #[schemars(with = "Vec<AgentPubKeyB64Schema>")]
+    pub progenitors: Vec<AgentPubKeyB64>,
+    /// Largest ShareItem, Feed or Comment accepted, in serialized bytes
+    #[serde(default)]
+    pub max_entry_bytes: Option<usize>,
+    /// Most tags a ShareItem may carry
+    #[serde(default)]
+    pub max_tags: Option<usize>,
+    /// Largest favicon or thumbnail accepted, in bytes of its encoded form
+    #[serde(default)]
+    pub max_image_bytes: Option<usize>,
+    /// Domains no ShareItem may link to, e.g. known malware or link farms.
+    /// Subdomains are blocked too.
+    #[serde(default)]
+    pub blocked_domains: Vec<String>,
+    /// Most ShareItems an agent may create in any hour
+    #[serde(default)]
+    pub max_shares_per_hour: Option<usize>,
+    /// Most times an agent may share the same normalized URL in any day
+    #[serde(default)]
+    pub max_shares_per_url_per_day: Option<usize>,
+    #[serde(default)]
+    pub features: SharefeedFeatures,
+    /// Name of the network's home feed. The first progenitor to run
+    /// creates it, and every agent follows it from `init`, so new users
+    /// don't start out with an empty app.
+    #[serde(default)]
+    pub home_feed: Option<String>,
+}
+
+impl SharefeedProperties {
+    pub fn is_moderator(&self, agent: &AgentPubKey) -> bool {
+        self.moderators
+            .iter()
+            .any(|moderator| &AgentPubKey::from(moderator.clone()) == agent)
+    }
+
+    pub fn is_progenitor(&self, agent: &AgentPubKey) -> bool {
+        self.progenitors
+            .iter()
+            .any(|progenitor| &AgentPubKey::from(progenitor.clone()) == agent)
+    }
+
+    /// Checks an entry's serialized size against `max_entry_bytes`
+    pub fn check_entry_size(&self, size: usize) -> Result<(), String> {
+        match self.max_entry_bytes {
+            Some(max_entry_bytes) if size > max_entry_bytes => Err(format!(
+                "Entry is {} bytes, over this network's limit of {}",
+                size, max_entry_bytes
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks a ShareItem's tags and images against `max_tags` and
+    /// `max_image_bytes`
+    pub fn check_share_item_limits(&self, share_item: &ShareItem) -> Result<(), String> {
+        if let Some(max_tags) = self.max_tags {
+            if share_item.tags.len() > max_tags {
+                return Err(format!(
+                    "ShareItem has {} tags, over this network's limit of {}",
+                    share_item.tags.len(),
+                    max_tags
+                ));
+            }
+        }
+        if let Some(max_image_bytes) = self.max_image_bytes {
+            let images = [&share_item.favicon, &share_item.thumbnail];
+            if images
+                .into_iter()
+                .flatten()
+                .any(|image| image.len() > max_image_bytes)
+            {
+                return Err(format!(
+                    "ShareItem images must be at most {} bytes on this network",
+                    max_image_bytes
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether one more ShareItem fits under `max_shares_per_hour`,
+    /// given how many the author created in the past hour
+    pub fn check_share_rate(&self, recent_shares: usize) -> Result<(), String> {
+        match self.max_shares_per_hour {
+            Some(max_shares_per_hour) if recent_shares >= max_shares_per_hour => Err(format!(
+                "Agents may share at most {} items per hour on this network",
+                max_shares_per_hour
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks whether another share of a URL fits under
+    /// `max_shares_per_url_per_day`, given how often the author shared it
+    /// in the past day
+    pub fn check_url_share_rate(&self, recent_shares: usize) -> Result<(), String> {
+        match self.max_shares_per_url_per_day {
+            Some(max_shares) if recent_shares >= max_shares => Err(format!(
+                "Agents may share the same link at most {} times a day on this network",
+                max_shares
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks a ShareItem's URL against `blocked_domains`
+    pub fn check_share_item_domain(&self, share_item: &ShareItem) -> Result<(), String> {
+        match url_domain(&share_item.url) {
+            Some(domain) if domain_is_listed(&domain, &self.blocked_domains) => {
+                Err(format!("Links to {} are blocked on this network", domain))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+thread_local! {
+    static PROPERTIES: OnceCell<SharefeedProperties> = const { OnceCell::new() };
+}
+
+/// How `properties: ~` in a DNA manifest arrives: a msgpack nil
+const EMPTY_PROPERTIES: [u8; 1] = [0xc0];
+
+/// Missing properties mean no special configuration. Properties that are
+/// set but don't decode are an error, so a typo can't quietly switch off
+/// a network's limits.
+fn decode_properties(properties: SerializedBytes) -> ExternResult<SharefeedProperties> {
+    let bytes = properties.bytes();
+    if bytes.is_empty() || bytes.as_slice() == EMPTY_PROPERTIES {
+        return Ok(SharefeedProperties::default());
+    }
+    SharefeedProperties::try_from(properties).map_err(|e| {
+        wasm_error!(WasmErrorInner::Guest(format!(
+            "Could not decode the DNA properties: {}",
+            e
+        )))
+    })
+}
+
+/// A cell's properties never change, so they are parsed once per wasm
+/// instance and shared by every check made while it runs.
+pub fn sharefeed_properties() -> ExternResult<SharefeedProperties> {
+    PROPERTIES.with(|cached| {
+        if let Some(properties) = cached.get() {
+            return Ok(properties.clone());
+        }
+        let properties = decode_properties(dna_info()?.modifiers.properties)?;
+        Ok(cached.get_or_init(|| properties).clone())
+    })
+}
+
+/// Size of an entry as it is stored on the DHT
+pub fn serialized_size<T>(entry: T) -> ExternResult<usize>
+where
+    T: TryInto<SerializedBytes, Error = SerializedBytesError>,
+{
+    let bytes: SerializedBytes = entry.try_into().map_err(|e| wasm_error!(e))?;
+    Ok(bytes.bytes().len())
+}
+
+/// Rejects entries over the network's `max_entry_bytes`
+pub fn validate_entry_size<T>(entry: T) -> ExternResult<ValidateCallbackResult>
+where
+    T: TryInto<SerializedBytes, Error = SerializedBytesError>,
+{
+    let size = serialized_size(entry)?;
+    Ok(match sharefeed_properties()?.check_entry_size(size) {
+        Ok(()) => ValidateCallbackResult::Valid,
+        Err(reason) => ValidateCallbackResult::Invalid(reason),
+    })
+}
@@ -0,0 +1,81 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{must_get_entry, must_get_linked_entry, ActionHashSchema};
+
+/// Longest reaction string accepted, enough for multi-codepoint emoji
+pub const MAX_REACTION_CHARS: usize = 16;
+
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Reaction {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    pub emoji: String,
+}
+
+pub fn validate_create_reaction(
+    _action: EntryCreationAction,
+    reaction: Reaction,
+) -> ExternResult<ValidateCallbackResult> {
+    if !crate::sharefeed_properties()?.features.reactions {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Reactions are turned off on this network",
+        )));
+    }
+    if reaction.emoji.is_empty() || reaction.emoji.chars().count() > MAX_REACTION_CHARS {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Reaction must be between 1 and {} characters",
+            MAX_REACTION_CHARS
+        )));
+    }
+    must_get_entry::<crate::ShareItem>(
+        reaction.share_item_hash,
+        "Reaction must reference a ShareItem entry",
+    )?;
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_reaction(
+    _action: Update,
+    _reaction: Reaction,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Reactions cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_create_link_share_item_to_reactions(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (_, reaction) = must_get_linked_entry::<crate::Reaction>(
+        target_address,
+        "Linked action must reference a Reaction entry",
+    )?;
+    if AnyLinkableHash::from(reaction.share_item_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "ShareItemToReactions base must be the ShareItem reacted to",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_share_item_to_reactions(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author of a reaction can remove it",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,115 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{must_get_entry, must_get_linked_entry, validate_entry_size, ActionHashSchema};
+
+/// Longest note accepted on a report
+pub const MAX_REPORT_NOTE_CHARS: usize = 1000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum ReportReason {
+    Spam,
+    Abuse,
+    Harassment,
+    Misinformation,
+    OffTopic,
+    Other,
+}
+
+/// A member's flag on a share in a feed, for the feed's stewards to act on
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Report {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    /// Feed whose stewards receive the report
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub reason: ReportReason,
+    pub note: Option<String>,
+}
+
+pub fn validate_create_report(
+    _action: EntryCreationAction,
+    report: Report,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(note) = &report.note {
+        if note.chars().count() > MAX_REPORT_NOTE_CHARS {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "Report note cannot exceed {} characters",
+                MAX_REPORT_NOTE_CHARS
+            )));
+        }
+    }
+    if report.reason == ReportReason::Other
+        && report
+            .note
+            .as_deref()
+            .map_or(true, |note| note.trim().is_empty())
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Reports for another reason must explain it in a note",
+        )));
+    }
+    must_get_entry::<crate::ShareItem>(
+        report.share_item_hash.clone(),
+        "Report must reference a ShareItem entry",
+    )?;
+    must_get_entry::<crate::Feed>(
+        report.feed_hash.clone(),
+        "Report must reference a Feed entry",
+    )?;
+    validate_entry_size(report)
+}
+
+pub fn validate_update_report(
+    _action: Update,
+    _report: Report,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Reports cannot be updated",
+    )))
+}
+
+pub fn validate_create_link_feed_to_reports(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, report) = must_get_linked_entry::<crate::Report>(
+        target_address,
+        "Linked action must reference a Report entry",
+    )?;
+    if AnyLinkableHash::from(report.feed_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "FeedToReports base must be the Feed the report is addressed to",
+        )));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the reporter can file their report",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_feed_to_reports(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the reporter can withdraw a report",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,41 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{AgentPubKeySchema, TimestampSchema};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct AgentReputation {
+    #[cfg_attr(feature = "ts", ts(type = "AgentPubKey"))]
+    #[schemars(with = "AgentPubKeySchema")]
+    pub agent: AgentPubKey,
+    pub reactions_received: u32,
+    pub removals: u32,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub score: i64,
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp"))]
+    #[schemars(with = "TimestampSchema")]
+    pub computed_at: Timestamp,
+}
+
+/// Private cache of reputation scores I've computed, refreshed on a schedule
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, Default, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ReputationCache {
+    pub reputations: Vec<AgentReputation>,
+}
+
+pub fn validate_create_reputation_cache(
+    _action: EntryCreationAction,
+    _reputation_cache: ReputationCache,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_reputation_cache(
+    _action: Update,
+    _reputation_cache: ReputationCache,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
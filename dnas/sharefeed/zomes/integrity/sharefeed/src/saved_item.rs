@@ -0,0 +1,32 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::ActionHashSchema;
+
+/// Private read-later bookmark on one of the shares I can see. Together my
+/// saved items form a queue, read from the lowest position up.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SavedItem {
+    /// Original action hash of the saved share
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    /// Place in my reading queue
+    pub position: u32,
+}
+
+pub fn validate_create_saved_item(
+    _action: EntryCreationAction,
+    _saved_item: SavedItem,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_saved_item(
+    _action: Update,
+    _saved_item: SavedItem,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
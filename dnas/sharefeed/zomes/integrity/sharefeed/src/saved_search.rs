@@ -0,0 +1,44 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::ActionHashSchema;
+
+/// Private full-text search kept on my chain, optionally checked on a
+/// schedule so I'm notified about new matching shares
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+    /// Only match shares in this feed
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash | null"))]
+    #[schemars(with = "Option<ActionHashSchema>")]
+    pub feed_hash: Option<ActionHash>,
+    /// Notify me when new shares match
+    pub notify: bool,
+}
+
+pub fn validate_create_saved_search(
+    _action: EntryCreationAction,
+    saved_search: SavedSearch,
+) -> ExternResult<ValidateCallbackResult> {
+    if saved_search.name.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "SavedSearch name cannot be empty".to_string(),
+        ));
+    }
+    if saved_search.query.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "SavedSearch query cannot be empty".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_saved_search(
+    _action: Update,
+    _saved_search: SavedSearch,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
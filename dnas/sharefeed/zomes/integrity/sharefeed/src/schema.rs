@@ -0,0 +1,164 @@
+//! JSON Schema stand-ins for the Holochain types our entries and extern
+//! payloads carry. Those types don't implement `JsonSchema`, so fields of
+//! these types name one of these stand-ins with `#[schemars(with = "...")]`.
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{ArrayValidation, InstanceType, Metadata, Schema, SchemaObject};
+use schemars::JsonSchema;
+
+/// Serialized length of a hash with its type prefix and location bytes
+const HOLO_HASH_BYTES: u32 = 39;
+const CAP_SECRET_BYTES: u32 = 64;
+
+fn described(description: &str, schema: SchemaObject) -> Schema {
+    SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.to_string()),
+            ..Default::default()
+        })),
+        ..schema
+    }
+    .into()
+}
+
+fn byte_array_schema(gen: &mut SchemaGenerator, description: &str, len: u32) -> Schema {
+    described(
+        description,
+        SchemaObject {
+            instance_type: Some(InstanceType::Array.into()),
+            array: Some(Box::new(ArrayValidation {
+                items: Some(gen.subschema_for::<u8>().into()),
+                min_items: Some(len),
+                max_items: Some(len),
+                ..Default::default()
+            })),
+            ..Default::default()
+        },
+    )
+}
+
+macro_rules! byte_array_schema_type {
+    ($wrapper:ident, $name:literal, $len:expr, $description:literal) => {
+        pub struct $wrapper;
+
+        impl JsonSchema for $wrapper {
+            fn schema_name() -> String {
+                String::from($name)
+            }
+
+            fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+                byte_array_schema(gen, $description, $len)
+            }
+        }
+    };
+}
+
+byte_array_schema_type!(
+    ActionHashSchema,
+    "ActionHash",
+    HOLO_HASH_BYTES,
+    "Hash of an action, as raw bytes"
+);
+byte_array_schema_type!(
+    AgentPubKeySchema,
+    "AgentPubKey",
+    HOLO_HASH_BYTES,
+    "Public key of an agent, as raw bytes"
+);
+byte_array_schema_type!(
+    DnaHashSchema,
+    "DnaHash",
+    HOLO_HASH_BYTES,
+    "Hash of a DNA, as raw bytes"
+);
+byte_array_schema_type!(
+    CapSecretSchema,
+    "CapSecret",
+    CAP_SECRET_BYTES,
+    "Secret that unlocks a capability grant"
+);
+
+pub struct AgentPubKeyB64Schema;
+
+impl JsonSchema for AgentPubKeyB64Schema {
+    fn schema_name() -> String {
+        String::from("AgentPubKeyB64")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        described(
+            "Public key of an agent in its base64 form, e.g. uhCAk...",
+            SchemaObject {
+                instance_type: Some(InstanceType::String.into()),
+                ..Default::default()
+            },
+        )
+    }
+}
+
+pub struct TimestampSchema;
+
+impl JsonSchema for TimestampSchema {
+    fn schema_name() -> String {
+        String::from("Timestamp")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        described(
+            "Microseconds since the Unix epoch",
+            SchemaObject {
+                instance_type: Some(InstanceType::Integer.into()),
+                format: Some(String::from("int64")),
+                ..Default::default()
+            },
+        )
+    }
+}
+
+pub struct RecordSchema;
+
+impl JsonSchema for RecordSchema {
+    fn schema_name() -> String {
+        String::from("Record")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        described(
+            "A signed action and, when public, its entry",
+            SchemaObject {
+                instance_type: Some(InstanceType::Object.into()),
+                ..Default::default()
+            },
+        )
+    }
+}
+
+pub struct CellIdSchema;
+
+impl JsonSchema for CellIdSchema {
+    fn schema_name() -> String {
+        String::from("CellId")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        <(DnaHashSchema, AgentPubKeySchema)>::json_schema(gen)
+    }
+}
+
+pub struct MigrationTargetSchema;
+
+impl JsonSchema for MigrationTargetSchema {
+    fn schema_name() -> String {
+        String::from("MigrationTarget")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        described(
+            "The DNA or agent key a closed chain migrated to",
+            SchemaObject {
+                instance_type: Some(InstanceType::Object.into()),
+                ..Default::default()
+            },
+        )
+    }
+}
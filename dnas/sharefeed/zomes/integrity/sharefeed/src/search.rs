@@ -0,0 +1,172 @@
+use hdi::prelude::*;
+
+use crate::{must_get_linked_entry, ShareItem};
+
+/// Longest word indexed; longer runs of characters are skipped
+const MAX_TOKEN_CHARS: usize = 32;
+/// Most distinct words indexed per share
+pub const MAX_INDEXED_TOKENS: usize = 50;
+/// Most distinct title trigrams indexed per share
+pub const MAX_INDEXED_TRIGRAMS: usize = 100;
+
+const STOPWORDS: [&str; 24] = [
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "is", "it", "of", "on",
+    "or", "that", "the", "this", "to", "was", "with", "you", "your",
+];
+
+/// Lowercased distinct words of `text`, in order of first appearance,
+/// without stopwords or single characters
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    for word in text.split(|c: char| !c.is_alphanumeric()) {
+        let token = word.to_lowercase();
+        let chars = token.chars().count();
+        if !(2..=MAX_TOKEN_CHARS).contains(&chars) || STOPWORDS.contains(&token.as_str()) {
+            continue;
+        }
+        if !tokens.contains(&token) {
+            tokens.push(token);
+        }
+    }
+    tokens
+}
+
+/// Words a share is searchable by: from its title, then its description,
+/// then a note's body
+pub fn share_item_tokens(share_item: &ShareItem) -> Vec<String> {
+    let mut text = share_item.title.clone();
+    if let Some(description) = &share_item.description {
+        text.push(' ');
+        text.push_str(description);
+    }
+    if let Some(body) = &share_item.body {
+        text.push(' ');
+        text.push_str(body);
+    }
+    for translation in share_item.translations.values() {
+        text.push(' ');
+        text.push_str(&translation.title);
+        if let Some(description) = &translation.description {
+            text.push(' ');
+            text.push_str(description);
+        }
+    }
+    let mut tokens = tokenize(&text);
+    tokens.truncate(MAX_INDEXED_TOKENS);
+    tokens
+}
+
+/// Anchor for one word, sharded by its first two characters
+pub fn word_path(token: &str) -> Path {
+    let shard: String = token.chars().take(2).collect();
+    Path::from(format!("words.{}.{}", shard, token))
+}
+
+/// Distinct three-character windows of each word in `text`, lowercased,
+/// in order of first appearance. Words shorter than three characters
+/// have none.
+pub fn trigrams(text: &str) -> Vec<String> {
+    let mut trigrams: Vec<String> = Vec::new();
+    for word in text.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = word.to_lowercase().chars().collect();
+        for window in chars.windows(3) {
+            let trigram: String = window.iter().collect();
+            if !trigrams.contains(&trigram) {
+                trigrams.push(trigram);
+            }
+        }
+    }
+    trigrams
+}
+
+/// Trigrams a share's title is searchable by
+pub fn share_item_trigrams(share_item: &ShareItem) -> Vec<String> {
+    let mut trigrams = trigrams(&share_item.title);
+    trigrams.truncate(MAX_INDEXED_TRIGRAMS);
+    trigrams
+}
+
+/// Anchor for one title trigram
+pub fn trigram_path(trigram: &str) -> Path {
+    Path::from(format!("trigrams.{}", trigram))
+}
+
+pub fn validate_create_link_word_to_share(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, share_item) = must_get_linked_entry::<ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author of a share can index it for search",
+        )));
+    }
+    for token in share_item_tokens(&share_item) {
+        if AnyLinkableHash::from(word_path(&token).path_entry_hash()?) == base_address {
+            return Ok(ValidateCallbackResult::Valid);
+        }
+    }
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "WordToShare base must be the anchor of a word in the share",
+    )))
+}
+
+pub fn validate_delete_link_word_to_share(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author can remove their search index links",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_trigram_to_share(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, share_item) = must_get_linked_entry::<ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author of a share can index it for search",
+        )));
+    }
+    for trigram in share_item_trigrams(&share_item) {
+        if AnyLinkableHash::from(trigram_path(&trigram).path_entry_hash()?) == base_address {
+            return Ok(ValidateCallbackResult::Valid);
+        }
+    }
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "TrigramToShare base must be the anchor of a trigram in the share's title",
+    )))
+}
+
+pub fn validate_delete_link_trigram_to_share(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author can remove their search index links",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
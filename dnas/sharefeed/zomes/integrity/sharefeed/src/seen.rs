@@ -0,0 +1,42 @@
+//! Seen receipts: a member links a feed to a share they've caught up on.
+//! Whether a feed takes receipts is its `seen_receipts` setting, which can
+//! change with feed updates, so the coordinator checks it on the latest
+//! version.
+
+use hdi::prelude::*;
+
+use crate::must_get_linked_entry;
+
+pub fn validate_create_link_feed_to_seen_receipts(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    must_get_linked_entry::<crate::Feed>(base_address, "FeedToSeenReceipts base must be a Feed")?;
+    must_get_linked_entry::<crate::ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    if crate::is_duplicate_link(&action)? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "This share is already marked as seen",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_feed_to_seen_receipts(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the reader can remove their seen receipt",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -1,7 +1,16 @@
 use hdi::prelude::*;
+use schemars::JsonSchema;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+    must_get_chain_since, must_get_entry, must_get_linked_entry, serialized_size,
+    sharefeed_properties, validate_network_blocklist, ActionHashSchema, TimestampSchema,
+    UnitEntryTypes, SHARE_RATE_WINDOW_SECS, URL_FLOOD_WINDOW_SECS,
+};
 
 #[hdk_entry_helper]
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct ShareItem {
     pub url: String,
     pub title: String,
@@ -10,17 +19,238 @@ pub struct ShareItem {
     pub favicon: Option<String>,
     pub thumbnail: Option<String>,
     pub tags: Vec<String>,
+    /// Where an imported share came from; `None` for shares made here
+    #[serde(default)]
+    pub provenance: Option<ShareProvenance>,
+    /// Link or note; shares from before notes existed are links
+    #[serde(default)]
+    pub kind: ShareKind,
+    /// Text of a note
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Title and description in other languages, keyed by language code
+    #[serde(default)]
+    pub translations: BTreeMap<String, ShareTranslation>,
+    /// The latest action the author had seen on each network moderator's
+    /// chain, which validation reads the network blocklist up to. Filled
+    /// in by `create_share_item`.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "Array<ActionHash>"))]
+    #[schemars(with = "Vec<ActionHashSchema>")]
+    pub moderator_chain_heads: Vec<ActionHash>,
+}
+
+/// A rendering of a share's title and description in one language
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ShareTranslation {
+    pub title: String,
+    pub description: Option<String>,
+}
+
+/// Most translations a share may carry
+pub const MAX_SHARE_TRANSLATIONS: usize = 20;
+
+/// Whether `code` looks like a BCP 47 language tag such as `de`, `pt-BR`
+/// or `zh-Hant`: a 2 or 3 letter language followed by optional
+/// alphanumeric subtags of 2 to 8 characters
+pub fn is_language_code(code: &str) -> bool {
+    let mut subtags = code.split('-');
+    let Some(language) = subtags.next() else {
+        return false;
+    };
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_alphabetic())
+        && subtags.all(|subtag| {
+            (2..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+/// Longest note body accepted
+pub const MAX_NOTE_BODY_CHARS: usize = 5000;
+
+/// What a share carries
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum ShareKind {
+    /// A link to `url`
+    #[default]
+    Link,
+    /// A short text update or question in `body`; `url` may be empty
+    Note,
+}
+
+impl ShareItem {
+    /// Whether two revisions point at the same URL once normalized, see
+    /// `normalize_url`
+    pub fn has_same_url(&self, other: &ShareItem) -> bool {
+        normalize_url(&self.url) == normalize_url(&other.url)
+    }
+
+    /// Whether the share has a URL to index and check. Notes may not.
+    pub fn has_url(&self) -> bool {
+        !self.url.is_empty()
+    }
+
+    /// Checks that a link has a URL and a note has text
+    pub fn check_kind(&self) -> Result<(), String> {
+        match self.kind {
+            ShareKind::Link if !self.has_url() => {
+                Err(String::from("ShareItem url cannot be empty"))
+            }
+            ShareKind::Note
+                if self
+                    .body
+                    .as_deref()
+                    .is_none_or(|body| body.trim().is_empty()) =>
+            {
+                Err(String::from("A note must have body text"))
+            }
+            _ => match &self.body {
+                Some(body) if body.chars().count() > MAX_NOTE_BODY_CHARS => Err(format!(
+                    "ShareItem body cannot exceed {} characters",
+                    MAX_NOTE_BODY_CHARS
+                )),
+                _ => Ok(()),
+            },
+        }
+    }
+
+    /// Checks translation language codes and that each has a title
+    pub fn check_translations(&self) -> Result<(), String> {
+        if self.translations.len() > MAX_SHARE_TRANSLATIONS {
+            return Err(format!(
+                "ShareItem can have at most {} translations",
+                MAX_SHARE_TRANSLATIONS
+            ));
+        }
+        for (language, translation) in &self.translations {
+            if !is_language_code(language) {
+                return Err(format!("'{}' is not a valid language code", language));
+            }
+            if translation.title.trim().is_empty() {
+                return Err(format!("ShareItem title in '{}' cannot be empty", language));
+            }
+        }
+        Ok(())
+    }
+
+    /// The share with its title and description in `language` when it has
+    /// a translation for it, or for its base language (`pt` for `pt-BR`).
+    /// Otherwise the share is returned as written.
+    pub fn localized(mut self, language: &str) -> ShareItem {
+        let base_language = language.split('-').next().unwrap_or(language);
+        let translation = self
+            .translations
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(language))
+            .or_else(|| {
+                self.translations
+                    .iter()
+                    .find(|(code, _)| code.eq_ignore_ascii_case(base_language))
+            })
+            .map(|(_, translation)| translation.clone());
+        if let Some(translation) = translation {
+            self.title = translation.title;
+            self.description = translation.description;
+        }
+        self
+    }
+}
+
+/// Origin of a share brought in from another service
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ShareProvenance {
+    /// The service or format it was imported from, e.g. `pocket`
+    pub source: String,
+    /// When it was originally saved there
+    #[cfg_attr(feature = "ts", ts(type = "Timestamp | null"))]
+    #[schemars(with = "Option<TimestampSchema>")]
+    pub original_added_at: Option<Timestamp>,
+    /// The share this one restores from a personal data export
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash | null"))]
+    #[schemars(with = "Option<ActionHashSchema>")]
+    pub original_action_hash: Option<ActionHash>,
+}
+
+/// Lowercased host of a URL without any `www.` prefix, e.g.
+/// `https://www.Example.com:8080/a?b` -> `example.com`
+pub fn url_domain(url: &str) -> Option<String> {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = without_scheme
+        .split(['/', '?', '#'])
+        .next()?
+        .rsplit('@')
+        .next()?
+        .split(':')
+        .next()?
+        .to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host).to_string();
+    if host.is_empty() {
+        None
+    } else {
+        Some(host)
+    }
+}
+
+/// Canonical form of a URL used for deduplication: scheme, `www.`,
+/// fragment, trailing slashes and `utm_*` tracking parameters are dropped
+/// and the host is lowercased, so `https://www.Example.com/a/?utm_source=x#top`
+/// becomes `example.com/a`.
+pub fn normalize_url(url: &str) -> String {
+    let url = url.trim();
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let rest = rest.split('#').next().unwrap_or_default();
+    let (host_and_path, query) = match rest.split_once('?') {
+        Some((host_and_path, query)) => (host_and_path, Some(query)),
+        None => (rest, None),
+    };
+    let (host, path) = host_and_path.split_once('/').unwrap_or((host_and_path, ""));
+    let host = host.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+
+    let mut normalized = host.to_string();
+    let path = path.trim_end_matches('/');
+    if !path.is_empty() {
+        normalized.push('/');
+        normalized.push_str(path);
+    }
+    let params: Vec<&str> = query
+        .map(|query| {
+            query
+                .split('&')
+                .filter(|param| !param.is_empty() && !param.starts_with("utm_"))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !params.is_empty() {
+        normalized.push('?');
+        normalized.push_str(&params.join("&"));
+    }
+    normalized
+}
+
+/// Anchor path shared by every ShareItem with the same normalized URL.
+/// The URL is hashed so its dots don't become path components.
+pub fn url_path(url: &str) -> ExternResult<Path> {
+    let hash = hash_blake2b(normalize_url(url).into_bytes(), 16)?;
+    let hex: String = hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+    Ok(Path::from(format!("urls.{}", hex)))
 }
 
 pub fn validate_create_share_item(
-    _action: EntryCreationAction,
+    action: EntryCreationAction,
     share_item: ShareItem,
 ) -> ExternResult<ValidateCallbackResult> {
-    // URL must not be empty
-    if share_item.url.is_empty() {
-        return Ok(ValidateCallbackResult::Invalid(
-            "ShareItem url cannot be empty".to_string(),
-        ));
+    // Links need a URL and notes need text
+    if let Err(reason) = share_item.check_kind() {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    if let Err(reason) = share_item.check_translations() {
+        return Ok(ValidateCallbackResult::Invalid(reason));
     }
     // Title must not be empty
     if share_item.title.is_empty() {
@@ -28,21 +258,149 @@ pub fn validate_create_share_item(
             "ShareItem title cannot be empty".to_string(),
         ));
     }
+    if let Some(provenance) = &share_item.provenance {
+        if provenance.source.trim().is_empty() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "ShareItem provenance must name its source".to_string(),
+            ));
+        }
+        if provenance
+            .original_added_at
+            .is_some_and(|added_at| added_at > *action.timestamp())
+        {
+            return Ok(ValidateCallbackResult::Invalid(
+                "ShareItem cannot have been saved elsewhere after it was shared".to_string(),
+            ));
+        }
+    }
+    let properties = sharefeed_properties()?;
+    if let Err(reason) = properties.check_share_item_limits(&share_item) {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    if let Err(reason) = properties.check_share_item_domain(&share_item) {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    if let ValidateCallbackResult::Invalid(reason) = validate_network_blocklist(
+        action.author(),
+        action.prev_action(),
+        &share_item.moderator_chain_heads,
+        &[action.author()],
+        Some(&share_item.url),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    if let Err(reason) = properties.check_entry_size(serialized_size(share_item)?) {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
+/// Rejects a ShareItem creation over the network's `max_shares_per_hour`,
+/// counting the author's other ShareItem creations in the hour before it,
+/// or over its `max_shares_per_url_per_day`
+pub fn validate_share_item_rate(action: Create) -> ExternResult<ValidateCallbackResult> {
+    let properties = sharefeed_properties()?;
+    if properties.max_shares_per_hour.is_none() {
+        return validate_url_share_rate(&action);
+    }
+    let share_item_type: EntryType = UnitEntryTypes::ShareItem.try_into()?;
+    let window_start =
+        Timestamp::from_micros(action.timestamp.as_micros() - SHARE_RATE_WINDOW_SECS * 1_000_000);
+    let recent_shares = must_get_chain_since(&action.author, &action.prev_action, window_start)?
+        .into_iter()
+        .filter(|action_hashed| match &action_hashed.content {
+            Action::Create(create) => create.entry_type == share_item_type,
+            _ => false,
+        })
+        .count();
+    if let Err(reason) = properties.check_share_rate(recent_shares) {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    validate_url_share_rate(&action)
+}
+
+/// Rejects a ShareItem creation over the network's
+/// `max_shares_per_url_per_day`, counting the author's undeleted shares of
+/// the same normalized URL in the day before it
+fn validate_url_share_rate(action: &Create) -> ExternResult<ValidateCallbackResult> {
+    let properties = sharefeed_properties()?;
+    if properties.max_shares_per_url_per_day.is_none() {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let share_item =
+        ShareItem::try_from(hdi::prelude::must_get_entry(action.entry_hash.clone())?.content)?;
+    if !share_item.has_url() {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let share_item_type: EntryType = UnitEntryTypes::ShareItem.try_into()?;
+    let window_start =
+        Timestamp::from_micros(action.timestamp.as_micros() - URL_FLOOD_WINDOW_SECS * 1_000_000);
+    // Shares in the window can only have been deleted after it started
+    let chain = must_get_chain_since(&action.author, &action.prev_action, window_start)?;
+    let deleted: HashSet<ActionHash> = chain
+        .iter()
+        .filter_map(|action_hashed| match &action_hashed.content {
+            Action::Delete(delete) => Some(delete.deletes_address.clone()),
+            _ => None,
+        })
+        .collect();
+    let mut recent_shares = 0;
+    for action_hashed in chain {
+        let Action::Create(create) = &action_hashed.content else {
+            continue;
+        };
+        if create.entry_type != share_item_type || deleted.contains(&action_hashed.hash) {
+            continue;
+        }
+        let (_, earlier_share_item) = must_get_entry::<ShareItem>(
+            action_hashed.hash,
+            "Action must reference a ShareItem entry",
+        )?;
+        if earlier_share_item.has_same_url(&share_item) {
+            recent_shares += 1;
+        }
+    }
+    Ok(match properties.check_url_share_rate(recent_shares) {
+        Ok(()) => ValidateCallbackResult::Valid,
+        Err(reason) => ValidateCallbackResult::Invalid(reason),
+    })
+}
+
+/// Only the author revises a share, and it keeps its URL across
+/// revisions, so one that collected comments and reactions can't be
+/// pointed somewhere else. Corrections that keep the normalized URL, like
+/// dropping tracking parameters, are allowed.
 pub fn validate_update_share_item(
-    _action: Update,
-    _share_item: ShareItem,
+    action: Update,
+    share_item: ShareItem,
 ) -> ExternResult<ValidateCallbackResult> {
+    let (previous_record, previous_share_item) = must_get_entry::<ShareItem>(
+        action.original_action_address,
+        "Updated action must reference a ShareItem entry",
+    )?;
+    if previous_record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author of a share can update it",
+        )));
+    }
+    if !previous_share_item.has_same_url(&share_item) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A share's URL cannot be changed",
+        )));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_delete_share_item(
-    _action: Delete,
-    _original_action: EntryCreationAction,
+    action: Delete,
+    original_action: EntryCreationAction,
     _original_share_item: ShareItem,
 ) -> ExternResult<ValidateCallbackResult> {
+    if original_action.author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author of a share can delete it",
+        )));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -52,24 +410,14 @@ pub fn validate_create_link_share_item_updates(
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::try_from(base_address).map_err(|err| wasm_error!(err))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _share_item: crate::ShareItem = record
-        .entry()
-        .to_app_option()
-        .map_err(|e| wasm_error!(e))?
-        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
-            "Linked action must reference an entry"
-        ))))?;
-    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(err))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _share_item: crate::ShareItem = record
-        .entry()
-        .to_app_option()
-        .map_err(|e| wasm_error!(e))?
-        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
-            "Linked action must reference an entry"
-        ))))?;
+    must_get_linked_entry::<crate::ShareItem>(
+        base_address,
+        "Linked action must reference an entry",
+    )?;
+    must_get_linked_entry::<crate::ShareItem>(
+        target_address,
+        "Linked action must reference an entry",
+    )?;
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -84,3 +432,89 @@ pub fn validate_delete_link_share_item_updates(
         "ShareItemUpdates links cannot be deleted",
     )))
 }
+
+pub fn validate_create_link_time_index(
+    _action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, share_item) = must_get_linked_entry::<crate::ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    Ok(crate::validate_share_link_summary(
+        &tag,
+        &share_item,
+        record.action().author(),
+    ))
+}
+
+pub fn validate_create_link_url_to_share(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (_, share_item) = must_get_linked_entry::<crate::ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    if !share_item.has_url() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Shares without a URL aren't indexed by URL",
+        )));
+    }
+    if AnyLinkableHash::from(url_path(&share_item.url)?.path_entry_hash()?) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "UrlToShare base must be the anchor for the share's URL",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_url_to_share(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author can remove their URL index links",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// UrlTimeIndex links point at a URL's dedup anchor; counts are taken per
+/// distinct link author, so each agent weighs at most once per URL
+pub fn validate_create_link_url_time_index(
+    _action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if target_address.into_entry_hash().is_none() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "UrlTimeIndex target must be a URL anchor",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_url_time_index(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author can remove their URL index links",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,266 @@
+//! Two-phase approval of destructive feed actions: one steward proposes,
+//! a different steward approves, and only then does the action run. This
+//! way a single compromised steward key can't wipe out a shared feed.
+
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{
+    must_get_chain_before, must_get_entry, must_get_feed_stewards, must_get_linked_entry,
+    must_get_original_feed_hash, ActionHashSchema, UnitEntryTypes,
+};
+
+/// Feed actions that need sign-off from other stewards on feeds with several
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub enum StewardProposalKind {
+    DeleteFeed,
+    /// Make a members-only feed public. Every steward has to sign off,
+    /// since it exposes what members shared in private.
+    MakePublic {
+        /// The revision of the feed to publish. Its stewards are the ones
+        /// who sign off, and only an update of this revision can use it.
+        #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+        #[schemars(with = "ActionHashSchema")]
+        revision: ActionHash,
+    },
+    /// Take shares others added out of the feed, beyond the few a steward
+    /// may remove on their own each day
+    RemoveShares {
+        #[cfg_attr(feature = "ts", ts(type = "Array<ActionHash>"))]
+        #[schemars(with = "Vec<ActionHashSchema>")]
+        share_item_hashes: Vec<ActionHash>,
+    },
+}
+
+/// A steward's proposal to take a destructive action on their feed,
+/// waiting for another steward's approval
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct StewardProposal {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub feed_hash: ActionHash,
+    pub kind: StewardProposalKind,
+    pub note: Option<String>,
+}
+
+/// A steward's sign-off on another steward's proposal. The steward whose
+/// approval completes the proposal is the one who carries out the action.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct StewardApproval {
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub proposal_hash: ActionHash,
+    /// Earlier approvals of the same proposal by other stewards, so that
+    /// validation can count everyone who signed off
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "Array<ActionHash>"))]
+    #[schemars(with = "Vec<ActionHashSchema>")]
+    pub co_signed: Vec<ActionHash>,
+}
+
+/// Stewards who signed off on a proposal for `feed_hash` whose kind
+/// satisfies `matches_kind`, that `author` approved on their chain up to
+/// `prev_action`: the proposer, `author` and the stewards whose approvals
+/// `author` co-signed. When `author` approved several such proposals, the
+/// one with the most signers counts. Empty if they approved none.
+pub fn must_get_signers_on_chain(
+    author: &AgentPubKey,
+    prev_action: &ActionHash,
+    feed_hash: &ActionHash,
+    matches_kind: impl Fn(&StewardProposalKind) -> bool,
+) -> ExternResult<Vec<AgentPubKey>> {
+    let approval_type: EntryType = UnitEntryTypes::StewardApproval.try_into()?;
+    let mut most_signers: Vec<AgentPubKey> = Vec::new();
+    for action_hashed in must_get_chain_before(author, prev_action)? {
+        let Action::Create(create) = &action_hashed.content else {
+            continue;
+        };
+        if create.entry_type != approval_type {
+            continue;
+        }
+        let (_, approval) = must_get_entry::<StewardApproval>(
+            action_hashed.hash,
+            "Action must reference a StewardApproval entry",
+        )?;
+        let (record, proposal) = must_get_entry::<StewardProposal>(
+            approval.proposal_hash,
+            "StewardApproval must reference a StewardProposal entry",
+        )?;
+        if &proposal.feed_hash != feed_hash || !matches_kind(&proposal.kind) {
+            continue;
+        }
+
+        let mut signers = vec![record.action().author().clone(), author.clone()];
+        for co_signed_hash in approval.co_signed {
+            let (co_signed_record, _) = must_get_entry::<StewardApproval>(
+                co_signed_hash,
+                "StewardApproval must co-sign StewardApproval entries",
+            )?;
+            let signer = co_signed_record.action().author();
+            if !signers.contains(signer) {
+                signers.push(signer.clone());
+            }
+        }
+        if signers.len() > most_signers.len() {
+            most_signers = signers;
+        }
+    }
+    Ok(most_signers)
+}
+
+/// Stewards who may propose and approve `proposal`: those of the revision
+/// it publishes for MakePublic, those of the feed as created otherwise
+fn must_get_proposal_stewards(proposal: &StewardProposal) -> ExternResult<Vec<AgentPubKey>> {
+    match &proposal.kind {
+        StewardProposalKind::MakePublic { revision } => must_get_feed_stewards(revision.clone()),
+        _ => must_get_feed_stewards(proposal.feed_hash.clone()),
+    }
+}
+
+pub fn validate_create_steward_proposal(
+    action: EntryCreationAction,
+    steward_proposal: StewardProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    if let StewardProposalKind::MakePublic { revision } = &steward_proposal.kind {
+        if must_get_original_feed_hash(revision.clone())? != steward_proposal.feed_hash {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "A MakePublic proposal must name a revision of its own feed",
+            )));
+        }
+    }
+    if !must_get_proposal_stewards(&steward_proposal)?.contains(action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can propose actions on it",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_steward_proposal(
+    _action: Update,
+    _steward_proposal: StewardProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Steward proposals cannot be updated",
+    )))
+}
+
+pub fn validate_create_steward_approval(
+    action: EntryCreationAction,
+    steward_approval: StewardApproval,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, proposal) = must_get_entry::<StewardProposal>(
+        steward_approval.proposal_hash.clone(),
+        "StewardApproval must reference a StewardProposal entry",
+    )?;
+    if record.action().author() == action.author() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Stewards cannot approve their own proposals",
+        )));
+    }
+    if !must_get_proposal_stewards(&proposal)?.contains(action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only stewards of a feed can approve proposals on it",
+        )));
+    }
+    for co_signed_hash in steward_approval.co_signed {
+        let (_, co_signed) = must_get_entry::<StewardApproval>(
+            co_signed_hash,
+            "StewardApproval must co-sign StewardApproval entries",
+        )?;
+        if co_signed.proposal_hash != steward_approval.proposal_hash {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "A StewardApproval can only co-sign approvals of the same proposal",
+            )));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_steward_approval(
+    _action: Update,
+    _steward_approval: StewardApproval,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Steward approvals cannot be updated",
+    )))
+}
+
+pub fn validate_create_link_feed_to_steward_proposals(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, proposal) = must_get_linked_entry::<StewardProposal>(
+        target_address,
+        "Linked action must reference a StewardProposal entry",
+    )?;
+    if AnyLinkableHash::from(proposal.feed_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "FeedToStewardProposals base must be the proposal's Feed",
+        )));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the steward who proposed it can link a proposal",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_feed_to_steward_proposals(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the steward who proposed it can withdraw a proposal",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_steward_proposal_to_approvals(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, approval) = must_get_linked_entry::<StewardApproval>(
+        target_address,
+        "Linked action must reference a StewardApproval entry",
+    )?;
+    if AnyLinkableHash::from(approval.proposal_hash) != base_address {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "StewardProposalToApprovals base must be the approved StewardProposal",
+        )));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the steward who approved it can link an approval",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_steward_proposal_to_approvals(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Steward approvals cannot be withdrawn",
+    )))
+}
@@ -0,0 +1,71 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::{normalize_tag, url_domain};
+
+/// Private subscription to a share tag: I'm notified when shares with the
+/// tag appear in feeds I belong to
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct TagSubscription {
+    /// Normalized, see `normalize_tag`
+    pub tag: String,
+}
+
+pub fn validate_create_tag_subscription(
+    _action: EntryCreationAction,
+    tag_subscription: TagSubscription,
+) -> ExternResult<ValidateCallbackResult> {
+    if tag_subscription.tag.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "TagSubscription tag cannot be empty",
+        )));
+    }
+    if normalize_tag(&tag_subscription.tag) != tag_subscription.tag {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "TagSubscription tag must be normalized",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_tag_subscription(
+    _action: Update,
+    _tag_subscription: TagSubscription,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "TagSubscription entries cannot be updated",
+    )))
+}
+
+/// Private subscription to a publication: I'm notified when shares of URLs
+/// on the domain appear in feeds I belong to
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct DomainSubscription {
+    /// Normalized, see `url_domain`
+    pub domain: String,
+}
+
+pub fn validate_create_domain_subscription(
+    _action: EntryCreationAction,
+    domain_subscription: DomainSubscription,
+) -> ExternResult<ValidateCallbackResult> {
+    if url_domain(&domain_subscription.domain).as_ref() != Some(&domain_subscription.domain) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "DomainSubscription domain must be a normalized domain",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_domain_subscription(
+    _action: Update,
+    _domain_subscription: DomainSubscription,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "DomainSubscription entries cannot be updated",
+    )))
+}
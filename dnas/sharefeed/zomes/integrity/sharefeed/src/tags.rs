@@ -0,0 +1,71 @@
+use hdi::prelude::*;
+
+use crate::{must_get_linked_entry, ShareItem, SharefeedLinkTag};
+
+/// Canonical form of a share tag used by the tag index
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+/// Distinct normalized forms of `tags`, in the order they were given
+pub fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = normalize_tag(tag);
+        if !tag.is_empty() && !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized
+}
+
+/// Distinct normalized tags of a share, in the order they were given
+pub fn share_item_tags(share_item: &ShareItem) -> Vec<String> {
+    normalize_tags(&share_item.tags)
+}
+
+/// A TagTimeIndex link records one use of a tag by a share: its link tag
+/// must name one of the share's own tags
+pub fn validate_create_link_tag_time_index(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, share_item) = must_get_linked_entry::<ShareItem>(
+        target_address,
+        "Linked action must reference a ShareItem entry",
+    )?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author of a share can index its tags",
+        )));
+    }
+    let Some(share_tag) = SharefeedLinkTag::from_link_tag(&tag).and_then(|link_tag| link_tag.tag)
+    else {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "TagTimeIndex links must name the tag they index",
+        )));
+    };
+    if !share_item_tags(&share_item).contains(&share_tag) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "TagTimeIndex link must name one of the share's tags",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_tag_time_index(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the author can remove their tag index links",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
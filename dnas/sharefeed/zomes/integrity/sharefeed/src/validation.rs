@@ -0,0 +1,77 @@
+//! Building blocks for validation rules.
+//!
+//! Every peer must reach the same verdict on an op whenever it validates
+//! it, so validation only reads data addressed by hash through `must_get_*`:
+//! records, actions, and an author's own chain up to the action under
+//! validation. It never looks at links, the current time, or anything else
+//! that depends on how much of the DHT a peer has seen.
+//!
+//! Rules that need context are inductive instead: an op is checked against
+//! the records it references, which were validated in turn. For example,
+//! "authored by a steward" means a steward of the exact Feed record the op
+//! names, not of whatever the latest version of that feed says, and
+//! "not a duplicate" means not already on the author's chain before this
+//! action.
+
+use hdi::prelude::*;
+
+use crate::Feed;
+
+/// The valid record at `action_hash` and its app entry. `error` is
+/// returned if the record holds no entry of type `T`.
+pub fn must_get_entry<T>(action_hash: ActionHash, error: &str) -> ExternResult<(Record, T)>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    let record = must_get_valid_record(action_hash)?;
+    let entry: T = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(error))))?;
+    Ok((record, entry))
+}
+
+/// Like `must_get_entry`, for link bases and targets
+pub fn must_get_linked_entry<T>(address: AnyLinkableHash, error: &str) -> ExternResult<(Record, T)>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    let action_hash = ActionHash::try_from(address).map_err(|err| wasm_error!(err))?;
+    must_get_entry(action_hash, error)
+}
+
+/// The author's chain from their first action up to and including
+/// `prev_action`, i.e. everything before the action being validated
+pub fn must_get_chain_before(
+    author: &AgentPubKey,
+    prev_action: &ActionHash,
+) -> ExternResult<Vec<ActionHashed>> {
+    let activity = must_get_agent_activity(author.clone(), ChainFilter::new(prev_action.clone()))?;
+    Ok(activity
+        .into_iter()
+        .map(|item| item.action.hashed)
+        .collect())
+}
+
+/// The author's actions up to and including `prev_action` from `since` on,
+/// newest first. Chain timestamps never go backwards, so the walk stops at
+/// the first older action.
+pub fn must_get_chain_since(
+    author: &AgentPubKey,
+    prev_action: &ActionHash,
+    since: Timestamp,
+) -> ExternResult<Vec<ActionHashed>> {
+    let mut chain = must_get_chain_before(author, prev_action)?;
+    chain.sort_by_key(|action_hashed| std::cmp::Reverse(action_hashed.content.action_seq()));
+    Ok(chain
+        .into_iter()
+        .take_while(|action_hashed| action_hashed.content.timestamp() >= since)
+        .collect())
+}
+
+/// Stewards listed in the Feed record at `feed_hash`
+pub fn must_get_feed_stewards(feed_hash: ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    let (_, feed) = must_get_entry::<Feed>(feed_hash, "Action must reference a Feed entry")?;
+    Ok(feed.stewards)
+}
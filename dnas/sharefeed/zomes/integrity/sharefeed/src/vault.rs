@@ -0,0 +1,36 @@
+use hdi::prelude::*;
+use schemars::JsonSchema;
+
+use crate::ActionHashSchema;
+
+/// Private note that one of the shares I can see was copied to the
+/// long-term archive vault DNA, and where the copy lives there
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, JsonSchema)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct VaultArchive {
+    /// Original action hash of the archived share
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub share_item_hash: ActionHash,
+    /// Hash of the archived copy in the vault DNA
+    #[cfg_attr(feature = "ts", ts(type = "ActionHash"))]
+    #[schemars(with = "ActionHashSchema")]
+    pub vault_hash: ActionHash,
+}
+
+pub fn validate_create_vault_archive(
+    _action: EntryCreationAction,
+    _vault_archive: VaultArchive,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_vault_archive(
+    _action: Update,
+    _vault_archive: VaultArchive,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "VaultArchive entries cannot be updated",
+    )))
+}
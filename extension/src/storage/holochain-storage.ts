@@ -335,7 +335,11 @@ export class HolochainStorageAdapter implements StorageAdapter {
     const hcShare = this.toHcShareItem(share);
     console.log('[Holochain] Saving share:', hcShare);
     try {
-      const result = await this.callZome('create_share_item', hcShare);
+      // Idempotent so a retried save doesn't create a duplicate share
+      const result = await this.callZome('create_share_item_idempotent', {
+        share_item: hcShare,
+        window_secs: null,
+      });
       console.log('[Holochain] Share saved, result:', result);
     } catch (error) {
       console.error('[Holochain] Failed to save share:', error);
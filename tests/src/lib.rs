@@ -23,6 +23,14 @@ mod common {
     pub async fn load_dna() -> DnaFile {
         SweetDnaFile::from_bundle(&dna_path()).await.unwrap()
     }
+
+    /// Helper to load the ShareFeed DNA with network properties, for
+    /// limits and features the bundled DNA leaves off
+    pub async fn load_dna_with_properties(properties: SerializedBytes) -> DnaFile {
+        load_dna()
+            .await
+            .update_modifiers(DnaModifiersOpt::none().with_properties(properties))
+    }
 }
 
 // Define types that match zome types for serialization
@@ -30,6 +38,30 @@ mod common {
 mod types {
     use holochain_types::prelude::*;
 
+    /// DNA properties set by tests; properties left as `None` keep the
+    /// network's defaults
+    #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, SerializedBytes)]
+    pub struct NetworkProperties {
+        pub blocked_domains: Vec<String>,
+        pub max_shares_per_hour: Option<usize>,
+        pub max_shares_per_url_per_day: Option<usize>,
+        pub moderators: Vec<AgentPubKeyB64>,
+        pub progenitors: Vec<AgentPubKeyB64>,
+        pub home_feed: Option<String>,
+    }
+
+    /// Properties with a limit of the wrong type
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, SerializedBytes)]
+    pub struct MalformedProperties {
+        pub max_shares_per_hour: String,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct NetworkBlocklist {
+        pub banned_agents: Vec<AgentPubKey>,
+        pub banned_domains: Vec<String>,
+    }
+
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
     pub struct ShareItem {
         pub url: String,
@@ -41,6 +73,45 @@ mod types {
         pub tags: Vec<String>,
     }
 
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    #[serde(tag = "type")]
+    pub enum ShareKind {
+        Note,
+    }
+
+    /// A ShareItem with its kind and note body
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct NoteShareItem {
+        pub url: String,
+        pub title: String,
+        pub tags: Vec<String>,
+        pub kind: ShareKind,
+        pub body: Option<String>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ShareTranslation {
+        pub title: String,
+        pub description: Option<String>,
+    }
+
+    /// A ShareItem with its title and description in other languages
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct TranslatedShareItem {
+        pub url: String,
+        pub title: String,
+        pub description: Option<String>,
+        pub tags: Vec<String>,
+        pub translations: std::collections::BTreeMap<String, ShareTranslation>,
+    }
+
+    /// Read input asking for shares in a language
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct LocalizedInput<T> {
+        pub input: T,
+        pub language: String,
+    }
+
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
     pub struct Feed {
         pub name: String,
@@ -49,12 +120,32 @@ mod types {
         pub is_public: bool,
     }
 
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    pub struct AuthorProfile {
+        pub nickname: String,
+        pub avatar: Option<String>,
+    }
+
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
     pub struct ShareItemInfo {
         pub action_hash: ActionHash,
         pub share_item: ShareItem,
         pub created_at: Timestamp,
         pub author: AgentPubKey,
+        pub author_profile: Option<AuthorProfile>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CreateShareItemIdempotentInput {
+        pub share_item: ShareItem,
+        pub window_secs: Option<i64>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct UpdateShareItemInput {
+        pub original_share_item_hash: ActionHash,
+        pub previous_share_item_hash: ActionHash,
+        pub updated_share_item: ShareItem,
     }
 
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -69,101 +160,5655 @@ mod types {
         pub feed_hash: ActionHash,
         pub share_item_hash: ActionHash,
     }
-}
 
-#[cfg(test)]
-mod share_item_tests {
-    use crate::common::load_dna;
-    use crate::types::*;
-    use holochain::sweettest::SweetConductor;
-    use holochain_types::prelude::*;
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct RemoveShareFromFeedInput {
+        pub link_hash: ActionHash,
+    }
 
-    #[tokio::test(flavor = "multi_thread")]
-    async fn can_create_and_get_share_item() {
-        holochain_trace::test_run();
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FeedShareLinkInfo {
+        pub link_hash: ActionHash,
+        pub share_item_hash: ActionHash,
+        pub added_by: AgentPubKey,
+        pub added_at: Timestamp,
+    }
 
-        let mut conductor = SweetConductor::from_standard_config().await;
-        let dna = load_dna().await;
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct FeedMembersCursor {
+        pub added_at: Timestamp,
+        pub create_link_hash: ActionHash,
+    }
 
-        let app = conductor
-            .setup_app("sharefeed", [&dna])
-            .await
-            .unwrap();
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FeedMembersPageInput {
+        pub feed_hash: ActionHash,
+        pub cursor: Option<FeedMembersCursor>,
+        pub limit: Option<usize>,
+    }
 
-        let (cell,) = app.into_tuple();
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FeedMember {
+        pub agent: AgentPubKey,
+        pub added_at: Timestamp,
+    }
 
-        let share_item = ShareItem {
-            url: "https://example.com/article".to_string(),
-            title: "Test Article".to_string(),
-            description: Some("A test article description".to_string()),
-            selection: None,
-            favicon: None,
-            thumbnail: None,
-            tags: vec!["test".to_string()],
-        };
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FeedMembersPage {
+        pub members: Vec<FeedMember>,
+        pub next_cursor: Option<FeedMembersCursor>,
+    }
 
-        // Create a share item
-        let record: Record = conductor
-            .call(&cell.zome("sharefeed"), "create_share_item", share_item.clone())
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct SearchSharesInput {
+        pub query: String,
+        pub cursor: Option<Timestamp>,
+        pub limit: Option<usize>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct SearchResultsPage {
+        pub shares: Vec<ShareItemInfo>,
+        pub next_cursor: Option<Timestamp>,
+    }
+
+    /// A Feed with its seen receipts setting
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct SeenReceiptsFeed {
+        pub name: String,
+        pub description: Option<String>,
+        pub stewards: Vec<AgentPubKey>,
+        pub is_public: bool,
+        pub seen_receipts: bool,
+    }
+
+    /// A Feed with tags merged into every share added to it
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct DefaultTagsFeed {
+        pub name: String,
+        pub description: Option<String>,
+        pub stewards: Vec<AgentPubKey>,
+        pub is_public: bool,
+        pub default_tags: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct AnnouncingFeed {
+        pub name: String,
+        pub description: Option<String>,
+        pub stewards: Vec<AgentPubKey>,
+        pub is_public: bool,
+        pub announce_new_members: bool,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct QuorumFeed {
+        pub name: String,
+        pub description: Option<String>,
+        pub stewards: Vec<AgentPubKey>,
+        pub is_public: bool,
+        pub steward_quorum: Option<u32>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    #[serde(tag = "type")]
+    pub enum KeywordFilterAction {
+        Hold,
+        Reject,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct KeywordFilter {
+        pub keyword: String,
+        pub action: KeywordFilterAction,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FilteredFeed {
+        pub name: String,
+        pub description: Option<String>,
+        pub stewards: Vec<AgentPubKey>,
+        pub is_public: bool,
+        pub keyword_filters: Vec<KeywordFilter>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Default)]
+    pub struct OnboardingState {
+        pub completed_steps: Vec<String>,
+        pub dismissed_tips: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct SeenReceiptInput {
+        pub feed_hash: ActionHash,
+        pub share_item_hash: ActionHash,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct SeenReceipt {
+        pub agent: AgentPubKey,
+        pub seen_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct Compilation {
+        pub feed_hash: ActionHash,
+        pub title: String,
+        pub note: Option<String>,
+        pub year: i64,
+        pub week: u32,
+        pub share_item_hashes: Vec<ActionHash>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CompilationInfo {
+        pub compilation_hash: ActionHash,
+        pub compilation: Compilation,
+        pub steward: AgentPubKey,
+        pub created_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct SavedItem {
+        pub share_item_hash: ActionHash,
+        pub position: u32,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct SavedItemInfo {
+        pub saved_item_hash: ActionHash,
+        pub saved_item: SavedItem,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CuratorNote {
+        pub feed_hash: ActionHash,
+        pub share_item_hash: ActionHash,
+        pub content: String,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CuratorNotesInput {
+        pub feed_hash: ActionHash,
+        pub share_item_hash: ActionHash,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CuratorNoteInfo {
+        pub curator_note_hash: ActionHash,
+        pub curator_note: CuratorNote,
+        pub steward: AgentPubKey,
+        pub created_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    #[serde(tag = "type")]
+    pub enum StewardProposalKind {
+        DeleteFeed,
+        MakePublic { revision: ActionHash },
+        RemoveShares { share_item_hashes: Vec<ActionHash> },
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct StewardProposal {
+        pub feed_hash: ActionHash,
+        pub kind: StewardProposalKind,
+        pub note: Option<String>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct StewardProposalInfo {
+        pub proposal_hash: ActionHash,
+        pub steward_proposal: StewardProposal,
+        pub proposer: AgentPubKey,
+        pub created_at: Timestamp,
+        pub approvals: Vec<AgentPubKey>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct UpdateFeedInput {
+        pub original_feed_hash: ActionHash,
+        pub previous_feed_hash: ActionHash,
+        pub updated_feed: Feed,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct UpdateQuorumFeedInput {
+        pub original_feed_hash: ActionHash,
+        pub previous_feed_hash: ActionHash,
+        pub updated_feed: QuorumFeed,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct TimelineInput {
+        pub cursor: Option<Timestamp>,
+        pub limit: Option<usize>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FeedPageInput {
+        pub feed_hash: ActionHash,
+        pub cursor: Option<Timestamp>,
+        pub limit: Option<usize>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FeedPage {
+        pub feed: FeedInfo,
+        pub member_count: u32,
+        pub shares: SearchResultsPage,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct SearchTitlesInput {
+        pub fragment: String,
+        pub limit: Option<usize>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FeedSharesChunkInput {
+        pub feed_hash: ActionHash,
+        pub chunk_index: usize,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FeedSharesChunk {
+        pub shares: Vec<ShareItemInfo>,
+        pub chunk_index: usize,
+        pub total_chunks: usize,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ImportBookmarksInput {
+        pub html: String,
+        pub target_feed: Option<ActionHash>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    #[serde(tag = "type")]
+    pub enum ImportItemStatus {
+        Imported { share_item_hash: ActionHash },
+        Skipped { reason: String },
+        Failed { error: String },
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ImportItemResult {
+        pub url: String,
+        pub title: String,
+        pub status: ImportItemStatus,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ImportReport {
+        pub imported: usize,
+        pub skipped: usize,
+        pub failed: usize,
+        pub items: Vec<ImportItemResult>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ExportedEntry<T> {
+        pub action_hash: ActionHash,
+        pub created_at: Timestamp,
+        pub entry: T,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ExportedFeedShare {
+        pub feed_hash: ActionHash,
+        pub share_item_hash: ActionHash,
+        pub added_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct PersonalDataExport {
+        pub version: u32,
+        pub agent: AgentPubKey,
+        pub share_items: Vec<ExportedEntry<ShareItem>>,
+        pub feeds: Vec<ExportedEntry<Feed>>,
+        pub feed_shares: Vec<ExportedFeedShare>,
+        pub comments: Vec<ExportedEntry<Comment>>,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    #[serde(tag = "type")]
+    pub enum ResolvedDeeplink {
+        Feed { feed: FeedInfo },
+        Share { share: ShareItemInfo },
+        Agent { summary: serde::de::IgnoredAny },
+    }
+
+    /// Only the names are checked; the schemas themselves are skipped
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ExternSchema {
+        pub name: String,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct ApiSchema {
+        pub dna_hash: DnaHash,
+        pub zome: String,
+        pub externs: Vec<ExternSchema>,
+        pub definitions: std::collections::BTreeMap<String, serde::de::IgnoredAny>,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct InstanceInfo {
+        pub agent: AgentPubKey,
+        pub dna_hash: DnaHash,
+        pub integrity_version: String,
+        pub coordinator_version: String,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct AnchorHealth {
+        pub anchor: String,
+        pub exists: bool,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct CapabilityGrantInfo {
+        pub grant_hash: ActionHash,
+        pub tag: String,
+        pub functions: Vec<String>,
+        pub assignees: Vec<AgentPubKey>,
+        pub created_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct HealthReport {
+        pub my_shares: usize,
+        pub my_feeds: usize,
+        pub scheduled_tasks: Vec<String>,
+        pub anchors: Vec<AnchorHealth>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ArchiveToVaultInput {
+        pub share_item_hash: ActionHash,
+        pub snapshot: Option<String>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    #[serde(tag = "type")]
+    pub enum ReportReason {
+        Spam,
+        Abuse,
+        Harassment,
+        Misinformation,
+        OffTopic,
+        Other,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct Report {
+        pub share_item_hash: ActionHash,
+        pub feed_hash: ActionHash,
+        pub reason: ReportReason,
+        pub note: Option<String>,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct ReportInfo {
+        pub report_hash: ActionHash,
+        pub report: Report,
+        pub reporter: AgentPubKey,
+        pub created_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    #[serde(tag = "type")]
+    pub enum ReportOutcome {
+        Dismissed,
+        ShareRemoved,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ResolveReportInput {
+        pub report_hash: ActionHash,
+        pub outcome: ReportOutcome,
+        pub note: Option<String>,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct ModerationQueue {
+        pub open_reports: Vec<ReportInfo>,
+        pub pending_shares: Vec<ShareItemInfo>,
+        pub open_appeals: Vec<AppealInfo>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct Appeal {
+        pub moderation_action_hash: ActionHash,
+        pub reason: String,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct AppealInfo {
+        pub appeal_hash: ActionHash,
+        pub appeal: Appeal,
+        pub appellant: AgentPubKey,
+        pub decision: Option<serde::de::IgnoredAny>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct DecideAppealInput {
+        pub appeal_hash: ActionHash,
+        pub note: Option<String>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct Ban {
+        pub feed_hash: ActionHash,
+        pub agent: AgentPubKey,
+        pub reason: String,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct UnbanMemberInput {
+        pub feed_hash: ActionHash,
+        pub agent: AgentPubKey,
+        pub note: Option<String>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ModerationLogInput {
+        pub feed_hash: ActionHash,
+        pub cursor: Option<Timestamp>,
+        pub limit: Option<usize>,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct ModerationLogPage {
+        pub actions: Vec<serde::de::IgnoredAny>,
+        pub next_cursor: Option<Timestamp>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct AddMemberToFeedInput {
+        pub feed_hash: ActionHash,
+        pub member_pubkey: AgentPubKey,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    #[serde(tag = "type")]
+    pub enum NotificationKind {
+        NewShareInFeed,
+        AddedToFeed,
+        Mention,
+        Comment,
+        Reaction,
+        SavedSearchMatch,
+        TagSubscriptionMatch,
+        DomainSubscriptionMatch,
+        MemberJoined,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    pub struct Notification {
+        pub kind: NotificationKind,
+        pub from: AgentPubKey,
+        pub feed_hash: Option<ActionHash>,
+        pub share_item_hash: Option<ActionHash>,
+        pub read: bool,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct NotificationInfo {
+        pub notification_hash: ActionHash,
+        pub notification: Notification,
+        pub created_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct TagSubscription {
+        pub tag: String,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct TagSubscriptionInfo {
+        pub tag_subscription_hash: ActionHash,
+        pub tag_subscription: TagSubscription,
+        pub created_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct DomainSubscription {
+        pub domain: String,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct DomainSubscriptionInfo {
+        pub domain_subscription_hash: ActionHash,
+        pub domain_subscription: DomainSubscription,
+        pub created_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct GetNotificationsInput {
+        pub cursor: Option<Timestamp>,
+        pub limit: Option<usize>,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    pub struct Comment {
+        pub share_item_hash: ActionHash,
+        pub content: String,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    #[serde(tag = "type")]
+    pub enum NotificationLevel {
+        All,
+        MentionsOnly,
+        None,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct FeedNotificationLevel {
+        pub feed_hash: ActionHash,
+        pub level: NotificationLevel,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct QuietHours {
+        pub start_hour: u8,
+        pub end_hour: u8,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct NotificationPrefs {
+        pub default_level: NotificationLevel,
+        pub feed_levels: Vec<FeedNotificationLevel>,
+        pub quiet_hours: Option<QuietHours>,
+    }
+
+    /// The remote signals tests send by hand
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    #[serde(tag = "type")]
+    pub enum Signal {
+        AddedToFeed {
+            feed_hash: ActionHash,
+            added_by: AgentPubKey,
+        },
+        CommentAdded {
+            share_item_hash: ActionHash,
+            comment_hash: ActionHash,
+            author: AgentPubKey,
+        },
+        MemberJoined {
+            feed_hash: ActionHash,
+            member: AgentPubKey,
+            added_by: AgentPubKey,
+            member_link_hash: ActionHash,
+        },
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct CommentInfo {
+        pub comment_hash: ActionHash,
+        pub comment: Comment,
+        pub author: AgentPubKey,
+        pub author_profile: Option<AuthorProfile>,
+        pub created_at: Timestamp,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct Reaction {
+        pub share_item_hash: ActionHash,
+        pub emoji: String,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    pub struct ReactionCount {
+        pub emoji: String,
+        pub count: u32,
+        pub mine: bool,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct SharerFeed {
+        pub feed_hash: ActionHash,
+        pub name: String,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct ShareDetail {
+        pub share: ShareItemInfo,
+        pub feeds: Vec<SharerFeed>,
+        pub comment_count: u32,
+        pub reactions: Vec<ReactionCount>,
+        pub saved: bool,
+    }
+}
+
+#[cfg(test)]
+mod share_item_tests {
+    use crate::common::{load_dna, load_dna_with_properties};
+    use crate::types::*;
+    use holochain::sweettest::{SweetAgents, SweetConductor};
+    use holochain_types::prelude::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_create_and_get_share_item() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let share_item = ShareItem {
+            url: "https://example.com/article".to_string(),
+            title: "Test Article".to_string(),
+            description: Some("A test article description".to_string()),
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec!["test".to_string()],
+        };
+
+        // Create a share item
+        let record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", share_item.clone())
+            .await;
+
+        let action_hash = record.action_hashed().hash.clone();
+
+        // Get the share item
+        let retrieved: Option<Record> = conductor
+            .call(&cell.zome("sharefeed"), "get_share_item", action_hash.clone())
+            .await;
+
+        assert!(retrieved.is_some());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn stale_share_update_is_a_conflict() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let share_item = ShareItem {
+            url: "https://example.com/revised".to_string(),
+            title: "First draft".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        let record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", share_item.clone())
+            .await;
+        let original_hash = record.action_hashed().hash.clone();
+
+        let revised: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "update_share_item",
+                UpdateShareItemInput {
+                    original_share_item_hash: original_hash.clone(),
+                    previous_share_item_hash: original_hash.clone(),
+                    updated_share_item: ShareItem {
+                        title: "Second draft".to_string(),
+                        ..share_item.clone()
+                    },
+                },
+            )
+            .await;
+        let revised_hash = revised.action_hashed().hash.clone();
+
+        // Updating the original again would fork the history
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "update_share_item",
+                UpdateShareItemInput {
+                    original_share_item_hash: original_hash.clone(),
+                    previous_share_item_hash: original_hash,
+                    updated_share_item: ShareItem {
+                        title: "Forked draft".to_string(),
+                        ..share_item
+                    },
+                },
+            )
+            .await;
+
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("Conflict"));
+        assert!(error.contains(&ActionHashB64::from(revised_hash).to_string()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn share_url_cannot_change_on_update() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let share_item = ShareItem {
+            url: "https://example.com/story?utm_source=newsletter".to_string(),
+            title: "A story".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        let record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", share_item.clone())
+            .await;
+        let original_hash = record.action_hashed().hash.clone();
+
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "update_share_item",
+                UpdateShareItemInput {
+                    original_share_item_hash: original_hash.clone(),
+                    previous_share_item_hash: original_hash.clone(),
+                    updated_share_item: ShareItem {
+                        url: "https://example.com/another-story".to_string(),
+                        ..share_item.clone()
+                    },
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("URL cannot be changed"));
+
+        // Dropping tracking parameters keeps the same normalized URL
+        let _: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "update_share_item",
+                UpdateShareItemInput {
+                    original_share_item_hash: original_hash.clone(),
+                    previous_share_item_hash: original_hash,
+                    updated_share_item: ShareItem {
+                        url: "https://example.com/story".to_string(),
+                        ..share_item
+                    },
+                },
+            )
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn opens_are_counted_once_per_agent() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/popular".to_string(),
+                    title: "Popular".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
+            .await;
+        let share_hash = record.action_hashed().hash.clone();
+
+        let open_count: usize = conductor
+            .call(&cell.zome("sharefeed"), "get_open_count", share_hash.clone())
+            .await;
+        assert_eq!(open_count, 0);
+
+        for _ in 0..2 {
+            let _: () = conductor
+                .call(&cell.zome("sharefeed"), "record_open", share_hash.clone())
+                .await;
+        }
+
+        let open_count: usize = conductor
+            .call(&cell.zome("sharefeed"), "get_open_count", share_hash)
+            .await;
+        assert_eq!(open_count, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn pruning_removes_links_to_deleted_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let share_item = ShareItem {
+            url: "https://example.com/gone".to_string(),
+            title: "Gone soon".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec!["ephemeral".to_string()],
+        };
+
+        let record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+            .await;
+
+        let _: ActionHash = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "delete_share_item",
+                record.action_hashed().hash.clone(),
+            )
+            .await;
+
+        let pruned: usize = conductor
+            .call(&cell.zome("sharefeed"), "prune_orphaned_links", ())
+            .await;
+
+        assert!(pruned > 0);
+
+        // Nothing is left to prune the second time round
+        let pruned: usize = conductor
+            .call(&cell.zome("sharefeed"), "prune_orphaned_links", ())
+            .await;
+
+        assert_eq!(pruned, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_resolve_share_deeplink() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let share_item = ShareItem {
+            url: "https://example.com/linked".to_string(),
+            title: "Linked Article".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        let record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+            .await;
+
+        let action_hash = record.action_hashed().hash.clone();
+        let path = format!("sharefeed://share/{}", ActionHashB64::from(action_hash.clone()));
+
+        let resolved: ResolvedDeeplink = conductor
+            .call(&cell.zome("sharefeed"), "resolve_deeplink", path)
+            .await;
+
+        match resolved {
+            ResolvedDeeplink::Share { share } => {
+                assert_eq!(share.action_hash, action_hash);
+                assert_eq!(share.share_item.title, "Linked Article");
+                assert_eq!(&share.author, cell.agent_pubkey());
+            }
+            other => panic!("Expected a share, got {:?}", other),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn archive_to_vault_needs_vault_role() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let share_item = ShareItem {
+            url: "https://example.com/archive".to_string(),
+            title: "Archived Article".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        let record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+            .await;
+
+        // This app has no archive DNA installed, so the bridge call fails
+        // and nothing is recorded
+        let result: Result<serde::de::IgnoredAny, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "archive_to_vault",
+                ArchiveToVaultInput {
+                    share_item_hash: record.action_hashed().hash.clone(),
+                    snapshot: Some("Archived text".to_string()),
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+
+        let archive: Option<serde::de::IgnoredAny> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_vault_archive",
+                record.action_hashed().hash.clone(),
+            )
+            .await;
+
+        assert!(archive.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn shares_are_read_in_the_requested_language() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let translations = std::collections::BTreeMap::from([(
+            "de".to_string(),
+            ShareTranslation {
+                title: "Ein guter Artikel".to_string(),
+                description: None,
+            },
+        )]);
+        let record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                TranslatedShareItem {
+                    url: "https://example.com/multilingual".to_string(),
+                    title: "A good article".to_string(),
+                    description: Some("Worth reading".to_string()),
+                    tags: vec![],
+                    translations: translations.clone(),
+                },
+            )
+            .await;
+        let share_hash = record.action_hashed().hash.clone();
+
+        // A regional variant falls back to its base language
+        let detail: Option<ShareDetail> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_share_detail",
+                LocalizedInput {
+                    input: share_hash.clone(),
+                    language: "de-AT".to_string(),
+                },
+            )
+            .await;
+        let share_item = detail.unwrap().share.share_item;
+        assert_eq!(share_item.title, "Ein guter Artikel");
+        assert_eq!(share_item.description, None);
+
+        let detail: Option<ShareDetail> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_share_detail",
+                LocalizedInput {
+                    input: share_hash,
+                    language: "fr".to_string(),
+                },
+            )
+            .await;
+        assert_eq!(detail.unwrap().share.share_item.title, "A good article");
+
+        let mut bad_translations = translations;
+        bad_translations.insert(
+            "not a language".to_string(),
+            ShareTranslation {
+                title: "Oops".to_string(),
+                description: None,
+            },
+        );
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                TranslatedShareItem {
+                    url: "https://example.com/bad-language".to_string(),
+                    title: "Bad language code".to_string(),
+                    description: None,
+                    tags: vec![],
+                    translations: bad_translations,
+                },
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reading_queue_can_be_reordered() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let mut share_hashes: Vec<ActionHash> = Vec::new();
+        for url in [
+            "https://example.com/one",
+            "https://example.com/two",
+            "https://example.com/three",
+        ] {
+            let record: Record = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "create_share_item",
+                    ShareItem {
+                        url: url.to_string(),
+                        title: "Queued".to_string(),
+                        description: None,
+                        selection: None,
+                        favicon: None,
+                        thumbnail: None,
+                        tags: vec![],
+                    },
+                )
+                .await;
+            let share_hash = record.action_hashed().hash.clone();
+            let _: SavedItemInfo = conductor
+                .call(&cell.zome("sharefeed"), "save_for_later", share_hash.clone())
+                .await;
+            share_hashes.push(share_hash);
+        }
+
+        let reordered: Vec<SavedItemInfo> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "reorder_saved",
+                vec![
+                    share_hashes[2].clone(),
+                    share_hashes[0].clone(),
+                    share_hashes[1].clone(),
+                ],
+            )
+            .await;
+        assert_eq!(reordered.len(), 3);
+
+        let _: () = conductor
+            .call(&cell.zome("sharefeed"), "remove_saved", share_hashes[0].clone())
+            .await;
+
+        let saved: Vec<SavedItemInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_saved", ())
+            .await;
+        let queue: Vec<ActionHash> = saved
+            .into_iter()
+            .map(|info| info.saved_item.share_item_hash)
+            .collect();
+        assert_eq!(queue, vec![share_hashes[2].clone(), share_hashes[1].clone()]);
+
+        // An order that leaves out a saved share is refused
+        let result: Result<Vec<SavedItemInfo>, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "reorder_saved",
+                vec![share_hashes[1].clone()],
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_get_recent_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        // Create multiple share items
+        for i in 0..3 {
+            let share_item = ShareItem {
+                url: format!("https://example.com/article-{}", i),
+                title: format!("Test Article {}", i),
+                description: None,
+                selection: None,
+                favicon: None,
+                thumbnail: None,
+                tags: vec![],
+            };
+
+            let _record: Record = conductor
+                .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+                .await;
+        }
+
+        // Time index links are written in post_commit, after the call returns
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        // Get recent shares
+        let shares: Vec<ShareItemInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_recent_shares", ())
+            .await;
+
+        assert_eq!(shares.len(), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_search_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let titles = ["Rust ownership explained", "Gardening with rust-resistant roses"];
+        for (i, title) in titles.iter().enumerate() {
+            let share_item = ShareItem {
+                url: format!("https://example.com/search-{}", i),
+                title: title.to_string(),
+                description: None,
+                selection: None,
+                favicon: None,
+                thumbnail: None,
+                tags: vec![],
+            };
+
+            let _record: Record = conductor
+                .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+                .await;
+        }
+
+        // Search index links are written in post_commit, after the call returns
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        // Every word must match, in any case
+        let page: SearchResultsPage = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "search_shares",
+                SearchSharesInput {
+                    query: "RUST ownership".to_string(),
+                    cursor: None,
+                    limit: None,
+                },
+            )
+            .await;
+
+        assert_eq!(page.shares.len(), 1);
+        assert_eq!(page.shares[0].share_item.title, "Rust ownership explained");
+        assert!(page.next_cursor.is_none());
+
+        let page: SearchResultsPage = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "search_shares",
+                SearchSharesInput {
+                    query: "rust".to_string(),
+                    cursor: None,
+                    limit: None,
+                },
+            )
+            .await;
+
+        assert_eq!(page.shares.len(), 2);
+
+        // Title search matches partial words
+        let shares: Vec<ShareItemInfo> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "search_titles",
+                SearchTitlesInput {
+                    fragment: "owner".to_string(),
+                    limit: None,
+                },
+            )
+            .await;
+
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares[0].share_item.title, "Rust ownership explained");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn shares_visible_across_agents() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        // Set up two agents
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        // Agent 1 creates a share
+        let share_item = ShareItem {
+            url: "https://example.com/shared-article".to_string(),
+            title: "Cross-Agent Test".to_string(),
+            description: Some("Testing cross-agent visibility".to_string()),
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec!["test".to_string()],
+        };
+
+        let _record: Record = conductor
+            .call(&cell1.zome("sharefeed"), "create_share_item", share_item.clone())
+            .await;
+
+        // Wait for gossip to propagate (sweettest uses same conductor so this is fast)
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        // Agent 2 should be able to see Agent 1's share
+        let shares: Vec<ShareItemInfo> = conductor
+            .call(&cell2.zome("sharefeed"), "get_recent_shares", ())
+            .await;
+
+        assert_eq!(shares.len(), 1, "Agent 2 should see Agent 1's share");
+        assert_eq!(shares[0].share_item.url, "https://example.com/shared-article");
+        assert_eq!(shares[0].share_item.title, "Cross-Agent Test");
+
+        // Verify the author is Agent 1's pubkey
+        assert_eq!(shares[0].author, *cell1.agent_pubkey());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn share_item_requires_url_and_title() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        // Try to create with empty URL - should fail validation
+        let invalid_share = ShareItem {
+            url: "".to_string(),
+            title: "Test".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        let result: Result<Record, _> = conductor
+            .call_fallible(&cell.zome("sharefeed"), "create_share_item", invalid_share)
+            .await;
+
+        // Rejected by the coordinator before anything is committed
+        let err = result.unwrap_err();
+        assert!(format!("{:?}", err).contains("InvalidInput"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_post_text_note() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let note = NoteShareItem {
+            url: "".to_string(),
+            title: "Question".to_string(),
+            tags: vec![],
+            kind: ShareKind::Note,
+            body: Some("Has anyone read the new paper on CRDTs?".to_string()),
+        };
+
+        let record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", note.clone())
+            .await;
+        let detail: Option<ShareDetail> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_share_detail",
+                record.action_hashed().hash.clone(),
+            )
+            .await;
+        let detail = detail.expect("note should be found");
+
+        assert_eq!(detail.share.share_item.title, "Question");
+        assert!(detail.share.share_item.url.is_empty());
+
+        // A note needs text
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                NoteShareItem {
+                    body: Some("   ".to_string()),
+                    ..note
+                },
+            )
+            .await;
+
+        let err = result.unwrap_err();
+        assert!(format!("{:?}", err).contains("InvalidInput"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn idempotent_shares_return_the_recent_share() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let input = |window_secs: Option<i64>| CreateShareItemIdempotentInput {
+            share_item: ShareItem {
+                url: "https://example.com/article".to_string(),
+                title: "Article".to_string(),
+                description: None,
+                selection: None,
+                favicon: None,
+                thumbnail: None,
+                tags: vec![],
+            },
+            window_secs,
+        };
+
+        // A window has to be positive
+        for window_secs in [0, -60, i64::MAX] {
+            let result: Result<Record, _> = conductor
+                .call_fallible(
+                    &cell.zome("sharefeed"),
+                    "create_share_item_idempotent",
+                    input(Some(window_secs)),
+                )
+                .await;
+            let error = format!("{:?}", result.unwrap_err());
+            assert!(error.contains("window_secs must be a positive number of seconds"));
+        }
+
+        let first: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item_idempotent",
+                input(None),
+            )
+            .await;
+
+        // URL index links are written in post_commit, after the call returns
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        let again: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item_idempotent",
+                input(Some(60)),
+            )
+            .await;
+        assert_eq!(again.action_hashed().hash, first.action_hashed().hash);
+
+        // A revised share comes back as its latest revision
+        let first_hash = first.action_hashed().hash.clone();
+        let revised: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "update_share_item",
+                UpdateShareItemInput {
+                    original_share_item_hash: first_hash.clone(),
+                    previous_share_item_hash: first_hash.clone(),
+                    updated_share_item: ShareItem {
+                        title: "Revised Article".to_string(),
+                        ..input(None).share_item
+                    },
+                },
+            )
+            .await;
+        let again: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item_idempotent",
+                input(None),
+            )
+            .await;
+        assert_eq!(again.action_hashed().hash, revised.action_hashed().hash);
+
+        // Once deleted, the URL is shared anew
+        let _: ActionHash = conductor
+            .call(&cell.zome("sharefeed"), "delete_share_item", first_hash.clone())
+            .await;
+        let fresh: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item_idempotent",
+                input(None),
+            )
+            .await;
+        assert_ne!(fresh.action_hashed().hash, first_hash);
+        assert_ne!(fresh.action_hashed().hash, revised.action_hashed().hash);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn only_the_author_updates_or_deletes_a_share() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let share_item = ShareItem {
+            url: "https://example.com/article".to_string(),
+            title: "Article".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+        let share_record: Record = conductor
+            .call(&cell1.zome("sharefeed"), "create_share_item", share_item.clone())
+            .await;
+        let share_hash = share_record.action_hashed().hash.clone();
+
+        let update = UpdateShareItemInput {
+            original_share_item_hash: share_hash.clone(),
+            previous_share_item_hash: share_hash.clone(),
+            updated_share_item: ShareItem {
+                title: "Hijacked".to_string(),
+                ..share_item.clone()
+            },
+        };
+        let mut result: Result<Record, _> = Ok(share_record.clone());
+        for _ in 0..20 {
+            result = conductor
+                .call_fallible(&cell2.zome("sharefeed"), "update_share_item", update.clone())
+                .await;
+            if format!("{:?}", result).contains("Only the author of a share can update it") {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("Only the author of a share can update it"));
+
+        let result: Result<ActionHash, _> = conductor
+            .call_fallible(&cell2.zome("sharefeed"), "delete_share_item", share_hash.clone())
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("Only the author of a share can delete it"));
+
+        let _: ActionHash = conductor
+            .call(&cell1.zome("sharefeed"), "delete_share_item", share_hash)
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn peers_get_public_records_but_not_private_ones() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let share_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/article".to_string(),
+                    title: "Article".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
+            .await;
+        let onboarding_hash: ActionHash = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "set_onboarding_state",
+                OnboardingState {
+                    completed_steps: vec!["welcome".to_string()],
+                    dismissed_tips: vec![],
+                },
+            )
+            .await;
+
+        let shared: Option<Record> = conductor
+            .call_from(
+                cell2.agent_pubkey(),
+                None,
+                &cell1.zome("sharefeed"),
+                "get_record_for_peer",
+                share_record.action_hashed().hash.clone(),
+            )
+            .await;
+        assert_eq!(shared, Some(share_record));
+
+        let private: Option<Record> = conductor
+            .call_from(
+                cell2.agent_pubkey(),
+                None,
+                &cell1.zome("sharefeed"),
+                "get_record_for_peer",
+                onboarding_hash,
+            )
+            .await;
+        assert!(private.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn shares_are_limited_per_hour() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna_with_properties(
+            NetworkProperties {
+                max_shares_per_hour: Some(2),
+                ..Default::default()
+            }
+            .try_into()
+            .unwrap(),
+        )
+        .await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let share_item = |url: &str| ShareItem {
+            url: url.to_string(),
+            title: "Article".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        for url in ["https://example.com/one", "https://example.com/two"] {
+            let _: Record = conductor
+                .call(&cell.zome("sharefeed"), "create_share_item", share_item(url))
+                .await;
+        }
+
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                share_item("https://example.com/three"),
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("at most 2 items per hour"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn sharing_the_same_url_is_limited_per_day() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna_with_properties(
+            NetworkProperties {
+                max_shares_per_url_per_day: Some(2),
+                ..Default::default()
+            }
+            .try_into()
+            .unwrap(),
+        )
+        .await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let share_item = |url: &str, title: &str| ShareItem {
+            url: url.to_string(),
+            title: title.to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        // Tracking parameters don't make it a different link
+        for url in [
+            "https://example.com/promo",
+            "https://www.example.com/promo?utm_source=feed",
+        ] {
+            let _: Record = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "create_share_item",
+                    share_item(url, "Promo"),
+                )
+                .await;
+        }
+
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                share_item("https://example.com/promo/", "Promo again"),
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("at most 2 times a day"));
+
+        let _: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                share_item("https://example.com/other", "Other"),
+            )
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn blocked_domains_cannot_be_shared() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna_with_properties(
+            NetworkProperties {
+                blocked_domains: vec!["linkfarm.example".to_string()],
+                ..Default::default()
+            }
+            .try_into()
+            .unwrap(),
+        )
+        .await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let share_item = |url: &str| ShareItem {
+            url: url.to_string(),
+            title: "Article".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        // Subdomains of a blocked domain are blocked too
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                share_item("https://www.linkfarm.example/top-10"),
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("are blocked on this network"));
+
+        let _: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                share_item("https://example.com/article"),
+            )
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn malformed_properties_are_an_error() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna_with_properties(
+            MalformedProperties {
+                max_shares_per_hour: "ten".to_string(),
+            }
+            .try_into()
+            .unwrap(),
+        )
+        .await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/article".to_string(),
+                    title: "Article".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("Could not decode the DNA properties"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn network_blocklist_rejects_banned_agents_and_domains() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let (moderator, banned, member) = SweetAgents::three(conductor.keystore()).await;
+        let dna = load_dna_with_properties(
+            NetworkProperties {
+                moderators: vec![moderator.clone().into()],
+                ..Default::default()
+            }
+            .try_into()
+            .unwrap(),
+        )
+        .await;
+
+        let (cell1,) = conductor
+            .setup_app_for_agent("sharefeed-1", moderator.clone(), [&dna])
+            .await
+            .unwrap()
+            .into_tuple();
+        let (cell2,) = conductor
+            .setup_app_for_agent("sharefeed-2", banned.clone(), [&dna])
+            .await
+            .unwrap()
+            .into_tuple();
+        let (cell3,) = conductor
+            .setup_app_for_agent("sharefeed-3", member.clone(), [&dna])
+            .await
+            .unwrap()
+            .into_tuple();
+
+        let _: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "publish_network_blocklist",
+                NetworkBlocklist {
+                    banned_agents: vec![banned],
+                    banned_domains: vec!["spam.example".to_string()],
+                },
+            )
+            .await;
+
+        let share_item = |url: &str| ShareItem {
+            url: url.to_string(),
+            title: "Shared".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        // The moderator's own list applies to them too
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell1.zome("sharefeed"),
+                "create_share_item",
+                share_item("https://www.spam.example/offer"),
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("not allowed on this network"));
+
+        let mut result: Result<Record, _> = Err(());
+        for _ in 0..20 {
+            result = conductor
+                .call_fallible(
+                    &cell2.zome("sharefeed"),
+                    "create_share_item",
+                    share_item("https://example.com/post"),
+                )
+                .await
+                .map_err(|_| ());
+            if result.is_err() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        assert!(result.is_err());
+
+        // Everyone else shares and adds to feeds as before, naming the
+        // moderator's chain head
+        let share_record: Record = conductor
+            .call(
+                &cell3.zome("sharefeed"),
+                "create_share_item",
+                share_item("https://example.com/post"),
+            )
+            .await;
+        let feed_record: Record = conductor
+            .call(
+                &cell3.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Clean Feed".to_string(),
+                    description: None,
+                    stewards: vec![member],
+                    is_public: true,
+                },
+            )
+            .await;
+        let _: () = conductor
+            .call(
+                &cell3.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_record.action_hashed().hash.clone(),
+                    share_item_hash: share_record.action_hashed().hash.clone(),
+                },
+            )
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn agents_banned_after_sharing_cannot_update_their_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let (moderator, banned) = SweetAgents::two(conductor.keystore()).await;
+        let dna = load_dna_with_properties(
+            NetworkProperties {
+                moderators: vec![moderator.clone().into()],
+                ..Default::default()
+            }
+            .try_into()
+            .unwrap(),
+        )
+        .await;
+
+        let (cell1,) = conductor
+            .setup_app_for_agent("sharefeed-1", moderator, [&dna])
+            .await
+            .unwrap()
+            .into_tuple();
+        let (cell2,) = conductor
+            .setup_app_for_agent("sharefeed-2", banned.clone(), [&dna])
+            .await
+            .unwrap()
+            .into_tuple();
+
+        let share_item = ShareItem {
+            url: "https://example.com/post".to_string(),
+            title: "Shared".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+        let share_record: Record = conductor
+            .call(&cell2.zome("sharefeed"), "create_share_item", share_item.clone())
+            .await;
+        let share_hash = share_record.action_hashed().hash.clone();
+
+        let _: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "publish_network_blocklist",
+                NetworkBlocklist {
+                    banned_agents: vec![banned],
+                    banned_domains: vec![],
+                },
+            )
+            .await;
+
+        // Updates are checked against the list as it is now, not as it was
+        // when the share was created
+        let update = UpdateShareItemInput {
+            original_share_item_hash: share_hash.clone(),
+            previous_share_item_hash: share_hash,
+            updated_share_item: ShareItem {
+                title: "Shared again".to_string(),
+                ..share_item
+            },
+        };
+        let mut result: Result<Record, _> = Ok(share_record.clone());
+        for _ in 0..20 {
+            result = conductor
+                .call_fallible(&cell2.zome("sharefeed"), "update_share_item", update.clone())
+                .await;
+            if format!("{:?}", result).contains("banned from the network") {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("banned from the network"));
+    }
+}
+
+#[cfg(test)]
+mod feed_tests {
+    use crate::common::{load_dna, load_dna_with_properties};
+    use crate::types::*;
+    use holochain::sweettest::{SweetAgents, SweetConductor};
+    use holochain_types::prelude::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_create_and_get_feed() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let agent_pubkey = cell.agent_pubkey().clone();
+
+        let feed = Feed {
+            name: "Family Links".to_string(),
+            description: Some("Links shared with family".to_string()),
+            stewards: vec![agent_pubkey],
+            is_public: false,
+        };
+
+        // Create a feed
+        let record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_feed", feed.clone())
+            .await;
+
+        let feed_hash = record.action_hashed().hash.clone();
+
+        // Get the feed
+        let retrieved: Option<Record> = conductor
+            .call(&cell.zome("sharefeed"), "get_feed", feed_hash)
+            .await;
+
+        assert!(retrieved.is_some());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_get_my_feeds() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let agent_pubkey = cell.agent_pubkey().clone();
+
+        // Create two feeds
+        for name in ["Feed 1", "Feed 2"] {
+            let feed = Feed {
+                name: name.to_string(),
+                description: None,
+                stewards: vec![agent_pubkey.clone()],
+                is_public: true,
+            };
+
+            let _record: Record = conductor
+                .call(&cell.zome("sharefeed"), "create_feed", feed)
+                .await;
+        }
+
+        // Get my feeds
+        let feeds: Vec<FeedInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_my_feeds", ())
+            .await;
+
+        assert_eq!(feeds.len(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_add_share_to_feed() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let agent_pubkey = cell.agent_pubkey().clone();
+
+        // Create a feed
+        let feed = Feed {
+            name: "Test Feed".to_string(),
+            description: None,
+            stewards: vec![agent_pubkey],
+            is_public: true,
+        };
+
+        let feed_record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_feed", feed)
+            .await;
+
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        // Create a share item
+        let share_item = ShareItem {
+            url: "https://example.com".to_string(),
+            title: "Example".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        let share_record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+            .await;
+
+        let share_hash = share_record.action_hashed().hash.clone();
+
+        // Add share to feed
+        let _: () = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hash,
+                },
+            )
+            .await;
+
+        // Get feed shares
+        let shares: Vec<ShareItemInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_feed_shares", feed_hash.clone())
+            .await;
+
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares[0].share_item.url, "https://example.com");
+
+        // A small feed fits in a single chunk
+        let chunk: FeedSharesChunk = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_feed_shares_chunk",
+                FeedSharesChunkInput {
+                    feed_hash,
+                    chunk_index: 0,
+                },
+            )
+            .await;
+
+        assert_eq!(chunk.total_chunks, 1);
+        assert_eq!(chunk.shares.len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn feed_page_pages_through_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Paged Feed".to_string(),
+                    description: None,
+                    stewards: vec![cell.agent_pubkey().clone()],
+                    is_public: true,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        for i in 0..3 {
+            let share_record: Record = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "create_share_item",
+                    ShareItem {
+                        url: format!("https://example.com/page/{}", i),
+                        title: format!("Page {}", i),
+                        description: None,
+                        selection: None,
+                        favicon: None,
+                        thumbnail: None,
+                        tags: vec![],
+                    },
+                )
+                .await;
+            let _: () = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "add_share_to_feed",
+                    AddShareToFeedInput {
+                        feed_hash: feed_hash.clone(),
+                        share_item_hash: share_record.action_hashed().hash.clone(),
+                    },
+                )
+                .await;
+        }
+
+        let first: Option<FeedPage> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_feed_page",
+                FeedPageInput {
+                    feed_hash: feed_hash.clone(),
+                    cursor: None,
+                    limit: Some(2),
+                },
+            )
+            .await;
+        let first = first.expect("feed page should be found");
+
+        assert_eq!(first.feed.action_hash, feed_hash);
+        assert_eq!(first.feed.feed.name, "Paged Feed");
+        assert_eq!(first.shares.shares.len(), 2);
+        assert_eq!(first.shares.shares[0].share_item.title, "Page 2");
+        assert!(first.shares.next_cursor.is_some());
+
+        let second: Option<FeedPage> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_feed_page",
+                FeedPageInput {
+                    feed_hash,
+                    cursor: first.shares.next_cursor,
+                    limit: Some(2),
+                },
+            )
+            .await;
+        let second = second.expect("feed page should be found");
+
+        assert_eq!(second.shares.shares.len(), 1);
+        assert_eq!(second.shares.shares[0].share_item.title, "Page 0");
+        assert!(second.shares.next_cursor.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn home_timeline_merges_my_feeds() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let mut feed_hashes: Vec<ActionHash> = Vec::new();
+        for name in ["Morning", "Evening"] {
+            let feed_record: Record = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "create_feed",
+                    Feed {
+                        name: name.to_string(),
+                        description: None,
+                        stewards: vec![cell.agent_pubkey().clone()],
+                        is_public: true,
+                    },
+                )
+                .await;
+            feed_hashes.push(feed_record.action_hashed().hash.clone());
+        }
+
+        let mut share_hashes: Vec<ActionHash> = Vec::new();
+        for title in ["Both feeds", "Evening only", "No feed"] {
+            let share_record: Record = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "create_share_item",
+                    ShareItem {
+                        url: format!("https://example.com/{}", title.replace(' ', "-")),
+                        title: title.to_string(),
+                        description: None,
+                        selection: None,
+                        favicon: None,
+                        thumbnail: None,
+                        tags: vec![],
+                    },
+                )
+                .await;
+            share_hashes.push(share_record.action_hashed().hash.clone());
+        }
+
+        for (feed_hash, share_hash) in [
+            (&feed_hashes[0], &share_hashes[0]),
+            (&feed_hashes[1], &share_hashes[0]),
+            (&feed_hashes[1], &share_hashes[1]),
+        ] {
+            let _: () = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "add_share_to_feed",
+                    AddShareToFeedInput {
+                        feed_hash: feed_hash.clone(),
+                        share_item_hash: share_hash.clone(),
+                    },
+                )
+                .await;
+        }
+
+        let timeline: Vec<ShareItemInfo> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_home_timeline",
+                TimelineInput {
+                    cursor: None,
+                    limit: None,
+                },
+            )
+            .await;
+
+        // Each share in my feeds shows once, newest first
+        let titles: Vec<&str> = timeline
+            .iter()
+            .map(|info| info.share_item.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Evening only", "Both feeds"]);
+
+        let older: Vec<ShareItemInfo> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_home_timeline",
+                TimelineInput {
+                    cursor: Some(timeline[0].created_at),
+                    limit: Some(1),
+                },
+            )
+            .await;
+
+        assert_eq!(older.len(), 1);
+        assert_eq!(older[0].action_hash, share_hashes[0]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn members_see_who_has_seen_a_share() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+        let agent_pubkey = cell.agent_pubkey().clone();
+
+        let mut feed_hashes: Vec<ActionHash> = Vec::new();
+        for seen_receipts in [true, false] {
+            let feed_record: Record = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "create_feed",
+                    SeenReceiptsFeed {
+                        name: "Reading Circle".to_string(),
+                        description: None,
+                        stewards: vec![agent_pubkey.clone()],
+                        is_public: false,
+                        seen_receipts,
+                    },
+                )
+                .await;
+            feed_hashes.push(feed_record.action_hashed().hash.clone());
+        }
+
+        let share_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/chapter-one".to_string(),
+                    title: "Chapter one".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
+            .await;
+        let share_hash = share_record.action_hashed().hash.clone();
+
+        let input = SeenReceiptInput {
+            feed_hash: feed_hashes[0].clone(),
+            share_item_hash: share_hash.clone(),
+        };
+        for _ in 0..2 {
+            let _: () = conductor
+                .call(&cell.zome("sharefeed"), "mark_seen", input.clone())
+                .await;
+        }
+
+        let seen_by: Vec<SeenReceipt> = conductor
+            .call(&cell.zome("sharefeed"), "get_seen_by", input)
+            .await;
+
+        assert_eq!(seen_by.len(), 1);
+        assert_eq!(seen_by[0].agent, agent_pubkey);
+
+        // Feeds that don't take receipts refuse them
+        let result: Result<(), _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "mark_seen",
+                SeenReceiptInput {
+                    feed_hash: feed_hashes[1].clone(),
+                    share_item_hash: share_hash,
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn stewards_compile_shares_in_their_feed() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+        let agent_pubkey = cell.agent_pubkey().clone();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Weekly Reads".to_string(),
+                    description: None,
+                    stewards: vec![agent_pubkey.clone()],
+                    is_public: true,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let mut share_hashes: Vec<ActionHash> = Vec::new();
+        for url in ["https://example.com/first", "https://example.com/second"] {
+            let share_record: Record = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "create_share_item",
+                    ShareItem {
+                        url: url.to_string(),
+                        title: "A good read".to_string(),
+                        description: None,
+                        selection: None,
+                        favicon: None,
+                        thumbnail: None,
+                        tags: vec![],
+                    },
+                )
+                .await;
+            share_hashes.push(share_record.action_hashed().hash.clone());
+        }
+        let _: () = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hashes[0].clone(),
+                },
+            )
+            .await;
+
+        let compilation = Compilation {
+            feed_hash: feed_hash.clone(),
+            title: "Best of week 12".to_string(),
+            note: Some("A quiet week".to_string()),
+            year: 2025,
+            week: 12,
+            share_item_hashes: vec![share_hashes[0].clone()],
+        };
+        let _: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_compilation", compilation)
+            .await;
+
+        let compilations: Vec<CompilationInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_compilations", feed_hash.clone())
+            .await;
+        assert_eq!(compilations.len(), 1);
+        assert_eq!(compilations[0].compilation.title, "Best of week 12");
+        assert_eq!(compilations[0].steward, agent_pubkey);
+
+        let for_share: Vec<CompilationInfo> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_compilations_for_share",
+                share_hashes[0].clone(),
+            )
+            .await;
+        assert_eq!(for_share.len(), 1);
+
+        // Shares outside the feed can't be compiled
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "create_compilation",
+                Compilation {
+                    feed_hash,
+                    title: "Best of week 13".to_string(),
+                    note: None,
+                    year: 2025,
+                    week: 13,
+                    share_item_hashes: vec![share_hashes[1].clone()],
+                },
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn members_can_leave_a_feed() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Neighbours".to_string(),
+                    description: None,
+                    stewards: vec![cell1.agent_pubkey().clone()],
+                    is_public: false,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "add_member_to_feed",
+                AddMemberToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    member_pubkey: cell2.agent_pubkey().clone(),
+                },
+            )
+            .await;
+
+        // The member link has to reach the member before they can remove it
+        let mut members: Vec<AgentPubKey> = Vec::new();
+        for _ in 0..20 {
+            let _: () = conductor
+                .call(&cell2.zome("sharefeed"), "leave_feed", feed_hash.clone())
+                .await;
+            members = conductor
+                .call(&cell1.zome("sharefeed"), "get_feed_members", feed_hash.clone())
+                .await;
+            if !members.contains(cell2.agent_pubkey()) {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+
+        assert_eq!(members, vec![cell1.agent_pubkey().clone()]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn only_the_adder_or_a_steward_can_remove_a_share_from_a_feed() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Reading List".to_string(),
+                    description: None,
+                    stewards: vec![cell1.agent_pubkey().clone()],
+                    is_public: true,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+        let share_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/keep".to_string(),
+                    title: "Keep".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
+            .await;
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_record.action_hashed().hash.clone(),
+                },
+            )
+            .await;
+
+        let mut links: Vec<FeedShareLinkInfo> = Vec::new();
+        for _ in 0..20 {
+            links = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "get_feed_share_links",
+                    feed_hash.clone(),
+                )
+                .await;
+            if !links.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        assert_eq!(links.len(), 1);
+
+        // Someone who neither added the share nor stewards the feed
+        let result: Result<(), _> = conductor
+            .call_fallible(
+                &cell2.zome("sharefeed"),
+                "remove_share_from_feed",
+                RemoveShareFromFeedInput {
+                    link_hash: links[0].link_hash.clone(),
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("Only the member who added a share or a steward"));
+
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "remove_share_from_feed",
+                RemoveShareFromFeedInput {
+                    link_hash: links[0].link_hash.clone(),
+                },
+            )
+            .await;
+        let count: usize = conductor
+            .call(&cell1.zome("sharefeed"), "count_feed_shares", feed_hash)
+            .await;
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn stewards_need_a_proposal_to_remove_many_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+        let app3 = conductor
+            .setup_app("sharefeed-3", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+        let (cell3,) = app3.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Shared Archive".to_string(),
+                    description: None,
+                    stewards: vec![
+                        cell1.agent_pubkey().clone(),
+                        cell2.agent_pubkey().clone(),
+                    ],
+                    is_public: true,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        // A third agent fills the feed with one share more than a steward
+        // may remove alone in a day
+        for i in 0..21 {
+            let share_record: Record = conductor
+                .call(
+                    &cell3.zome("sharefeed"),
+                    "create_share_item",
+                    ShareItem {
+                        url: format!("https://example.com/archive/{}", i),
+                        title: format!("Archive {}", i),
+                        description: None,
+                        selection: None,
+                        favicon: None,
+                        thumbnail: None,
+                        tags: vec![],
+                    },
+                )
+                .await;
+            let input = AddShareToFeedInput {
+                feed_hash: feed_hash.clone(),
+                share_item_hash: share_record.action_hashed().hash.clone(),
+            };
+            // The feed has to reach the third agent first
+            for _ in 0..20 {
+                let result: Result<(), _> = conductor
+                    .call_fallible(&cell3.zome("sharefeed"), "add_share_to_feed", input.clone())
+                    .await;
+                if result.is_ok() {
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+            }
+        }
+
+        let mut links: Vec<FeedShareLinkInfo> = Vec::new();
+        for _ in 0..20 {
+            links = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "get_feed_share_links",
+                    feed_hash.clone(),
+                )
+                .await;
+            if links.len() == 21 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        assert_eq!(links.len(), 21);
+
+        let last = links.pop().unwrap();
+        for link in links {
+            let _: () = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "remove_share_from_feed",
+                    RemoveShareFromFeedInput {
+                        link_hash: link.link_hash,
+                    },
+                )
+                .await;
+        }
+        let result: Result<(), _> = conductor
+            .call_fallible(
+                &cell1.zome("sharefeed"),
+                "remove_share_from_feed",
+                RemoveShareFromFeedInput {
+                    link_hash: last.link_hash.clone(),
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("RemoveShares"));
+
+        // With the other steward's sign-off the removal goes through
+        let proposal_record: Record = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "propose_steward_action",
+                StewardProposal {
+                    feed_hash: feed_hash.clone(),
+                    kind: StewardProposalKind::RemoveShares {
+                        share_item_hashes: vec![last.share_item_hash.clone()],
+                    },
+                    note: None,
+                },
+            )
+            .await;
+        let proposal_hash = proposal_record.action_hashed().hash.clone();
+        let mut proposals: Vec<StewardProposalInfo> = Vec::new();
+        for _ in 0..20 {
+            proposals = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "get_steward_proposals",
+                    feed_hash.clone(),
+                )
+                .await;
+            if !proposals.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        assert_eq!(proposals.len(), 1);
+
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "approve_steward_proposal",
+                proposal_hash,
+            )
+            .await;
+        let count: usize = conductor
+            .call(&cell1.zome("sharefeed"), "count_feed_shares", feed_hash)
+            .await;
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn deleting_a_feed_with_several_stewards_needs_a_second_steward() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Community Archive".to_string(),
+                    description: None,
+                    stewards: vec![
+                        cell1.agent_pubkey().clone(),
+                        cell2.agent_pubkey().clone(),
+                    ],
+                    is_public: true,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let result: Result<ActionHash, _> = conductor
+            .call_fallible(&cell1.zome("sharefeed"), "delete_feed", feed_hash.clone())
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("second steward"));
+
+        let proposal_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "propose_steward_action",
+                StewardProposal {
+                    feed_hash: feed_hash.clone(),
+                    kind: StewardProposalKind::DeleteFeed,
+                    note: Some("Moving to a new feed".to_string()),
+                },
+            )
+            .await;
+        let proposal_hash = proposal_record.action_hashed().hash.clone();
+
+        // Stewards can't approve their own proposals
+        let result: Result<(), _> = conductor
+            .call_fallible(
+                &cell1.zome("sharefeed"),
+                "approve_steward_proposal",
+                proposal_hash.clone(),
+            )
+            .await;
+        assert!(result.is_err());
+
+        // The proposal has to reach the other steward first
+        let mut proposals: Vec<StewardProposalInfo> = Vec::new();
+        for _ in 0..20 {
+            proposals = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "get_steward_proposals",
+                    feed_hash.clone(),
+                )
+                .await;
+            if !proposals.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].proposal_hash, proposal_hash);
+        assert_eq!(proposals[0].proposer, *cell1.agent_pubkey());
+        assert!(proposals[0].approvals.is_empty());
+
+        let _: () = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "approve_steward_proposal",
+                proposal_hash.clone(),
+            )
+            .await;
+
+        let proposals: Vec<StewardProposalInfo> = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "get_steward_proposals",
+                feed_hash.clone(),
+            )
+            .await;
+        assert_eq!(proposals[0].approvals, vec![cell2.agent_pubkey().clone()]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn making_a_feed_public_needs_every_steward() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed = Feed {
+            name: "Reading Circle".to_string(),
+            description: None,
+            stewards: vec![
+                cell1.agent_pubkey().clone(),
+                cell2.agent_pubkey().clone(),
+            ],
+            is_public: false,
+        };
+        let feed_record: Record = conductor
+            .call(&cell1.zome("sharefeed"), "create_feed", feed.clone())
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell1.zome("sharefeed"),
+                "update_feed",
+                UpdateFeedInput {
+                    original_feed_hash: feed_hash.clone(),
+                    previous_feed_hash: feed_hash.clone(),
+                    updated_feed: Feed {
+                        is_public: true,
+                        ..feed.clone()
+                    },
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("every steward"));
+
+        let proposal_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "propose_steward_action",
+                StewardProposal {
+                    feed_hash: feed_hash.clone(),
+                    kind: StewardProposalKind::MakePublic {
+                        revision: feed_hash.clone(),
+                    },
+                    note: None,
+                },
+            )
+            .await;
+        let proposal_hash = proposal_record.action_hashed().hash.clone();
+
+        let mut proposals: Vec<StewardProposalInfo> = Vec::new();
+        for _ in 0..20 {
+            proposals = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "get_steward_proposals",
+                    feed_hash.clone(),
+                )
+                .await;
+            if !proposals.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        assert_eq!(proposals.len(), 1);
+
+        // The second steward's approval completes the proposal and
+        // publishes a new revision of the feed
+        let _: () = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "approve_steward_proposal",
+                proposal_hash,
+            )
+            .await;
+
+        let latest: Option<Record> = conductor
+            .call(&cell2.zome("sharefeed"), "get_feed", feed_hash.clone())
+            .await;
+        let latest = latest.unwrap();
+        assert_ne!(latest.action_hashed().hash, feed_hash);
+        assert_eq!(latest.action().author(), cell2.agent_pubkey());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn stewards_added_later_sign_off_on_making_a_feed_public() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+        let app3 = conductor
+            .setup_app("sharefeed-3", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+        let (cell3,) = app3.into_tuple();
+
+        let feed = Feed {
+            name: "Reading Circle".to_string(),
+            description: None,
+            stewards: vec![
+                cell1.agent_pubkey().clone(),
+                cell2.agent_pubkey().clone(),
+            ],
+            is_public: false,
+        };
+        let feed_record: Record = conductor
+            .call(&cell1.zome("sharefeed"), "create_feed", feed.clone())
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        // Proposed before the third steward joins
+        let stale_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "propose_steward_action",
+                StewardProposal {
+                    feed_hash: feed_hash.clone(),
+                    kind: StewardProposalKind::MakePublic {
+                        revision: feed_hash.clone(),
+                    },
+                    note: None,
+                },
+            )
+            .await;
+        let stale_hash = stale_record.action_hashed().hash.clone();
+
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "add_member_to_feed",
+                AddMemberToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    member_pubkey: cell3.agent_pubkey().clone(),
+                },
+            )
+            .await;
+        let revision_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "update_feed",
+                UpdateFeedInput {
+                    original_feed_hash: feed_hash.clone(),
+                    previous_feed_hash: feed_hash.clone(),
+                    updated_feed: Feed {
+                        stewards: vec![
+                            cell1.agent_pubkey().clone(),
+                            cell2.agent_pubkey().clone(),
+                            cell3.agent_pubkey().clone(),
+                        ],
+                        ..feed.clone()
+                    },
+                },
+            )
+            .await;
+        let revision = revision_record.action_hashed().hash.clone();
+
+        for cell in [&cell2, &cell3] {
+            for _ in 0..20 {
+                let latest: Option<Record> = conductor
+                    .call(&cell.zome("sharefeed"), "get_feed", feed_hash.clone())
+                    .await;
+                if latest.is_some_and(|latest| latest.action_hashed().hash == revision) {
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+            }
+        }
+
+        // Approvals given for the earlier revision don't publish the new one
+        let result: Result<(), _> = conductor
+            .call_fallible(
+                &cell2.zome("sharefeed"),
+                "approve_steward_proposal",
+                stale_hash,
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("has changed"));
+
+        let proposal_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "propose_steward_action",
+                StewardProposal {
+                    feed_hash: feed_hash.clone(),
+                    kind: StewardProposalKind::MakePublic {
+                        revision: revision.clone(),
+                    },
+                    note: None,
+                },
+            )
+            .await;
+        let proposal_hash = proposal_record.action_hashed().hash.clone();
+
+        for _ in 0..20 {
+            let proposals: Vec<StewardProposalInfo> = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "get_steward_proposals",
+                    feed_hash.clone(),
+                )
+                .await;
+            if proposals
+                .iter()
+                .any(|proposal| proposal.proposal_hash == proposal_hash)
+            {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let _: () = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "approve_steward_proposal",
+                proposal_hash.clone(),
+            )
+            .await;
+        let latest: Option<Record> = conductor
+            .call(&cell2.zome("sharefeed"), "get_feed", feed_hash.clone())
+            .await;
+        assert_eq!(latest.unwrap().action_hashed().hash, revision);
+
+        // The steward added by the revision completes the sign-off
+        let mut proposals: Vec<StewardProposalInfo> = Vec::new();
+        for _ in 0..20 {
+            proposals = conductor
+                .call(
+                    &cell3.zome("sharefeed"),
+                    "get_steward_proposals",
+                    feed_hash.clone(),
+                )
+                .await;
+            if proposals.iter().any(|proposal| {
+                proposal.proposal_hash == proposal_hash
+                    && proposal.approvals.contains(cell2.agent_pubkey())
+            }) {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        assert!(proposals.iter().any(|proposal| {
+            proposal.proposal_hash == proposal_hash
+                && proposal.approvals.contains(cell2.agent_pubkey())
+        }));
+
+        let _: () = conductor
+            .call(
+                &cell3.zome("sharefeed"),
+                "approve_steward_proposal",
+                proposal_hash,
+            )
+            .await;
+
+        let latest: Option<Record> = conductor
+            .call(&cell3.zome("sharefeed"), "get_feed", feed_hash)
+            .await;
+        let latest = latest.unwrap();
+        assert_ne!(latest.action_hashed().hash, revision);
+        assert_eq!(latest.action().author(), cell3.agent_pubkey());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn only_stewards_delete_a_feed() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Solo".to_string(),
+                    description: None,
+                    stewards: vec![cell1.agent_pubkey().clone()],
+                    is_public: true,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let mut result: Result<ActionHash, _> = Ok(feed_hash.clone());
+        for _ in 0..20 {
+            result = conductor
+                .call_fallible(&cell2.zome("sharefeed"), "delete_feed", feed_hash.clone())
+                .await;
+            if format!("{:?}", result).contains("Only stewards of a feed can delete it") {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("Only stewards of a feed can delete it"));
+
+        let _: ActionHash = conductor
+            .call(&cell1.zome("sharefeed"), "delete_feed", feed_hash)
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn only_stewards_update_a_feed_and_none_can_drop_the_others() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+        let app3 = conductor
+            .setup_app("sharefeed-3", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+        let (cell3,) = app3.into_tuple();
+
+        let feed = Feed {
+            name: "Reading Circle".to_string(),
+            description: None,
+            stewards: vec![
+                cell1.agent_pubkey().clone(),
+                cell2.agent_pubkey().clone(),
+            ],
+            is_public: false,
+        };
+        let feed_record: Record = conductor
+            .call(&cell1.zome("sharefeed"), "create_feed", feed.clone())
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        // Someone who isn't a steward can't write themselves in
+        let take_over = UpdateFeedInput {
+            original_feed_hash: feed_hash.clone(),
+            previous_feed_hash: feed_hash.clone(),
+            updated_feed: Feed {
+                stewards: vec![cell3.agent_pubkey().clone()],
+                ..feed.clone()
+            },
+        };
+        let mut result: Result<Record, _> = Ok(feed_record.clone());
+        for _ in 0..20 {
+            result = conductor
+                .call_fallible(&cell3.zome("sharefeed"), "update_feed", take_over.clone())
+                .await;
+            if format!("{:?}", result).contains("Only stewards of a feed can update it") {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("Only stewards of a feed can update it"));
+
+        // A steward can't drop the other steward and then publish alone
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell1.zome("sharefeed"),
+                "update_feed",
+                UpdateFeedInput {
+                    original_feed_hash: feed_hash.clone(),
+                    previous_feed_hash: feed_hash.clone(),
+                    updated_feed: Feed {
+                        stewards: vec![cell1.agent_pubkey().clone()],
+                        ..feed.clone()
+                    },
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("can't remove other stewards"));
+
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell1.zome("sharefeed"),
+                "update_feed",
+                UpdateFeedInput {
+                    original_feed_hash: feed_hash.clone(),
+                    previous_feed_hash: feed_hash.clone(),
+                    updated_feed: Feed {
+                        is_public: true,
+                        ..feed.clone()
+                    },
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("every steward"));
+
+        // Renaming the feed is fine
+        let _: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "update_feed",
+                UpdateFeedInput {
+                    original_feed_hash: feed_hash.clone(),
+                    previous_feed_hash: feed_hash,
+                    updated_feed: Feed {
+                        name: "Reading Room".to_string(),
+                        ..feed
+                    },
+                },
+            )
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn feeds_set_their_own_steward_quorum() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let stewards = vec![
+            cell1.agent_pubkey().clone(),
+            cell2.agent_pubkey().clone(),
+        ];
+
+        // A quorum the stewards can't meet is refused
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                QuorumFeed {
+                    name: "Unreachable".to_string(),
+                    description: None,
+                    stewards: stewards.clone(),
+                    is_public: true,
+                    steward_quorum: Some(3),
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("quorum"));
+
+        // With a quorum of one, any steward can delete the feed alone
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                QuorumFeed {
+                    name: "Scratchpad".to_string(),
+                    description: None,
+                    stewards,
+                    is_public: true,
+                    steward_quorum: Some(1),
+                },
+            )
+            .await;
+        let _: ActionHash = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "delete_feed",
+                feed_record.action_hashed().hash.clone(),
+            )
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn steward_quorum_is_fixed_when_the_feed_is_created() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed = QuorumFeed {
+            name: "Scratchpad".to_string(),
+            description: None,
+            stewards: vec![
+                cell1.agent_pubkey().clone(),
+                cell2.agent_pubkey().clone(),
+            ],
+            is_public: true,
+            steward_quorum: Some(1),
+        };
+        let feed_record: Record = conductor
+            .call(&cell1.zome("sharefeed"), "create_feed", feed.clone())
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        // Raising the quorum would be ignored by proposals, so it's refused
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell1.zome("sharefeed"),
+                "update_feed",
+                UpdateQuorumFeedInput {
+                    original_feed_hash: feed_hash.clone(),
+                    previous_feed_hash: feed_hash.clone(),
+                    updated_feed: QuorumFeed {
+                        steward_quorum: Some(2),
+                        ..feed.clone()
+                    },
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("steward quorum can't be changed"));
+
+        // Other changes keep the quorum the feed was created with
+        let _: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "update_feed",
+                UpdateQuorumFeedInput {
+                    original_feed_hash: feed_hash.clone(),
+                    previous_feed_hash: feed_hash,
+                    updated_feed: QuorumFeed {
+                        name: "Notepad".to_string(),
+                        ..feed
+                    },
+                },
+            )
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn onboarding_state_is_kept_on_the_source_chain() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let state: OnboardingState = conductor
+            .call(&cell.zome("sharefeed"), "get_onboarding_state", ())
+            .await;
+        assert_eq!(state, OnboardingState::default());
+
+        let mut state = OnboardingState {
+            completed_steps: vec!["create_feed".to_string()],
+            dismissed_tips: Vec::new(),
+        };
+        let _: ActionHash = conductor
+            .call(&cell.zome("sharefeed"), "set_onboarding_state", state.clone())
+            .await;
+
+        // Later saves replace the earlier state
+        state.completed_steps.push("first_share".to_string());
+        state.dismissed_tips.push("keyboard_shortcuts".to_string());
+        let _: ActionHash = conductor
+            .call(&cell.zome("sharefeed"), "set_onboarding_state", state.clone())
+            .await;
+
+        let saved: OnboardingState = conductor
+            .call(&cell.zome("sharefeed"), "get_onboarding_state", ())
+            .await;
+        assert_eq!(saved, state);
+
+        let result: Result<ActionHash, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "set_onboarding_state",
+                OnboardingState {
+                    completed_steps: vec![" ".to_string()],
+                    dismissed_tips: Vec::new(),
+                },
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn stewards_annotate_shares_in_their_feed() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+        let agent_pubkey = cell.agent_pubkey().clone();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Curated".to_string(),
+                    description: None,
+                    stewards: vec![agent_pubkey.clone()],
+                    is_public: false,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let share_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/paywalled".to_string(),
+                    title: "Paywalled article".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
+            .await;
+        let share_hash = share_record.action_hashed().hash.clone();
+        let _: () = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hash.clone(),
+                },
+            )
+            .await;
+
+        let _: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_curator_note",
+                CuratorNote {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hash.clone(),
+                    content: "Paywalled, see the archive link".to_string(),
+                },
+            )
+            .await;
+
+        let input = CuratorNotesInput {
+            feed_hash,
+            share_item_hash: share_hash,
+        };
+        let notes: Vec<CuratorNoteInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_curator_notes", input.clone())
+            .await;
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].steward, agent_pubkey);
+
+        let _: () = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "delete_curator_note",
+                notes[0].curator_note_hash.clone(),
+            )
+            .await;
+
+        let notes: Vec<CuratorNoteInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_curator_notes", input)
+            .await;
+        assert!(notes.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn feed_default_tags_are_merged_into_its_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_feed",
+                DefaultTagsFeed {
+                    name: "Rust Weekly".to_string(),
+                    description: None,
+                    stewards: vec![cell.agent_pubkey().clone()],
+                    is_public: true,
+                    default_tags: vec!["rust".to_string(), "#Weekly".to_string()],
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let share_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/async-traits".to_string(),
+                    title: "Async traits".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec!["Rust".to_string(), "async".to_string()],
+                },
+            )
+            .await;
+        let share_hash = share_record.action_hashed().hash.clone();
+        let _: () = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hash.clone(),
+                },
+            )
+            .await;
+
+        let shares: Vec<ShareItemInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_feed_shares", feed_hash)
+            .await;
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares[0].share_item.tags, vec!["rust", "async", "weekly"]);
+
+        // The share itself keeps the tags it was posted with
+        let detail: Option<ShareDetail> = conductor
+            .call(&cell.zome("sharefeed"), "get_share_detail", share_hash)
+            .await;
+        assert_eq!(detail.unwrap().share.share_item.tags, vec!["Rust", "async"]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_report_share_in_feed() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let agent_pubkey = cell.agent_pubkey().clone();
+
+        let feed = Feed {
+            name: "Moderated Feed".to_string(),
+            description: None,
+            stewards: vec![agent_pubkey.clone()],
+            is_public: true,
+        };
+
+        let feed_record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_feed", feed)
+            .await;
+
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let share_item = ShareItem {
+            url: "https://example.com/spam".to_string(),
+            title: "Buy now".to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+
+        let share_record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+            .await;
+
+        let share_hash = share_record.action_hashed().hash.clone();
+
+        let _: () = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hash.clone(),
+                },
+            )
+            .await;
+
+        let _: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "report_share",
+                Report {
+                    share_item_hash: share_hash.clone(),
+                    feed_hash: feed_hash.clone(),
+                    reason: ReportReason::Spam,
+                    note: Some("Advertising".to_string()),
+                },
+            )
+            .await;
+
+        let reports: Vec<ReportInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_reports_for_feed", feed_hash.clone())
+            .await;
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].report.share_item_hash, share_hash);
+        assert_eq!(reports[0].report.reason, ReportReason::Spam);
+        assert_eq!(reports[0].reporter, agent_pubkey);
+
+        let queue: ModerationQueue = conductor
+            .call(&cell.zome("sharefeed"), "get_moderation_queue", feed_hash.clone())
+            .await;
+
+        assert_eq!(queue.open_reports.len(), 1);
+        assert!(queue.pending_shares.is_empty());
+
+        // Upholding the report takes the share out of the feed
+        let resolution: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "resolve_report",
+                ResolveReportInput {
+                    report_hash: reports[0].report_hash.clone(),
+                    outcome: ReportOutcome::ShareRemoved,
+                    note: None,
+                },
+            )
+            .await;
+
+        let queue: ModerationQueue = conductor
+            .call(&cell.zome("sharefeed"), "get_moderation_queue", feed_hash.clone())
+            .await;
+
+        assert!(queue.open_reports.is_empty());
+
+        let shares: Vec<ShareItemInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_feed_shares", feed_hash.clone())
+            .await;
+
+        assert!(shares.is_empty());
+
+        // Accepting an appeal against the removal puts the share back
+        let appeal_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "file_appeal",
+                Appeal {
+                    moderation_action_hash: resolution.action_hashed().hash.clone(),
+                    reason: "It's a product review, not an ad".to_string(),
+                },
+            )
+            .await;
+
+        let queue: ModerationQueue = conductor
+            .call(&cell.zome("sharefeed"), "get_moderation_queue", feed_hash.clone())
+            .await;
+
+        assert_eq!(queue.open_appeals.len(), 1);
+
+        let _: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "accept_appeal",
+                DecideAppealInput {
+                    appeal_hash: appeal_record.action_hashed().hash.clone(),
+                    note: None,
+                },
+            )
+            .await;
+
+        let shares: Vec<ShareItemInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_feed_shares", feed_hash.clone())
+            .await;
+
+        assert_eq!(shares.len(), 1);
+
+        let appeals: Vec<AppealInfo> = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_appeals_for_moderation_action",
+                resolution.action_hashed().hash.clone(),
+            )
+            .await;
+
+        assert_eq!(appeals.len(), 1);
+        assert!(appeals[0].decision.is_some());
+
+        // The resolution and the appeal decision are both in the log
+        let log: ModerationLogPage = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_moderation_log",
+                ModerationLogInput {
+                    feed_hash: feed_hash.clone(),
+                    cursor: None,
+                    limit: Some(1),
+                },
+            )
+            .await;
+
+        assert_eq!(log.actions.len(), 1);
+        assert!(log.next_cursor.is_some());
+
+        let log: ModerationLogPage = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "get_moderation_log",
+                ModerationLogInput {
+                    feed_hash: feed_hash.clone(),
+                    cursor: log.next_cursor,
+                    limit: None,
+                },
+            )
+            .await;
+
+        assert_eq!(log.actions.len(), 1);
+        assert!(log.next_cursor.is_none());
+
+        // "Other" needs a note saying what's wrong
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "report_share",
+                Report {
+                    share_item_hash: share_hash,
+                    feed_hash,
+                    reason: ReportReason::Other,
+                    note: None,
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn banned_member_cannot_rejoin() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed = Feed {
+            name: "Strict Feed".to_string(),
+            description: None,
+            stewards: vec![cell1.agent_pubkey().clone()],
+            is_public: false,
+        };
+
+        let feed_record: Record = conductor
+            .call(&cell1.zome("sharefeed"), "create_feed", feed)
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let add_member = AddMemberToFeedInput {
+            feed_hash: feed_hash.clone(),
+            member_pubkey: cell2.agent_pubkey().clone(),
+        };
+        let _: () = conductor
+            .call(&cell1.zome("sharefeed"), "add_member_to_feed", add_member.clone())
+            .await;
+
+        let _: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "ban_member",
+                Ban {
+                    feed_hash: feed_hash.clone(),
+                    agent: cell2.agent_pubkey().clone(),
+                    reason: "Spamming the feed".to_string(),
+                },
+            )
+            .await;
+
+        let members: Vec<AgentPubKey> = conductor
+            .call(&cell1.zome("sharefeed"), "get_feed_members", feed_hash.clone())
+            .await;
+
+        assert!(!members.contains(cell2.agent_pubkey()));
+
+        // Neither the steward nor the banned agent can add them back
+        let result: Result<(), _> = conductor
+            .call_fallible(&cell1.zome("sharefeed"), "add_member_to_feed", add_member.clone())
+            .await;
+
+        assert!(result.is_err());
+
+        let result: Result<(), _> = conductor
+            .call_fallible(&cell2.zome("sharefeed"), "add_member_to_feed", add_member.clone())
+            .await;
+
+        assert!(result.is_err());
+
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "unban_member",
+                UnbanMemberInput {
+                    feed_hash: feed_hash.clone(),
+                    agent: cell2.agent_pubkey().clone(),
+                    note: None,
+                },
+            )
+            .await;
+
+        let _: () = conductor
+            .call(&cell1.zome("sharefeed"), "add_member_to_feed", add_member)
+            .await;
+
+        let members: Vec<AgentPubKey> = conductor
+            .call(&cell1.zome("sharefeed"), "get_feed_members", feed_hash)
+            .await;
+
+        assert!(members.contains(cell2.agent_pubkey()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn only_stewards_add_members_unless_the_feed_is_public() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+        let app3 = conductor
+            .setup_app("sharefeed-3", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+        let (cell3,) = app3.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Members Only".to_string(),
+                    description: None,
+                    stewards: vec![
+                        cell1.agent_pubkey().clone(),
+                        cell2.agent_pubkey().clone(),
+                    ],
+                    is_public: false,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+        let add_member = AddMemberToFeedInput {
+            feed_hash: feed_hash.clone(),
+            member_pubkey: cell3.agent_pubkey().clone(),
+        };
+
+        // Someone who isn't a steward can't let themselves in
+        let mut result: Result<(), _> = Ok(());
+        for _ in 0..20 {
+            result = conductor
+                .call_fallible(&cell3.zome("sharefeed"), "add_member_to_feed", add_member.clone())
+                .await;
+            if format!("{:?}", result).contains("Only stewards can add members") {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("Only stewards can add members"));
+
+        // The second steward can, with the first steward's chain checked
+        // for bans
+        for _ in 0..20 {
+            let result: Result<(), _> = conductor
+                .call_fallible(&cell2.zome("sharefeed"), "add_member_to_feed", add_member.clone())
+                .await;
+            if result.is_ok() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let members: Vec<AgentPubKey> = conductor
+            .call(&cell2.zome("sharefeed"), "get_feed_members", feed_hash)
+            .await;
+        assert!(members.contains(cell3.agent_pubkey()));
+
+        // Anyone may join a feed created public
+        let public_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Open Door".to_string(),
+                    description: None,
+                    stewards: vec![
+                        cell1.agent_pubkey().clone(),
+                        cell2.agent_pubkey().clone(),
+                    ],
+                    is_public: true,
+                },
+            )
+            .await;
+        let public_hash = public_record.action_hashed().hash.clone();
+        let join = AddMemberToFeedInput {
+            feed_hash: public_hash.clone(),
+            member_pubkey: cell3.agent_pubkey().clone(),
+        };
+        for _ in 0..20 {
+            let result: Result<(), _> = conductor
+                .call_fallible(&cell3.zome("sharefeed"), "add_member_to_feed", join.clone())
+                .await;
+            if result.is_ok() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let members: Vec<AgentPubKey> = conductor
+            .call(&cell3.zome("sharefeed"), "get_feed_members", public_hash)
+            .await;
+        assert!(members.contains(cell3.agent_pubkey()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn adding_a_member_again_after_stewards_move_on_links_them_once() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+        let app3 = conductor
+            .setup_app("sharefeed-3", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+        let (cell3,) = app3.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Members Only".to_string(),
+                    description: None,
+                    stewards: vec![
+                        cell1.agent_pubkey().clone(),
+                        cell2.agent_pubkey().clone(),
+                    ],
+                    is_public: false,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+        let add_member = AddMemberToFeedInput {
+            feed_hash: feed_hash.clone(),
+            member_pubkey: cell3.agent_pubkey().clone(),
+        };
+        let _: () = conductor
+            .call(&cell1.zome("sharefeed"), "add_member_to_feed", add_member.clone())
+            .await;
+
+        // The other steward's chain moves on, so a second member link would
+        // carry a different chain head in its tag
+        let _: Record = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Elsewhere".to_string(),
+                    description: None,
+                    stewards: vec![cell2.agent_pubkey().clone()],
+                    is_public: false,
+                },
+            )
+            .await;
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+        let _: () = conductor
+            .call(&cell1.zome("sharefeed"), "add_member_to_feed", add_member)
+            .await;
+        let page: FeedMembersPage = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "get_feed_members_page",
+                FeedMembersPageInput {
+                    feed_hash,
+                    cursor: None,
+                    limit: None,
+                },
+            )
+            .await;
+        let members: Vec<AgentPubKey> = page.members.into_iter().map(|m| m.agent).collect();
+        assert_eq!(
+            members
+                .iter()
+                .filter(|agent| *agent == cell3.agent_pubkey())
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn feed_members_come_one_page_at_a_time() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+        let app3 = conductor
+            .setup_app("sharefeed-3", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+        let (cell3,) = app3.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Book Club".to_string(),
+                    description: None,
+                    stewards: vec![cell1.agent_pubkey().clone()],
+                    is_public: false,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+        for member in [&cell2, &cell3] {
+            let _: () = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "add_member_to_feed",
+                    AddMemberToFeedInput {
+                        feed_hash: feed_hash.clone(),
+                        member_pubkey: member.agent_pubkey().clone(),
+                    },
+                )
+                .await;
+        }
+        let all_members: Vec<AgentPubKey> = conductor
+            .call(&cell1.zome("sharefeed"), "get_feed_members", feed_hash.clone())
+            .await;
+
+        // A limit of 0 still returns a member
+        let page: FeedMembersPage = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "get_feed_members_page",
+                FeedMembersPageInput {
+                    feed_hash: feed_hash.clone(),
+                    cursor: None,
+                    limit: Some(0),
+                },
+            )
+            .await;
+        assert_eq!(page.members.len(), 1);
+        assert!(page.next_cursor.is_some());
+
+        // Walking the pages lists every member once
+        let mut paged: Vec<AgentPubKey> = Vec::new();
+        let mut cursor: Option<FeedMembersCursor> = None;
+        loop {
+            let page: FeedMembersPage = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "get_feed_members_page",
+                    FeedMembersPageInput {
+                        feed_hash: feed_hash.clone(),
+                        cursor: cursor.clone(),
+                        limit: Some(1),
+                    },
+                )
+                .await;
+            assert!(page.members.len() <= 1);
+            paged.extend(page.members.into_iter().map(|member| member.agent));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(paged.len(), all_members.len());
+        for member in &all_members {
+            assert_eq!(paged.iter().filter(|agent| *agent == member).count(), 1);
+        }
+        assert!(paged.contains(cell2.agent_pubkey()));
+        assert!(paged.contains(cell3.agent_pubkey()));
+
+        // Oversized limits are capped rather than fetched in full
+        let page: FeedMembersPage = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "get_feed_members_page",
+                FeedMembersPageInput {
+                    feed_hash,
+                    cursor: None,
+                    limit: Some(usize::MAX),
+                },
+            )
+            .await;
+        assert_eq!(page.members.len(), all_members.len());
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn keyword_filters_hold_or_keep_out_member_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                FilteredFeed {
+                    name: "Gardening".to_string(),
+                    description: None,
+                    stewards: vec![cell1.agent_pubkey().clone()],
+                    is_public: true,
+                    keyword_filters: vec![
+                        KeywordFilter {
+                            keyword: "casino".to_string(),
+                            action: KeywordFilterAction::Reject,
+                        },
+                        KeywordFilter {
+                            keyword: "crypto".to_string(),
+                            action: KeywordFilterAction::Hold,
+                        },
+                    ],
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let join = AddMemberToFeedInput {
+            feed_hash: feed_hash.clone(),
+            member_pubkey: cell2.agent_pubkey().clone(),
+        };
+        for _ in 0..20 {
+            let result: Result<(), _> = conductor
+                .call_fallible(&cell2.zome("sharefeed"), "add_member_to_feed", join.clone())
+                .await;
+            if result.is_ok() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+
+        let share_item = |url: &str, title: &str| ShareItem {
+            url: url.to_string(),
+            title: title.to_string(),
+            description: None,
+            selection: None,
+            favicon: None,
+            thumbnail: None,
+            tags: vec![],
+        };
+        let mut share_hashes: Vec<ActionHash> = Vec::new();
+        for (url, title) in [
+            ("https://example.com/bonus", "Casino bonus codes"),
+            ("https://example.com/coins", "Growing CRYPTO gardens"),
+            ("https://example.com/tomatoes", "Pruning tomatoes"),
+        ] {
+            let record: Record = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "create_share_item",
+                    share_item(url, title),
+                )
+                .await;
+            share_hashes.push(record.action_hashed().hash.clone());
+        }
+
+        // A Reject keyword keeps the share out
+        let result: Result<(), _> = conductor
+            .call_fallible(
+                &cell2.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hashes[0].clone(),
+                },
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("doesn't allow"));
+
+        // A Hold keyword sends it to the moderation queue, case aside
+        for share_hash in &share_hashes[1..] {
+            let _: () = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "add_share_to_feed",
+                    AddShareToFeedInput {
+                        feed_hash: feed_hash.clone(),
+                        share_item_hash: share_hash.clone(),
+                    },
+                )
+                .await;
+        }
+
+        let mut queue: Option<ModerationQueue> = None;
+        for _ in 0..20 {
+            let current: ModerationQueue = conductor
+                .call(&cell1.zome("sharefeed"), "get_moderation_queue", feed_hash.clone())
+                .await;
+            let found = !current.pending_shares.is_empty();
+            queue = Some(current);
+            if found {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let pending: Vec<ActionHash> = queue
+            .unwrap()
+            .pending_shares
+            .into_iter()
+            .map(|info| info.action_hash)
+            .collect();
+        assert_eq!(pending, vec![share_hashes[1].clone()]);
+
+        let shares: Vec<ShareItemInfo> = conductor
+            .call(&cell2.zome("sharefeed"), "get_feed_shares", feed_hash.clone())
+            .await;
+        let shares: Vec<ActionHash> = shares.into_iter().map(|info| info.action_hash).collect();
+        assert_eq!(shares, vec![share_hashes[2].clone()]);
+
+        // Stewards aren't filtered
+        let record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_share_item",
+                share_item("https://example.com/roulette", "Casino gardens"),
+            )
+            .await;
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash,
+                    share_item_hash: record.action_hashed().hash.clone(),
+                },
+            )
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn feeds_are_listed_only_under_their_creator() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        // Naming someone else as a steward doesn't list the feed under them
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Shared Stewardship".to_string(),
+                    description: None,
+                    stewards: vec![
+                        cell1.agent_pubkey().clone(),
+                        cell2.agent_pubkey().clone(),
+                    ],
+                    is_public: false,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let my_feeds: Vec<FeedInfo> = conductor
+            .call(&cell1.zome("sharefeed"), "get_my_feeds", ())
+            .await;
+        assert!(my_feeds.iter().any(|info| info.action_hash == feed_hash));
+
+        // Wait until the feed itself has reached the other steward
+        for _ in 0..20 {
+            let feed: Option<Record> = conductor
+                .call(&cell2.zome("sharefeed"), "get_feed", feed_hash.clone())
+                .await;
+            if feed.is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let their_feeds: Vec<FeedInfo> = conductor
+            .call(&cell2.zome("sharefeed"), "get_my_feeds", ())
+            .await;
+        assert!(!their_feeds.iter().any(|info| info.action_hash == feed_hash));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn members_can_only_be_added_to_feeds() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let share_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/article".to_string(),
+                    title: "Article".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
+            .await;
+
+        // A share isn't a feed, so it can't have members
+        let result: Result<(), _> = conductor
+            .call_fallible(
+                &cell1.zome("sharefeed"),
+                "add_member_to_feed",
+                AddMemberToFeedInput {
+                    feed_hash: share_record.action_hashed().hash.clone(),
+                    member_pubkey: cell2.agent_pubkey().clone(),
+                },
+            )
+            .await;
+        assert!(result.is_err());
+
+        let feed_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Book Club".to_string(),
+                    description: None,
+                    stewards: vec![cell1.agent_pubkey().clone()],
+                    is_public: false,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "add_member_to_feed",
+                AddMemberToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    member_pubkey: cell2.agent_pubkey().clone(),
+                },
+            )
+            .await;
+
+        // Every member link points at an agent
+        let members: Vec<AgentPubKey> = conductor
+            .call(&cell1.zome("sharefeed"), "get_feed_members", feed_hash)
+            .await;
+        assert_eq!(members.len(), 2);
+        assert!(members.contains(cell1.agent_pubkey()));
+        assert!(members.contains(cell2.agent_pubkey()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn progenitor_creates_the_home_feed_everyone_follows() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let (progenitor, newcomer) = SweetAgents::two(conductor.keystore()).await;
+        let dna = load_dna_with_properties(
+            NetworkProperties {
+                progenitors: vec![progenitor.clone().into()],
+                home_feed: Some("Welcome".to_string()),
+                ..Default::default()
+            }
+            .try_into()
+            .unwrap(),
+        )
+        .await;
+
+        let (cell1,) = conductor
+            .setup_app_for_agent("sharefeed-1", progenitor.clone(), [&dna])
+            .await
+            .unwrap()
+            .into_tuple();
+        let (cell2,) = conductor
+            .setup_app_for_agent("sharefeed-2", newcomer.clone(), [&dna])
+            .await
+            .unwrap()
+            .into_tuple();
+
+        // The progenitor's init creates the feed and lists it in their feeds
+        let home_feed: Option<ActionHash> = conductor
+            .call(&cell1.zome("sharefeed"), "get_home_feed", ())
+            .await;
+        let home_feed = home_feed.unwrap();
+        let my_feeds: Vec<FeedInfo> = conductor
+            .call(&cell1.zome("sharefeed"), "get_my_feeds", ())
+            .await;
+        assert!(my_feeds.iter().any(|info| info.action_hash == home_feed));
+        assert_eq!(
+            my_feeds
+                .iter()
+                .find(|info| info.action_hash == home_feed)
+                .unwrap()
+                .feed
+                .name,
+            "Welcome"
+        );
+
+        // Anyone else follows it once it reaches them
+        let mut followed: Option<ActionHash> = None;
+        for _ in 0..20 {
+            followed = conductor
+                .call(&cell2.zome("sharefeed"), "follow_home_feed", ())
+                .await;
+            if followed.is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        assert_eq!(followed, Some(home_feed.clone()));
+        let their_feeds: Vec<FeedInfo> = conductor
+            .call(&cell2.zome("sharefeed"), "get_my_feeds", ())
+            .await;
+        assert!(their_feeds.iter().any(|info| info.action_hash == home_feed));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_import_bookmarks_into_feed() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let feed = Feed {
+            name: "Imported".to_string(),
+            description: None,
+            stewards: vec![cell.agent_pubkey().clone()],
+            is_public: true,
+        };
+
+        let feed_record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_feed", feed)
+            .await;
+
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let html = r#"<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<DL><p>
+    <DT><H3>Reading</H3>
+    <DL><p>
+        <DT><A HREF="https://example.com/a" ADD_DATE="1700000000">Article &amp; notes</A>
+        <DD>Worth a second look
+        <DT><A HREF="https://example.com/a/">Same article</A>
+        <DT><A HREF="javascript:void(0)">Bookmarklet</A>
+    </DL><p>
+</DL><p>"#;
+
+        let report: ImportReport = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "import_bookmarks",
+                ImportBookmarksInput {
+                    html: html.to_string(),
+                    target_feed: Some(feed_hash.clone()),
+                },
+            )
+            .await;
+
+        assert_eq!(report.imported, 1);
+        assert_eq!(report.skipped, 1);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.items.len(), 3);
+
+        let shares: Vec<ShareItemInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_feed_shares", feed_hash)
+            .await;
+
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares[0].share_item.title, "Article & notes");
+        assert_eq!(
+            shares[0].share_item.description.as_deref(),
+            Some("Worth a second look")
+        );
+        assert_eq!(shares[0].share_item.tags, vec!["Reading".to_string()]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn can_export_my_data() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let agent_pubkey = cell.agent_pubkey().clone();
+
+        let feed = Feed {
+            name: "Exported".to_string(),
+            description: None,
+            stewards: vec![agent_pubkey.clone()],
+            is_public: true,
+        };
+
+        let feed_record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_feed", feed)
+            .await;
+
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let mut share_hashes = Vec::new();
+        for url in ["https://example.com/kept", "https://example.com/deleted"] {
+            let share_item = ShareItem {
+                url: url.to_string(),
+                title: "Example".to_string(),
+                description: None,
+                selection: None,
+                favicon: None,
+                thumbnail: None,
+                tags: vec![],
+            };
+
+            let share_record: Record = conductor
+                .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+                .await;
+
+            share_hashes.push(share_record.action_hashed().hash.clone());
+        }
+
+        let _: () = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hashes[0].clone(),
+                },
+            )
+            .await;
+
+        let _: ActionHash = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "delete_share_item",
+                share_hashes[1].clone(),
+            )
+            .await;
+
+        let export: PersonalDataExport = conductor
+            .call(&cell.zome("sharefeed"), "export_my_data", ())
+            .await;
+
+        assert_eq!(export.agent, agent_pubkey);
+        assert_eq!(export.share_items.len(), 1);
+        assert_eq!(export.share_items[0].action_hash, share_hashes[0]);
+        assert_eq!(export.share_items[0].entry.url, "https://example.com/kept");
+        assert_eq!(export.feeds.len(), 1);
+        assert_eq!(export.feeds[0].action_hash, feed_hash);
+        assert_eq!(export.feed_shares.len(), 1);
+        assert_eq!(export.feed_shares[0].share_item_hash, share_hashes[0]);
+        assert!(export.comments.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod notification_tests {
+    use crate::common::load_dna;
+    use crate::types::*;
+    use holochain::sweettest::SweetConductor;
+    use holochain_types::prelude::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn tag_subscribers_are_notified_of_matching_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Reading Group".to_string(),
+                    description: None,
+                    stewards: vec![cell2.agent_pubkey().clone()],
+                    is_public: false,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+        let _: () = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "add_member_to_feed",
+                AddMemberToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    member_pubkey: cell1.agent_pubkey().clone(),
+                },
+            )
+            .await;
+
+        // Tags are normalized, so these are the same subscription
+        for tag in ["#Rust", "rust"] {
+            let _: TagSubscriptionInfo = conductor
+                .call(&cell2.zome("sharefeed"), "subscribe_to_tag", tag.to_string())
+                .await;
+        }
+        let subscriptions: Vec<TagSubscriptionInfo> = conductor
+            .call(&cell2.zome("sharefeed"), "get_tag_subscriptions", ())
+            .await;
+        assert_eq!(subscriptions.len(), 1);
+        assert_eq!(subscriptions[0].tag_subscription.tag, "rust");
+
+        let share_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/borrow-checker".to_string(),
+                    title: "The borrow checker".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec!["Rust".to_string()],
+                },
+            )
+            .await;
+        let share_hash = share_record.action_hashed().hash.clone();
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hash.clone(),
+                },
+            )
+            .await;
+
+        // The share has to reach the subscriber before the check sees it
+        let mut matches: Vec<NotificationInfo> = Vec::new();
+        for _ in 0..20 {
+            let _: serde::de::IgnoredAny = conductor
+                .call(&cell2.zome("sharefeed"), "scheduled_subscription_check", ())
+                .await;
+            let notifications: Vec<NotificationInfo> = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "get_notifications",
+                    GetNotificationsInput {
+                        cursor: None,
+                        limit: None,
+                    },
+                )
+                .await;
+            matches = notifications
+                .into_iter()
+                .filter(|info| info.notification.kind == NotificationKind::TagSubscriptionMatch)
+                .collect();
+            if !matches.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].notification.share_item_hash, Some(share_hash));
+        assert_eq!(matches[0].notification.feed_hash, Some(feed_hash));
+
+        let _: () = conductor
+            .call(&cell2.zome("sharefeed"), "unsubscribe_from_tag", "RUST".to_string())
+            .await;
+        let subscriptions: Vec<TagSubscriptionInfo> = conductor
+            .call(&cell2.zome("sharefeed"), "get_tag_subscriptions", ())
+            .await;
+        assert!(subscriptions.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn domain_subscribers_are_notified_of_matching_shares() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let feed_record: Record = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Reading Group".to_string(),
+                    description: None,
+                    stewards: vec![cell2.agent_pubkey().clone()],
+                    is_public: false,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+        let _: () = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "add_member_to_feed",
+                AddMemberToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    member_pubkey: cell1.agent_pubkey().clone(),
+                },
+            )
+            .await;
+
+        // A URL subscribes to its domain
+        let subscription: DomainSubscriptionInfo = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "subscribe_to_domain",
+                "https://www.Example.com/essays".to_string(),
+            )
+            .await;
+        assert_eq!(subscription.domain_subscription.domain, "example.com");
+
+        let mut share_hashes = Vec::new();
+        for url in ["https://example.com/essay", "https://elsewhere.org/essay"] {
+            let share_record: Record = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "create_share_item",
+                    ShareItem {
+                        url: url.to_string(),
+                        title: "An essay".to_string(),
+                        description: None,
+                        selection: None,
+                        favicon: None,
+                        thumbnail: None,
+                        tags: vec![],
+                    },
+                )
+                .await;
+            let share_hash = share_record.action_hashed().hash.clone();
+            let _: () = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "add_share_to_feed",
+                    AddShareToFeedInput {
+                        feed_hash: feed_hash.clone(),
+                        share_item_hash: share_hash.clone(),
+                    },
+                )
+                .await;
+            share_hashes.push(share_hash);
+        }
+
+        // The shares have to reach the subscriber before the check sees them
+        let mut matches: Vec<NotificationInfo> = Vec::new();
+        for _ in 0..20 {
+            let _: serde::de::IgnoredAny = conductor
+                .call(&cell2.zome("sharefeed"), "scheduled_subscription_check", ())
+                .await;
+            let notifications: Vec<NotificationInfo> = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "get_notifications",
+                    GetNotificationsInput {
+                        cursor: None,
+                        limit: None,
+                    },
+                )
+                .await;
+            matches = notifications
+                .into_iter()
+                .filter(|info| {
+                    info.notification.kind == NotificationKind::DomainSubscriptionMatch
+                })
+                .collect();
+            if !matches.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].notification.share_item_hash,
+            Some(share_hashes[0].clone())
+        );
+
+        let _: () = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "unsubscribe_from_domain",
+                "example.com".to_string(),
+            )
+            .await;
+        let subscriptions: Vec<DomainSubscriptionInfo> = conductor
+            .call(&cell2.zome("sharefeed"), "get_domain_subscriptions", ())
+            .await;
+        assert!(subscriptions.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn muted_shares_do_not_notify_about_comments() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
+            .await
+            .unwrap();
+
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
+
+        let mut share_hashes = Vec::new();
+        for title in ["Busy thread", "Quiet thread"] {
+            let share_record: Record = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "create_share_item",
+                    ShareItem {
+                        url: format!("https://example.com/{}", title.replace(' ', "-")),
+                        title: title.to_string(),
+                        description: None,
+                        selection: None,
+                        favicon: None,
+                        thumbnail: None,
+                        tags: vec![],
+                    },
+                )
+                .await;
+            share_hashes.push(share_record.action_hashed().hash.clone());
+        }
+        let muted_hash = share_hashes[0].clone();
+        let unmuted_hash = share_hashes[1].clone();
+
+        let _: serde::de::IgnoredAny = conductor
+            .call(&cell1.zome("sharefeed"), "mute_share", muted_hash.clone())
+            .await;
+
+        // The shares have to reach the commenter first
+        for share_hash in &share_hashes {
+            for _ in 0..20 {
+                let share: Option<Record> = conductor
+                    .call(&cell2.zome("sharefeed"), "get_share_item", share_hash.clone())
+                    .await;
+                if share.is_some() {
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+            }
+        }
+        for share_hash in &share_hashes {
+            let _: Record = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "create_comment",
+                    Comment {
+                        share_item_hash: share_hash.clone(),
+                        content: "Thoughts?".to_string(),
+                    },
+                )
+                .await;
+        }
+
+        // Remote signals are delivered asynchronously; once the unmuted
+        // share's comment arrives, the muted one has had its chance
+        let mut comments: Vec<NotificationInfo> = Vec::new();
+        for _ in 0..20 {
+            let notifications: Vec<NotificationInfo> = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "get_notifications",
+                    GetNotificationsInput {
+                        cursor: None,
+                        limit: None,
+                    },
+                )
+                .await;
+            comments = notifications
+                .into_iter()
+                .filter(|info| info.notification.kind == NotificationKind::Comment)
+                .collect();
+            if !comments.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].notification.share_item_hash, Some(unmuted_hash));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn quiet_hours_are_checked_when_prefs_change() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
+
+        let prefs = |start_hour: u8, end_hour: u8| NotificationPrefs {
+            default_level: NotificationLevel::All,
+            feed_levels: vec![],
+            quiet_hours: Some(QuietHours {
+                start_hour,
+                end_hour,
+            }),
+        };
+
+        // The first call creates the prefs, later ones update them
+        let _: ActionHash = conductor
+            .call(&cell.zome("sharefeed"), "set_notification_prefs", prefs(22, 7))
+            .await;
+
+        let result: Result<ActionHash, _> = conductor
+            .call_fallible(&cell.zome("sharefeed"), "set_notification_prefs", prefs(22, 31))
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains("Quiet hours must be between 0 and 23"));
+
+        let _: ActionHash = conductor
+            .call(&cell.zome("sharefeed"), "set_notification_prefs", prefs(23, 6))
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn forged_signals_are_not_stored_as_notifications() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let mut cells = Vec::new();
+        for app_id in ["sharefeed-1", "sharefeed-2", "sharefeed-3"] {
+            let app = conductor.setup_app(app_id, [&dna]).await.unwrap();
+            let (cell,) = app.into_tuple();
+            cells.push(cell);
+        }
+        let (cell1, cell2, cell3) = (&cells[0], &cells[1], &cells[2]);
+
+        let share_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/discussion".to_string(),
+                    title: "Discussion".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
+            .await;
+        let share_hash = share_record.action_hashed().hash.clone();
+        let other_share_record: Record = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/elsewhere".to_string(),
+                    title: "Elsewhere".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
+            .await;
+        let feed_record: Record = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Not Yours".to_string(),
+                    description: None,
+                    stewards: vec![cell2.agent_pubkey().clone()],
+                    is_public: false,
+                },
+            )
+            .await;
+
+        for _ in 0..20 {
+            let share: Option<Record> = conductor
+                .call(&cell2.zome("sharefeed"), "get_share_item", share_hash.clone())
+                .await;
+            if share.is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+        let comment_record: Record = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "create_comment",
+                Comment {
+                    share_item_hash: share_hash.clone(),
+                    content: "Genuine".to_string(),
+                },
+            )
+            .await;
+
+        // A stranger claims someone else's comment and a feed they aren't in,
+        // and the commenter moves their comment to another share and claims
+        // a member they never added
+        let forgeries = [
+            (
+                cell3,
+                Signal::CommentAdded {
+                    share_item_hash: share_hash.clone(),
+                    comment_hash: comment_record.action_hashed().hash.clone(),
+                    author: cell3.agent_pubkey().clone(),
+                },
+            ),
+            (
+                cell3,
+                Signal::AddedToFeed {
+                    feed_hash: feed_record.action_hashed().hash.clone(),
+                    added_by: cell3.agent_pubkey().clone(),
+                },
+            ),
+            (
+                cell2,
+                Signal::CommentAdded {
+                    share_item_hash: other_share_record.action_hashed().hash.clone(),
+                    comment_hash: comment_record.action_hashed().hash.clone(),
+                    author: cell2.agent_pubkey().clone(),
+                },
+            ),
+            (
+                cell2,
+                Signal::MemberJoined {
+                    feed_hash: feed_record.action_hashed().hash.clone(),
+                    member: cell3.agent_pubkey().clone(),
+                    added_by: cell2.agent_pubkey().clone(),
+                    member_link_hash: comment_record.action_hashed().hash.clone(),
+                },
+            ),
+        ];
+        for (sender, forgery) in forgeries {
+            let _: () = conductor
+                .call_from(
+                    sender.agent_pubkey(),
+                    None,
+                    &cell1.zome("sharefeed"),
+                    "recv_remote_signal",
+                    forgery,
+                )
+                .await;
+        }
+
+        let mut notifications: Vec<NotificationInfo> = Vec::new();
+        for _ in 0..20 {
+            notifications = conductor
+                .call(
+                    &cell1.zome("sharefeed"),
+                    "get_notifications",
+                    GetNotificationsInput {
+                        cursor: None,
+                        limit: None,
+                    },
+                )
+                .await;
+            if !notifications.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
+
+        // Only the real commenter's notification is kept
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].notification.kind, NotificationKind::Comment);
+        assert_eq!(&notifications[0].notification.from, cell2.agent_pubkey());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn members_hear_about_new_members_when_announced() {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let mut cells = Vec::new();
+        for app_id in ["sharefeed-1", "sharefeed-2", "sharefeed-3"] {
+            let app = conductor.setup_app(app_id, [&dna]).await.unwrap();
+            let (cell,) = app.into_tuple();
+            cells.push(cell);
+        }
+
+        let feed_record: Record = conductor
+            .call(
+                &cells[0].zome("sharefeed"),
+                "create_feed",
+                AnnouncingFeed {
+                    name: "Book Club".to_string(),
+                    description: None,
+                    stewards: vec![cells[0].agent_pubkey().clone()],
+                    is_public: false,
+                    announce_new_members: true,
+                },
+            )
             .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
 
-        let action_hash = record.action_hashed().hash.clone();
+        for cell in &cells[1..] {
+            let _: () = conductor
+                .call(
+                    &cells[0].zome("sharefeed"),
+                    "add_member_to_feed",
+                    AddMemberToFeedInput {
+                        feed_hash: feed_hash.clone(),
+                        member_pubkey: cell.agent_pubkey().clone(),
+                    },
+                )
+                .await;
+        }
 
-        // Get the share item
-        let retrieved: Option<Record> = conductor
-            .call(&cell.zome("sharefeed"), "get_share_item", action_hash.clone())
-            .await;
+        // The second member joining is announced to the first
+        let mut joined: Vec<NotificationInfo> = Vec::new();
+        for _ in 0..20 {
+            let notifications: Vec<NotificationInfo> = conductor
+                .call(
+                    &cells[1].zome("sharefeed"),
+                    "get_notifications",
+                    GetNotificationsInput {
+                        cursor: None,
+                        limit: None,
+                    },
+                )
+                .await;
+            joined = notifications
+                .into_iter()
+                .filter(|info| info.notification.kind == NotificationKind::MemberJoined)
+                .collect();
+            if !joined.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        }
 
-        assert!(retrieved.is_some());
+        assert_eq!(joined.len(), 1);
+        assert_eq!(joined[0].notification.from, *cells[2].agent_pubkey());
+        assert_eq!(joined[0].notification.feed_hash, Some(feed_hash));
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn can_get_recent_shares() {
+    async fn added_member_gets_notification() {
         holochain_trace::test_run();
 
         let mut conductor = SweetConductor::from_standard_config().await;
         let dna = load_dna().await;
 
-        let app = conductor
-            .setup_app("sharefeed", [&dna])
+        let app1 = conductor
+            .setup_app("sharefeed-1", [&dna])
+            .await
+            .unwrap();
+        let app2 = conductor
+            .setup_app("sharefeed-2", [&dna])
             .await
             .unwrap();
 
-        let (cell,) = app.into_tuple();
+        let (cell1,) = app1.into_tuple();
+        let (cell2,) = app2.into_tuple();
 
-        // Create multiple share items
-        for i in 0..3 {
-            let share_item = ShareItem {
-                url: format!("https://example.com/article-{}", i),
-                title: format!("Test Article {}", i),
-                description: None,
-                selection: None,
-                favicon: None,
-                thumbnail: None,
-                tags: vec![],
-            };
+        let feed = Feed {
+            name: "Family Links".to_string(),
+            description: None,
+            stewards: vec![cell1.agent_pubkey().clone()],
+            is_public: false,
+        };
 
-            let _record: Record = conductor
-                .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+        let feed_record: Record = conductor
+            .call(&cell1.zome("sharefeed"), "create_feed", feed)
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
+
+        let _: () = conductor
+            .call(
+                &cell1.zome("sharefeed"),
+                "add_member_to_feed",
+                AddMemberToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    member_pubkey: cell2.agent_pubkey().clone(),
+                },
+            )
+            .await;
+
+        // Remote signals are delivered asynchronously
+        let mut notifications: Vec<NotificationInfo> = Vec::new();
+        for _ in 0..20 {
+            notifications = conductor
+                .call(
+                    &cell2.zome("sharefeed"),
+                    "get_notifications",
+                    GetNotificationsInput {
+                        cursor: None,
+                        limit: None,
+                    },
+                )
                 .await;
+            if !notifications.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
         }
 
-        // Get recent shares
-        let shares: Vec<ShareItemInfo> = conductor
-            .call(&cell.zome("sharefeed"), "get_recent_shares", ())
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].notification.kind, NotificationKind::AddedToFeed);
+        assert_eq!(notifications[0].notification.feed_hash, Some(feed_hash));
+        assert_eq!(notifications[0].notification.from, *cell1.agent_pubkey());
+
+        let unread: usize = conductor
+            .call(&cell2.zome("sharefeed"), "get_unread_notification_count", ())
             .await;
+        assert_eq!(unread, 1);
 
-        assert_eq!(shares.len(), 3);
+        let _: () = conductor
+            .call(
+                &cell2.zome("sharefeed"),
+                "mark_read",
+                vec![notifications[0].notification_hash.clone()],
+            )
+            .await;
+
+        let unread: usize = conductor
+            .call(&cell2.zome("sharefeed"), "get_unread_notification_count", ())
+            .await;
+        assert_eq!(unread, 0);
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn shares_visible_across_agents() {
+    async fn mentioned_agent_sees_share() {
         holochain_trace::test_run();
 
         let mut conductor = SweetConductor::from_standard_config().await;
         let dna = load_dna().await;
 
-        // Set up two agents
         let app1 = conductor
             .setup_app("sharefeed-1", [&dna])
             .await
@@ -176,39 +5821,41 @@ mod share_item_tests {
         let (cell1,) = app1.into_tuple();
         let (cell2,) = app2.into_tuple();
 
-        // Agent 1 creates a share
+        let mentioned = AgentPubKeyB64::from(cell2.agent_pubkey().clone());
         let share_item = ShareItem {
-            url: "https://example.com/shared-article".to_string(),
-            title: "Cross-Agent Test".to_string(),
-            description: Some("Testing cross-agent visibility".to_string()),
+            url: "https://example.com/for-you".to_string(),
+            title: "For You".to_string(),
+            description: Some(format!("@{} you'll like this", mentioned)),
             selection: None,
             favicon: None,
             thumbnail: None,
-            tags: vec!["test".to_string()],
+            tags: vec![],
         };
 
         let _record: Record = conductor
-            .call(&cell1.zome("sharefeed"), "create_share_item", share_item.clone())
+            .call(&cell1.zome("sharefeed"), "create_share_item", share_item)
             .await;
 
-        // Wait for gossip to propagate (sweettest uses same conductor so this is fast)
         tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 
-        // Agent 2 should be able to see Agent 1's share
-        let shares: Vec<ShareItemInfo> = conductor
-            .call(&cell2.zome("sharefeed"), "get_recent_shares", ())
+        let mentions: Vec<ShareItemInfo> = conductor
+            .call(&cell2.zome("sharefeed"), "get_my_mentions", ())
             .await;
 
-        assert_eq!(shares.len(), 1, "Agent 2 should see Agent 1's share");
-        assert_eq!(shares[0].share_item.url, "https://example.com/shared-article");
-        assert_eq!(shares[0].share_item.title, "Cross-Agent Test");
-
-        // Verify the author is Agent 1's pubkey
-        assert_eq!(shares[0].author, *cell1.agent_pubkey());
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].share_item.title, "For You");
     }
+}
+
+#[cfg(test)]
+mod comment_tests {
+    use crate::common::load_dna;
+    use crate::types::*;
+    use holochain::sweettest::SweetConductor;
+    use holochain_types::prelude::*;
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn share_item_requires_url_and_title() {
+    async fn can_comment_on_share() {
         holochain_trace::test_run();
 
         let mut conductor = SweetConductor::from_standard_config().await;
@@ -221,10 +5868,9 @@ mod share_item_tests {
 
         let (cell,) = app.into_tuple();
 
-        // Try to create with empty URL - should fail validation
-        let invalid_share = ShareItem {
-            url: "".to_string(),
-            title: "Test".to_string(),
+        let share_item = ShareItem {
+            url: "https://example.com/discuss".to_string(),
+            title: "Discuss".to_string(),
             description: None,
             selection: None,
             favicon: None,
@@ -232,23 +5878,52 @@ mod share_item_tests {
             tags: vec![],
         };
 
+        let share_record: Record = conductor
+            .call(&cell.zome("sharefeed"), "create_share_item", share_item)
+            .await;
+        let share_hash = share_record.action_hashed().hash.clone();
+
+        let _comment: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_comment",
+                Comment {
+                    share_item_hash: share_hash.clone(),
+                    content: "Great read".to_string(),
+                },
+            )
+            .await;
+
+        let comments: Vec<CommentInfo> = conductor
+            .call(&cell.zome("sharefeed"), "get_comments", share_hash.clone())
+            .await;
+
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].comment.content, "Great read");
+
+        let comment_count: usize = conductor
+            .call(&cell.zome("sharefeed"), "count_comments", share_hash.clone())
+            .await;
+
+        assert_eq!(comment_count, 1);
+
+        // Empty comments are rejected by validation
         let result: Result<Record, _> = conductor
-            .call_fallible(&cell.zome("sharefeed"), "create_share_item", invalid_share)
+            .call_fallible(
+                &cell.zome("sharefeed"),
+                "create_comment",
+                Comment {
+                    share_item_hash: share_hash,
+                    content: "  ".to_string(),
+                },
+            )
             .await;
 
         assert!(result.is_err());
     }
-}
-
-#[cfg(test)]
-mod feed_tests {
-    use crate::common::load_dna;
-    use crate::types::*;
-    use holochain::sweettest::SweetConductor;
-    use holochain_types::prelude::*;
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn can_create_and_get_feed() {
+    async fn share_detail_combines_share_page_data() {
         holochain_trace::test_run();
 
         let mut conductor = SweetConductor::from_standard_config().await;
@@ -261,32 +5936,108 @@ mod feed_tests {
 
         let (cell,) = app.into_tuple();
 
-        let agent_pubkey = cell.agent_pubkey().clone();
-
-        let feed = Feed {
-            name: "Family Links".to_string(),
-            description: Some("Links shared with family".to_string()),
-            stewards: vec![agent_pubkey],
-            is_public: false,
-        };
+        let feed_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_feed",
+                Feed {
+                    name: "Reading Group".to_string(),
+                    description: None,
+                    stewards: vec![cell.agent_pubkey().clone()],
+                    is_public: true,
+                },
+            )
+            .await;
+        let feed_hash = feed_record.action_hashed().hash.clone();
 
-        // Create a feed
-        let record: Record = conductor
-            .call(&cell.zome("sharefeed"), "create_feed", feed.clone())
+        let share_record: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_share_item",
+                ShareItem {
+                    url: "https://example.com/detail".to_string(),
+                    title: "Detail".to_string(),
+                    description: None,
+                    selection: None,
+                    favicon: None,
+                    thumbnail: None,
+                    tags: vec![],
+                },
+            )
             .await;
+        let share_hash = share_record.action_hashed().hash.clone();
 
-        let feed_hash = record.action_hashed().hash.clone();
+        let _: () = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "add_share_to_feed",
+                AddShareToFeedInput {
+                    feed_hash: feed_hash.clone(),
+                    share_item_hash: share_hash.clone(),
+                },
+            )
+            .await;
+        let _: Record = conductor
+            .call(
+                &cell.zome("sharefeed"),
+                "create_comment",
+                Comment {
+                    share_item_hash: share_hash.clone(),
+                    content: "Worth a look".to_string(),
+                },
+            )
+            .await;
+        for emoji in ["👍", "🎉", "👍"] {
+            let _: Record = conductor
+                .call(
+                    &cell.zome("sharefeed"),
+                    "add_reaction",
+                    Reaction {
+                        share_item_hash: share_hash.clone(),
+                        emoji: emoji.to_string(),
+                    },
+                )
+                .await;
+        }
 
-        // Get the feed
-        let retrieved: Option<Record> = conductor
-            .call(&cell.zome("sharefeed"), "get_feed", feed_hash)
+        let detail: Option<ShareDetail> = conductor
+            .call(&cell.zome("sharefeed"), "get_share_detail", share_hash.clone())
             .await;
+        let detail = detail.expect("share detail should be found");
 
-        assert!(retrieved.is_some());
+        assert_eq!(detail.share.action_hash, share_hash);
+        assert_eq!(detail.share.share_item.title, "Detail");
+        assert_eq!(detail.feeds.len(), 1);
+        assert_eq!(detail.feeds[0].feed_hash, feed_hash);
+        assert_eq!(detail.comment_count, 1);
+        assert_eq!(
+            detail.reactions,
+            vec![
+                ReactionCount {
+                    emoji: "👍".to_string(),
+                    count: 2,
+                    mine: true,
+                },
+                ReactionCount {
+                    emoji: "🎉".to_string(),
+                    count: 1,
+                    mine: true,
+                },
+            ]
+        );
+        assert!(!detail.saved);
     }
+}
+
+#[cfg(test)]
+mod api_schema_tests {
+    use crate::common::load_dna;
+    use crate::types::*;
+    use holochain::sweettest::SweetConductor;
+    use holochain_types::prelude::*;
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn can_get_my_feeds() {
+    async fn api_schema_describes_externs() {
         holochain_trace::test_run();
 
         let mut conductor = SweetConductor::from_standard_config().await;
@@ -299,32 +6050,29 @@ mod feed_tests {
 
         let (cell,) = app.into_tuple();
 
-        let agent_pubkey = cell.agent_pubkey().clone();
-
-        // Create two feeds
-        for name in ["Feed 1", "Feed 2"] {
-            let feed = Feed {
-                name: name.to_string(),
-                description: None,
-                stewards: vec![agent_pubkey.clone()],
-                is_public: true,
-            };
+        let schema: ApiSchema = conductor
+            .call(&cell.zome("sharefeed"), "get_api_schema", ())
+            .await;
 
-            let _record: Record = conductor
-                .call(&cell.zome("sharefeed"), "create_feed", feed)
-                .await;
-        }
+        assert_eq!(&schema.dna_hash, cell.dna_hash());
+        assert_eq!(schema.zome, "sharefeed");
 
-        // Get my feeds
-        let feeds: Vec<FeedInfo> = conductor
-            .call(&cell.zome("sharefeed"), "get_my_feeds", ())
-            .await;
+        let names: Vec<&str> = schema
+            .externs
+            .iter()
+            .map(|extern_schema| extern_schema.name.as_str())
+            .collect();
+        assert!(names.contains(&"create_share_item"));
+        assert!(names.contains(&"get_api_schema"));
+        // Lifecycle callbacks aren't part of the API
+        assert!(!names.contains(&"init"));
 
-        assert_eq!(feeds.len(), 2);
+        assert!(schema.definitions.contains_key("ShareItem"));
+        assert!(schema.definitions.contains_key("ActionHash"));
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn can_add_share_to_feed() {
+    async fn instance_info_identifies_agent_and_dna() {
         holochain_trace::test_run();
 
         let mut conductor = SweetConductor::from_standard_config().await;
@@ -337,26 +6085,33 @@ mod feed_tests {
 
         let (cell,) = app.into_tuple();
 
-        let agent_pubkey = cell.agent_pubkey().clone();
+        let info: InstanceInfo = conductor
+            .call(&cell.zome("sharefeed"), "get_instance_info", ())
+            .await;
 
-        // Create a feed
-        let feed = Feed {
-            name: "Test Feed".to_string(),
-            description: None,
-            stewards: vec![agent_pubkey],
-            is_public: true,
-        };
+        assert_eq!(&info.agent, cell.agent_pubkey());
+        assert_eq!(&info.dna_hash, cell.dna_hash());
+        assert!(!info.integrity_version.is_empty());
+        assert!(!info.coordinator_version.is_empty());
+    }
 
-        let feed_record: Record = conductor
-            .call(&cell.zome("sharefeed"), "create_feed", feed)
-            .await;
+    #[tokio::test(flavor = "multi_thread")]
+    async fn health_check_reports_init_work() {
+        holochain_trace::test_run();
 
-        let feed_hash = feed_record.action_hashed().hash.clone();
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let dna = load_dna().await;
+
+        let app = conductor
+            .setup_app("sharefeed", [&dna])
+            .await
+            .unwrap();
+
+        let (cell,) = app.into_tuple();
 
-        // Create a share item
         let share_item = ShareItem {
-            url: "https://example.com".to_string(),
-            title: "Example".to_string(),
+            url: "https://example.com/health".to_string(),
+            title: "Health".to_string(),
             description: None,
             selection: None,
             favicon: None,
@@ -364,30 +6119,31 @@ mod feed_tests {
             tags: vec![],
         };
 
-        let share_record: Record = conductor
+        let _: Record = conductor
             .call(&cell.zome("sharefeed"), "create_share_item", share_item)
             .await;
 
-        let share_hash = share_record.action_hashed().hash.clone();
-
-        // Add share to feed
-        let _: () = conductor
-            .call(
-                &cell.zome("sharefeed"),
-                "add_share_to_feed",
-                AddShareToFeedInput {
-                    feed_hash: feed_hash.clone(),
-                    share_item_hash: share_hash,
-                },
-            )
+        let report: HealthReport = conductor
+            .call(&cell.zome("sharefeed"), "health_check", ())
             .await;
 
-        // Get feed shares
-        let shares: Vec<ShareItemInfo> = conductor
-            .call(&cell.zome("sharefeed"), "get_feed_shares", feed_hash)
+        assert_eq!(report.my_shares, 1);
+        assert_eq!(report.my_feeds, 0);
+        assert!(report.scheduled_tasks.contains(&"scheduled_weekly_digest".to_string()));
+        // init ensures the current week's time path
+        assert!(report.anchors[0].anchor.starts_with("shares."));
+        assert!(report.anchors[0].exists);
+
+        let agents: Vec<AgentPubKey> = conductor
+            .call(&cell.zome("sharefeed"), "get_agent_directory", ())
             .await;
 
-        assert_eq!(shares.len(), 1);
-        assert_eq!(shares[0].share_item.url, "https://example.com");
+        assert_eq!(agents, vec![cell.agent_pubkey().clone()]);
+
+        // Extension grants are only made for a key an extension presents
+        let grants: Vec<CapabilityGrantInfo> = conductor
+            .call(&cell.zome("sharefeed"), "list_my_capability_grants", ())
+            .await;
+        assert!(!grants.iter().any(|grant| grant.tag == "browser_extension"));
     }
 }
@@ -26,6 +26,24 @@ export interface Feed {
   description: string | null;
   stewards: AgentPubKey[];
   is_public: boolean;
+  requires_approval?: boolean;
+  keyword_filters?: KeywordFilter[];
+}
+
+/**
+ * KeywordFilter - matches sharefeed_integrity::KeywordFilter
+ */
+export interface KeywordFilter {
+  keyword: string;
+  action: { type: 'Hold' } | { type: 'Reject' };
+}
+
+/**
+ * AuthorProfile - matches coordinator::AuthorProfile
+ */
+export interface AuthorProfile {
+  nickname: string;
+  avatar: string | null;
 }
 
 /**
@@ -36,6 +54,7 @@ export interface ShareItemInfo {
   share_item: ShareItem;
   created_at: Timestamp;
   author: AgentPubKey;
+  author_profile: AuthorProfile | null;
 }
 
 /**
@@ -96,5 +115,18 @@ export interface AddMemberToFeedInput {
   member_pubkey: AgentPubKey;
 }
 
+/**
+ * ReadStrategy - where read calls look for data; defaults to Local
+ */
+export type ReadStrategy = 'Local' | 'Network';
+
+/**
+ * ReadInput - read calls accept either the bare input or this wrapper
+ */
+export interface ReadInput<T> {
+  input: T;
+  strategy?: ReadStrategy;
+}
+
 // Re-export Holochain types for convenience
 export type { ActionHash, AgentPubKey, HcRecord, Timestamp };
@@ -11,13 +11,36 @@ import type {
   WAL,
   WeaveServices,
 } from '@theweave/api';
-import type { AppClient } from '@holochain/client';
+import type {
+  ActionHash,
+  AppClient,
+  ProvisionedCell,
+} from '@holochain/client';
 
 export const ROLE_NAME = 'sharefeed';
+export const ZOME_NAME = 'sharefeed';
+
+/** Shown for assets without an icon of their own */
+const DEFAULT_ICON_SRC = '/icon.png';
+
+/** Matches the zome's `AssetInfo` */
+interface ZomeAssetInfo {
+  kind: { type: 'ShareItem' | 'Feed' };
+  name: string;
+  icon_src: string | null;
+  url: string | null;
+}
+
+/** Matches the zome's `AssetRef` */
+interface ZomeAssetRef {
+  kind: { type: 'ShareItem' | 'Feed' };
+  hash: ActionHash;
+}
 
 /**
  * Applet services configuration for Moss integration.
- * Minimal implementation - can be extended for asset previews, search, etc.
+ * Asset previews and search are answered by the zome; other tools'
+ * assets are shared with the `create_share_from_wal` zome function.
  */
 export const appletServices: AppletServices = {
   // Types of attachment that this Applet offers for other Applets to create
@@ -28,22 +51,43 @@ export const appletServices: AppletServices = {
 
   // Get info about an asset for display in Moss
   getAssetInfo: async (
-    _appletClient: AppClient,
-    _wal: WAL,
+    appletClient: AppClient,
+    wal: WAL,
     _recordInfo?: RecordInfo
   ): Promise<AssetInfo | undefined> => {
-    // For now, return undefined - can be extended to show share previews
-    return undefined;
+    const info: ZomeAssetInfo | null = await appletClient.callZome({
+      role_name: ROLE_NAME,
+      zome_name: ZOME_NAME,
+      fn_name: 'get_asset_info',
+      payload: wal.hrl[1],
+    });
+    if (!info) return undefined;
+    return {
+      name: info.name,
+      icon_src: info.icon_src ?? DEFAULT_ICON_SRC,
+    };
   },
 
   // Search within this applet
   search: async (
-    _appletClient: AppClient,
+    appletClient: AppClient,
     _appletHash: AppletHash,
     _weServices: WeaveServices,
-    _searchFilter: string
+    searchFilter: string
   ): Promise<Array<WAL>> => {
-    // For now, return empty - can be extended to search shares
-    return [];
+    const appInfo = await appletClient.appInfo();
+    const cell = appInfo?.cell_info[ROLE_NAME]?.find(
+      (info) => 'provisioned' in info
+    );
+    if (!cell) return [];
+    const dnaHash = (cell.provisioned as ProvisionedCell).cell_id[0];
+
+    const assets: ZomeAssetRef[] = await appletClient.callZome({
+      role_name: ROLE_NAME,
+      zome_name: ZOME_NAME,
+      fn_name: 'search_assets',
+      payload: { query: searchFilter, limit: null },
+    });
+    return assets.map((asset) => ({ hrl: [dnaHash, asset.hash] }));
   },
 };